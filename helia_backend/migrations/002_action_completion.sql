@@ -0,0 +1,7 @@
+--  Migration 2: Completion status of actions
+
+ALTER TABLE action ADD COLUMN completed_at DATETIME;
+
+CREATE INDEX idx_action_completed_at ON action (completed_at);
+
+PRAGMA user_version = 2;
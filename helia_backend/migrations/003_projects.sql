@@ -0,0 +1,14 @@
+--  Migration 3: Projects
+
+CREATE TABLE project (
+    id BLOB PRIMARY KEY,
+    title TEXT NOT NULL,
+    created_at DATETIME NOT NULL,
+    status TEXT NOT NULL
+);
+
+ALTER TABLE action ADD COLUMN project_id BLOB REFERENCES project (id) ON DELETE SET NULL;
+
+CREATE INDEX idx_action_project_id ON action (project_id);
+
+PRAGMA user_version = 3;
@@ -0,0 +1,17 @@
+--  Migration 4: Inbox, reference material and someday/maybe actions
+
+CREATE TABLE inbox_item (
+    id BLOB PRIMARY KEY,
+    text TEXT NOT NULL,
+    captured_at DATETIME NOT NULL
+);
+
+CREATE TABLE reference_item (
+    id BLOB PRIMARY KEY,
+    text TEXT NOT NULL,
+    created_at DATETIME NOT NULL
+);
+
+ALTER TABLE action ADD COLUMN someday INTEGER NOT NULL DEFAULT 0;
+
+PRAGMA user_version = 4;
@@ -0,0 +1,17 @@
+--  Migration 5: Contexts
+
+CREATE TABLE context (
+    id BLOB PRIMARY KEY,
+    name TEXT NOT NULL UNIQUE
+);
+
+-- Contexts that are still linked to an action cannot be deleted.
+CREATE TABLE action_context (
+    action_id BLOB NOT NULL REFERENCES action (id) ON DELETE CASCADE,
+    context_id BLOB NOT NULL REFERENCES context (id) ON DELETE RESTRICT,
+    PRIMARY KEY (action_id, context_id)
+);
+
+CREATE INDEX idx_action_context_context_id ON action_context (context_id);
+
+PRAGMA user_version = 5;
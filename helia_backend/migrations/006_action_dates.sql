@@ -0,0 +1,10 @@
+--  Migration 6: Due and defer dates of actions
+
+ALTER TABLE action ADD COLUMN due_at DATETIME;
+ALTER TABLE action ADD COLUMN defer_until DATETIME;
+
+-- Date based lists only ever look at open actions, so the completion date leads both indexes.
+CREATE INDEX idx_action_open_due_at ON action (completed_at, due_at);
+CREATE INDEX idx_action_open_defer_until ON action (completed_at, defer_until);
+
+PRAGMA user_version = 6;
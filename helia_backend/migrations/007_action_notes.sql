@@ -0,0 +1,5 @@
+--  Migration 7: Notes of actions
+
+ALTER TABLE action ADD COLUMN notes TEXT;
+
+PRAGMA user_version = 7;
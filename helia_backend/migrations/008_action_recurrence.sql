@@ -0,0 +1,6 @@
+--  Migration 8: Recurrence rules of actions
+
+ALTER TABLE action ADD COLUMN recurrence TEXT;
+ALTER TABLE action ADD COLUMN recur_from_completion INTEGER NOT NULL DEFAULT 0;
+
+PRAGMA user_version = 8;
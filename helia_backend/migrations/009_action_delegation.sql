@@ -0,0 +1,10 @@
+--  Migration 9: Delegation of actions
+
+ALTER TABLE action ADD COLUMN delegated_to TEXT;
+ALTER TABLE action ADD COLUMN delegated_at DATETIME;
+ALTER TABLE action ADD COLUMN follow_up_at DATETIME;
+
+-- The waiting-for list only ever looks at open actions, ordered by their follow-up date.
+CREATE INDEX idx_action_open_follow_up_at ON action (completed_at, follow_up_at);
+
+PRAGMA user_version = 9;
@@ -0,0 +1,10 @@
+--  Migration 10: Someday/maybe projects
+--
+--  Actions got their someday flag in migration 4. Actions of a someday/maybe project count as
+--  someday/maybe as well, so the project flag is looked up for every action listing.
+
+ALTER TABLE project ADD COLUMN someday INTEGER NOT NULL DEFAULT 0;
+
+CREATE INDEX idx_project_someday ON project (id) WHERE someday = 1;
+
+PRAGMA user_version = 10;
@@ -0,0 +1,21 @@
+--  Migration 11: Weekly reviews
+
+CREATE TABLE review (
+    id BLOB PRIMARY KEY,
+    started_at DATETIME NOT NULL,
+    completed_at DATETIME
+);
+
+-- At most one review is in progress at any time.
+CREATE UNIQUE INDEX idx_review_in_progress ON review ((completed_at IS NULL)) WHERE completed_at IS NULL;
+
+CREATE TABLE review_step (
+    review_id BLOB NOT NULL REFERENCES review (id) ON DELETE CASCADE,
+    step TEXT NOT NULL,
+    completed_at DATETIME NOT NULL,
+    PRIMARY KEY (review_id, step)
+);
+
+ALTER TABLE project ADD COLUMN last_reviewed_at DATETIME;
+
+PRAGMA user_version = 11;
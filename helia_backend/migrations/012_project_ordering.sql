@@ -0,0 +1,27 @@
+--  Migration 12: Sequential projects and action positions
+--
+--  Existing project actions are numbered in the order they were created.
+
+ALTER TABLE project ADD COLUMN ordering TEXT NOT NULL DEFAULT 'parallel';
+
+ALTER TABLE action ADD COLUMN position INTEGER;
+
+UPDATE action SET position = (
+    SELECT COUNT(*) FROM action AS earlier
+    WHERE earlier.project_id = action.project_id
+    AND (earlier.created_at < action.created_at
+        OR (earlier.created_at = action.created_at AND earlier.rowid < action.rowid))
+)
+WHERE project_id IS NOT NULL;
+
+DROP INDEX idx_action_project_id;
+CREATE INDEX idx_action_project_id ON action (project_id, position);
+
+-- Actions leaving their project, also when it is deleted, lose their position.
+CREATE TRIGGER action_project_cleared AFTER UPDATE OF project_id ON action
+WHEN NEW.project_id IS NULL AND NEW.position IS NOT NULL
+BEGIN
+    UPDATE action SET position = NULL WHERE id = NEW.id;
+END;
+
+PRAGMA user_version = 12;
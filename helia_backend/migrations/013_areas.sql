@@ -0,0 +1,16 @@
+--  Migration 13: Areas of focus
+--
+--  Projects and standalone actions can belong to an area. Deleting an area keeps them.
+
+CREATE TABLE area (
+    id BLOB PRIMARY KEY,
+    name TEXT NOT NULL UNIQUE
+);
+
+ALTER TABLE project ADD COLUMN area_id BLOB REFERENCES area (id) ON DELETE SET NULL;
+ALTER TABLE action ADD COLUMN area_id BLOB REFERENCES area (id) ON DELETE SET NULL;
+
+CREATE INDEX idx_project_area_id ON project (area_id);
+CREATE INDEX idx_action_area_id ON action (area_id);
+
+PRAGMA user_version = 13;
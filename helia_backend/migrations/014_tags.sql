@@ -0,0 +1,27 @@
+--  Migration 14: Tags
+--
+--  Tags form a hierarchy through their paths, like `work/client-x`. Parent tags don't need to
+--  exist. Deleting a tag, an action or a project removes their links.
+
+CREATE TABLE tag (
+    id BLOB PRIMARY KEY,
+    path TEXT NOT NULL UNIQUE
+);
+
+CREATE TABLE action_tag (
+    action_id BLOB NOT NULL REFERENCES action (id) ON DELETE CASCADE,
+    tag_id BLOB NOT NULL REFERENCES tag (id) ON DELETE CASCADE,
+    PRIMARY KEY (action_id, tag_id)
+);
+
+CREATE INDEX idx_action_tag_tag_id ON action_tag (tag_id);
+
+CREATE TABLE project_tag (
+    project_id BLOB NOT NULL REFERENCES project (id) ON DELETE CASCADE,
+    tag_id BLOB NOT NULL REFERENCES tag (id) ON DELETE CASCADE,
+    PRIMARY KEY (project_id, tag_id)
+);
+
+CREATE INDEX idx_project_tag_tag_id ON project_tag (tag_id);
+
+PRAGMA user_version = 14;
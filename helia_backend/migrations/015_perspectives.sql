@@ -0,0 +1,12 @@
+--  Migration 15: Perspectives
+--
+--  A perspective stores the textual form of an action query, not its result. Relative dates
+--  like `today` are resolved whenever the perspective is evaluated.
+
+CREATE TABLE perspective (
+    id BLOB PRIMARY KEY,
+    name TEXT NOT NULL UNIQUE,
+    query TEXT NOT NULL
+);
+
+PRAGMA user_version = 15;
@@ -0,0 +1,66 @@
+--  Migration 16: Full-text search
+--
+--  One FTS5 index covers actions, projects and reference items. `kind` and `item_id` tell
+--  which item a row belongs to, they are not searchable. The triggers keep the index in sync,
+--  the updates only fire when a searchable column changed.
+
+CREATE VIRTUAL TABLE search_index USING fts5 (
+    title,
+    body,
+    kind UNINDEXED,
+    item_id UNINDEXED,
+    tokenize = 'unicode61 remove_diacritics 2'
+);
+
+INSERT INTO search_index (title, body, kind, item_id)
+SELECT title, notes, 'action', id FROM action;
+
+INSERT INTO search_index (title, body, kind, item_id)
+SELECT title, NULL, 'project', id FROM project;
+
+INSERT INTO search_index (title, body, kind, item_id)
+SELECT text, NULL, 'reference_item', id FROM reference_item;
+
+CREATE TRIGGER search_index_action_insert AFTER INSERT ON action BEGIN
+    INSERT INTO search_index (title, body, kind, item_id)
+    VALUES (new.title, new.notes, 'action', new.id);
+END;
+
+CREATE TRIGGER search_index_action_update AFTER UPDATE OF title, notes ON action
+WHEN old.title IS NOT new.title OR old.notes IS NOT new.notes BEGIN
+    UPDATE search_index SET title = new.title, body = new.notes WHERE item_id = old.id;
+END;
+
+CREATE TRIGGER search_index_action_delete AFTER DELETE ON action BEGIN
+    DELETE FROM search_index WHERE item_id = old.id;
+END;
+
+CREATE TRIGGER search_index_project_insert AFTER INSERT ON project BEGIN
+    INSERT INTO search_index (title, body, kind, item_id)
+    VALUES (new.title, NULL, 'project', new.id);
+END;
+
+CREATE TRIGGER search_index_project_update AFTER UPDATE OF title ON project
+WHEN old.title IS NOT new.title BEGIN
+    UPDATE search_index SET title = new.title WHERE item_id = old.id;
+END;
+
+CREATE TRIGGER search_index_project_delete AFTER DELETE ON project BEGIN
+    DELETE FROM search_index WHERE item_id = old.id;
+END;
+
+CREATE TRIGGER search_index_reference_item_insert AFTER INSERT ON reference_item BEGIN
+    INSERT INTO search_index (title, body, kind, item_id)
+    VALUES (new.text, NULL, 'reference_item', new.id);
+END;
+
+CREATE TRIGGER search_index_reference_item_update AFTER UPDATE OF text ON reference_item
+WHEN old.text IS NOT new.text BEGIN
+    UPDATE search_index SET title = new.text WHERE item_id = old.id;
+END;
+
+CREATE TRIGGER search_index_reference_item_delete AFTER DELETE ON reference_item BEGIN
+    DELETE FROM search_index WHERE item_id = old.id;
+END;
+
+PRAGMA user_version = 16;
@@ -0,0 +1,396 @@
+--  Migration 17: Undo history
+--
+--  Every change to the tables below is logged as the SQL statement reverting it. A step of the
+--  history groups the statements logged by one operation, they are open (without a step) until
+--  the operation is done. Undoing a step runs its statements in reverse order, which logs the
+--  statements redoing it, and vice versa.
+--
+--  The triggers list every column of their table. Migrations adding a column to one of these
+--  tables have to recreate its triggers.
+
+CREATE TABLE history_step (
+    id INTEGER PRIMARY KEY,
+    operation TEXT NOT NULL,
+    undone INTEGER NOT NULL DEFAULT 0
+);
+
+CREATE TABLE history_entry (
+    id INTEGER PRIMARY KEY,
+    step_id INTEGER REFERENCES history_step (id) ON DELETE CASCADE,
+    statement TEXT NOT NULL
+);
+
+CREATE INDEX idx_history_entry_step_id ON history_entry (step_id);
+
+CREATE TRIGGER history_area_insert AFTER INSERT ON area BEGIN
+    INSERT INTO history_entry (statement)
+    VALUES (
+        'DELETE FROM area'
+        || ' WHERE id = ' || quote(new.id)
+    );
+END;
+
+CREATE TRIGGER history_area_update AFTER UPDATE ON area BEGIN
+    INSERT INTO history_entry (statement)
+    VALUES (
+        'UPDATE area SET name = ' || quote(old.name)
+        || ' WHERE id = ' || quote(old.id)
+    );
+END;
+
+CREATE TRIGGER history_area_delete AFTER DELETE ON area BEGIN
+    INSERT INTO history_entry (statement)
+    VALUES (
+        'INSERT INTO area (id, name) VALUES ('
+        || quote(old.id)
+        || ', ' || quote(old.name)
+        || ')'
+    );
+END;
+
+CREATE TRIGGER history_project_insert AFTER INSERT ON project BEGIN
+    INSERT INTO history_entry (statement)
+    VALUES (
+        'DELETE FROM project'
+        || ' WHERE id = ' || quote(new.id)
+    );
+END;
+
+CREATE TRIGGER history_project_update AFTER UPDATE ON project BEGIN
+    INSERT INTO history_entry (statement)
+    VALUES (
+        'UPDATE project SET title = ' || quote(old.title)
+        || ', created_at = ' || quote(old.created_at)
+        || ', status = ' || quote(old.status)
+        || ', someday = ' || quote(old.someday)
+        || ', last_reviewed_at = ' || quote(old.last_reviewed_at)
+        || ', ordering = ' || quote(old.ordering)
+        || ', area_id = ' || quote(old.area_id)
+        || ' WHERE id = ' || quote(old.id)
+    );
+END;
+
+CREATE TRIGGER history_project_delete AFTER DELETE ON project BEGIN
+    INSERT INTO history_entry (statement)
+    VALUES (
+        'INSERT INTO project (id, title, created_at, status, someday, last_reviewed_at, '
+        || 'ordering, area_id) VALUES ('
+        || quote(old.id)
+        || ', ' || quote(old.title)
+        || ', ' || quote(old.created_at)
+        || ', ' || quote(old.status)
+        || ', ' || quote(old.someday)
+        || ', ' || quote(old.last_reviewed_at)
+        || ', ' || quote(old.ordering)
+        || ', ' || quote(old.area_id)
+        || ')'
+    );
+END;
+
+CREATE TRIGGER history_action_insert AFTER INSERT ON action BEGIN
+    INSERT INTO history_entry (statement)
+    VALUES (
+        'DELETE FROM action'
+        || ' WHERE id = ' || quote(new.id)
+    );
+END;
+
+CREATE TRIGGER history_action_update AFTER UPDATE ON action BEGIN
+    INSERT INTO history_entry (statement)
+    VALUES (
+        'UPDATE action SET title = ' || quote(old.title)
+        || ', created_at = ' || quote(old.created_at)
+        || ', completed_at = ' || quote(old.completed_at)
+        || ', project_id = ' || quote(old.project_id)
+        || ', someday = ' || quote(old.someday)
+        || ', due_at = ' || quote(old.due_at)
+        || ', defer_until = ' || quote(old.defer_until)
+        || ', notes = ' || quote(old.notes)
+        || ', recurrence = ' || quote(old.recurrence)
+        || ', recur_from_completion = ' || quote(old.recur_from_completion)
+        || ', delegated_to = ' || quote(old.delegated_to)
+        || ', delegated_at = ' || quote(old.delegated_at)
+        || ', follow_up_at = ' || quote(old.follow_up_at)
+        || ', position = ' || quote(old.position)
+        || ', area_id = ' || quote(old.area_id)
+        || ' WHERE id = ' || quote(old.id)
+    );
+END;
+
+CREATE TRIGGER history_action_delete AFTER DELETE ON action BEGIN
+    INSERT INTO history_entry (statement)
+    VALUES (
+        'INSERT INTO action (id, title, created_at, completed_at, project_id, someday, '
+        || 'due_at, defer_until, notes, recurrence, recur_from_completion, delegated_to, '
+        || 'delegated_at, follow_up_at, position, area_id) VALUES ('
+        || quote(old.id)
+        || ', ' || quote(old.title)
+        || ', ' || quote(old.created_at)
+        || ', ' || quote(old.completed_at)
+        || ', ' || quote(old.project_id)
+        || ', ' || quote(old.someday)
+        || ', ' || quote(old.due_at)
+        || ', ' || quote(old.defer_until)
+        || ', ' || quote(old.notes)
+        || ', ' || quote(old.recurrence)
+        || ', ' || quote(old.recur_from_completion)
+        || ', ' || quote(old.delegated_to)
+        || ', ' || quote(old.delegated_at)
+        || ', ' || quote(old.follow_up_at)
+        || ', ' || quote(old.position)
+        || ', ' || quote(old.area_id)
+        || ')'
+    );
+END;
+
+CREATE TRIGGER history_context_insert AFTER INSERT ON context BEGIN
+    INSERT INTO history_entry (statement)
+    VALUES (
+        'DELETE FROM context'
+        || ' WHERE id = ' || quote(new.id)
+    );
+END;
+
+CREATE TRIGGER history_context_update AFTER UPDATE ON context BEGIN
+    INSERT INTO history_entry (statement)
+    VALUES (
+        'UPDATE context SET name = ' || quote(old.name)
+        || ' WHERE id = ' || quote(old.id)
+    );
+END;
+
+CREATE TRIGGER history_context_delete AFTER DELETE ON context BEGIN
+    INSERT INTO history_entry (statement)
+    VALUES (
+        'INSERT INTO context (id, name) VALUES ('
+        || quote(old.id)
+        || ', ' || quote(old.name)
+        || ')'
+    );
+END;
+
+CREATE TRIGGER history_action_context_insert AFTER INSERT ON action_context BEGIN
+    INSERT INTO history_entry (statement)
+    VALUES (
+        'DELETE FROM action_context'
+        || ' WHERE action_id = ' || quote(new.action_id)
+        || ' AND context_id = ' || quote(new.context_id)
+    );
+END;
+
+CREATE TRIGGER history_action_context_delete AFTER DELETE ON action_context BEGIN
+    INSERT INTO history_entry (statement)
+    VALUES (
+        'INSERT INTO action_context (action_id, context_id) VALUES ('
+        || quote(old.action_id)
+        || ', ' || quote(old.context_id)
+        || ')'
+    );
+END;
+
+CREATE TRIGGER history_tag_insert AFTER INSERT ON tag BEGIN
+    INSERT INTO history_entry (statement)
+    VALUES (
+        'DELETE FROM tag'
+        || ' WHERE id = ' || quote(new.id)
+    );
+END;
+
+CREATE TRIGGER history_tag_update AFTER UPDATE ON tag BEGIN
+    INSERT INTO history_entry (statement)
+    VALUES (
+        'UPDATE tag SET path = ' || quote(old.path)
+        || ' WHERE id = ' || quote(old.id)
+    );
+END;
+
+CREATE TRIGGER history_tag_delete AFTER DELETE ON tag BEGIN
+    INSERT INTO history_entry (statement)
+    VALUES (
+        'INSERT INTO tag (id, path) VALUES ('
+        || quote(old.id)
+        || ', ' || quote(old.path)
+        || ')'
+    );
+END;
+
+CREATE TRIGGER history_action_tag_insert AFTER INSERT ON action_tag BEGIN
+    INSERT INTO history_entry (statement)
+    VALUES (
+        'DELETE FROM action_tag'
+        || ' WHERE action_id = ' || quote(new.action_id)
+        || ' AND tag_id = ' || quote(new.tag_id)
+    );
+END;
+
+CREATE TRIGGER history_action_tag_delete AFTER DELETE ON action_tag BEGIN
+    INSERT INTO history_entry (statement)
+    VALUES (
+        'INSERT INTO action_tag (action_id, tag_id) VALUES ('
+        || quote(old.action_id)
+        || ', ' || quote(old.tag_id)
+        || ')'
+    );
+END;
+
+CREATE TRIGGER history_project_tag_insert AFTER INSERT ON project_tag BEGIN
+    INSERT INTO history_entry (statement)
+    VALUES (
+        'DELETE FROM project_tag'
+        || ' WHERE project_id = ' || quote(new.project_id)
+        || ' AND tag_id = ' || quote(new.tag_id)
+    );
+END;
+
+CREATE TRIGGER history_project_tag_delete AFTER DELETE ON project_tag BEGIN
+    INSERT INTO history_entry (statement)
+    VALUES (
+        'INSERT INTO project_tag (project_id, tag_id) VALUES ('
+        || quote(old.project_id)
+        || ', ' || quote(old.tag_id)
+        || ')'
+    );
+END;
+
+CREATE TRIGGER history_perspective_insert AFTER INSERT ON perspective BEGIN
+    INSERT INTO history_entry (statement)
+    VALUES (
+        'DELETE FROM perspective'
+        || ' WHERE id = ' || quote(new.id)
+    );
+END;
+
+CREATE TRIGGER history_perspective_update AFTER UPDATE ON perspective BEGIN
+    INSERT INTO history_entry (statement)
+    VALUES (
+        'UPDATE perspective SET name = ' || quote(old.name)
+        || ', query = ' || quote(old.query)
+        || ' WHERE id = ' || quote(old.id)
+    );
+END;
+
+CREATE TRIGGER history_perspective_delete AFTER DELETE ON perspective BEGIN
+    INSERT INTO history_entry (statement)
+    VALUES (
+        'INSERT INTO perspective (id, name, query) VALUES ('
+        || quote(old.id)
+        || ', ' || quote(old.name)
+        || ', ' || quote(old.query)
+        || ')'
+    );
+END;
+
+CREATE TRIGGER history_inbox_item_insert AFTER INSERT ON inbox_item BEGIN
+    INSERT INTO history_entry (statement)
+    VALUES (
+        'DELETE FROM inbox_item'
+        || ' WHERE id = ' || quote(new.id)
+    );
+END;
+
+CREATE TRIGGER history_inbox_item_update AFTER UPDATE ON inbox_item BEGIN
+    INSERT INTO history_entry (statement)
+    VALUES (
+        'UPDATE inbox_item SET text = ' || quote(old.text)
+        || ', captured_at = ' || quote(old.captured_at)
+        || ' WHERE id = ' || quote(old.id)
+    );
+END;
+
+CREATE TRIGGER history_inbox_item_delete AFTER DELETE ON inbox_item BEGIN
+    INSERT INTO history_entry (statement)
+    VALUES (
+        'INSERT INTO inbox_item (id, text, captured_at) VALUES ('
+        || quote(old.id)
+        || ', ' || quote(old.text)
+        || ', ' || quote(old.captured_at)
+        || ')'
+    );
+END;
+
+CREATE TRIGGER history_reference_item_insert AFTER INSERT ON reference_item BEGIN
+    INSERT INTO history_entry (statement)
+    VALUES (
+        'DELETE FROM reference_item'
+        || ' WHERE id = ' || quote(new.id)
+    );
+END;
+
+CREATE TRIGGER history_reference_item_update AFTER UPDATE ON reference_item BEGIN
+    INSERT INTO history_entry (statement)
+    VALUES (
+        'UPDATE reference_item SET text = ' || quote(old.text)
+        || ', created_at = ' || quote(old.created_at)
+        || ' WHERE id = ' || quote(old.id)
+    );
+END;
+
+CREATE TRIGGER history_reference_item_delete AFTER DELETE ON reference_item BEGIN
+    INSERT INTO history_entry (statement)
+    VALUES (
+        'INSERT INTO reference_item (id, text, created_at) VALUES ('
+        || quote(old.id)
+        || ', ' || quote(old.text)
+        || ', ' || quote(old.created_at)
+        || ')'
+    );
+END;
+
+CREATE TRIGGER history_review_insert AFTER INSERT ON review BEGIN
+    INSERT INTO history_entry (statement)
+    VALUES (
+        'DELETE FROM review'
+        || ' WHERE id = ' || quote(new.id)
+    );
+END;
+
+CREATE TRIGGER history_review_update AFTER UPDATE ON review BEGIN
+    INSERT INTO history_entry (statement)
+    VALUES (
+        'UPDATE review SET started_at = ' || quote(old.started_at)
+        || ', completed_at = ' || quote(old.completed_at)
+        || ' WHERE id = ' || quote(old.id)
+    );
+END;
+
+CREATE TRIGGER history_review_delete AFTER DELETE ON review BEGIN
+    INSERT INTO history_entry (statement)
+    VALUES (
+        'INSERT INTO review (id, started_at, completed_at) VALUES ('
+        || quote(old.id)
+        || ', ' || quote(old.started_at)
+        || ', ' || quote(old.completed_at)
+        || ')'
+    );
+END;
+
+CREATE TRIGGER history_review_step_insert AFTER INSERT ON review_step BEGIN
+    INSERT INTO history_entry (statement)
+    VALUES (
+        'DELETE FROM review_step'
+        || ' WHERE review_id = ' || quote(new.review_id)
+        || ' AND step = ' || quote(new.step)
+    );
+END;
+
+CREATE TRIGGER history_review_step_update AFTER UPDATE ON review_step BEGIN
+    INSERT INTO history_entry (statement)
+    VALUES (
+        'UPDATE review_step SET completed_at = ' || quote(old.completed_at)
+        || ' WHERE review_id = ' || quote(old.review_id)
+        || ' AND step = ' || quote(old.step)
+    );
+END;
+
+CREATE TRIGGER history_review_step_delete AFTER DELETE ON review_step BEGIN
+    INSERT INTO history_entry (statement)
+    VALUES (
+        'INSERT INTO review_step (review_id, step, completed_at) VALUES ('
+        || quote(old.review_id)
+        || ', ' || quote(old.step)
+        || ', ' || quote(old.completed_at)
+        || ')'
+    );
+END;
+
+PRAGMA user_version = 17;
@@ -0,0 +1,76 @@
+--  Migration 18: Search index rowids
+--
+--  `item_id` is not indexed by the FTS5 table, so finding the row of an item in `search_index`
+--  scanned the whole index. `search_item` maps every item to the rowid of its row instead, the
+--  triggers look rows up through it.
+
+CREATE TABLE search_item (
+    id INTEGER PRIMARY KEY,
+    item_id BLOB NOT NULL UNIQUE
+);
+
+INSERT INTO search_item (id, item_id)
+SELECT rowid, item_id FROM search_index;
+
+DROP TRIGGER search_index_action_insert;
+DROP TRIGGER search_index_action_update;
+DROP TRIGGER search_index_action_delete;
+DROP TRIGGER search_index_project_insert;
+DROP TRIGGER search_index_project_update;
+DROP TRIGGER search_index_project_delete;
+DROP TRIGGER search_index_reference_item_insert;
+DROP TRIGGER search_index_reference_item_update;
+DROP TRIGGER search_index_reference_item_delete;
+
+CREATE TRIGGER search_index_action_insert AFTER INSERT ON action BEGIN
+    INSERT INTO search_item (item_id) VALUES (new.id);
+    INSERT INTO search_index (rowid, title, body, kind, item_id)
+    SELECT id, new.title, new.notes, 'action', new.id FROM search_item WHERE item_id = new.id;
+END;
+
+CREATE TRIGGER search_index_action_update AFTER UPDATE OF title, notes ON action
+WHEN old.title IS NOT new.title OR old.notes IS NOT new.notes BEGIN
+    UPDATE search_index SET title = new.title, body = new.notes
+    WHERE rowid = (SELECT id FROM search_item WHERE item_id = old.id);
+END;
+
+CREATE TRIGGER search_index_action_delete AFTER DELETE ON action BEGIN
+    DELETE FROM search_index WHERE rowid = (SELECT id FROM search_item WHERE item_id = old.id);
+    DELETE FROM search_item WHERE item_id = old.id;
+END;
+
+CREATE TRIGGER search_index_project_insert AFTER INSERT ON project BEGIN
+    INSERT INTO search_item (item_id) VALUES (new.id);
+    INSERT INTO search_index (rowid, title, body, kind, item_id)
+    SELECT id, new.title, NULL, 'project', new.id FROM search_item WHERE item_id = new.id;
+END;
+
+CREATE TRIGGER search_index_project_update AFTER UPDATE OF title ON project
+WHEN old.title IS NOT new.title BEGIN
+    UPDATE search_index SET title = new.title
+    WHERE rowid = (SELECT id FROM search_item WHERE item_id = old.id);
+END;
+
+CREATE TRIGGER search_index_project_delete AFTER DELETE ON project BEGIN
+    DELETE FROM search_index WHERE rowid = (SELECT id FROM search_item WHERE item_id = old.id);
+    DELETE FROM search_item WHERE item_id = old.id;
+END;
+
+CREATE TRIGGER search_index_reference_item_insert AFTER INSERT ON reference_item BEGIN
+    INSERT INTO search_item (item_id) VALUES (new.id);
+    INSERT INTO search_index (rowid, title, body, kind, item_id)
+    SELECT id, new.text, NULL, 'reference_item', new.id FROM search_item WHERE item_id = new.id;
+END;
+
+CREATE TRIGGER search_index_reference_item_update AFTER UPDATE OF text ON reference_item
+WHEN old.text IS NOT new.text BEGIN
+    UPDATE search_index SET title = new.text
+    WHERE rowid = (SELECT id FROM search_item WHERE item_id = old.id);
+END;
+
+CREATE TRIGGER search_index_reference_item_delete AFTER DELETE ON reference_item BEGIN
+    DELETE FROM search_index WHERE rowid = (SELECT id FROM search_item WHERE item_id = old.id);
+    DELETE FROM search_item WHERE item_id = old.id;
+END;
+
+PRAGMA user_version = 18;
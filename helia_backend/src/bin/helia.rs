@@ -0,0 +1,335 @@
+//! `helia` - the command-line interface to the Helia backend.
+//!
+//! Every subcommand maps to one call of the [HeliaCore] API.
+//! Failures are reported on stderr and mapped to the exit codes in [exit_code].
+
+use std::{path::PathBuf, process::ExitCode};
+
+use clap::{Parser, Subcommand, ValueEnum};
+use helia_backend::{
+    Action, ActionId, ActionStatusFilter, CreateActionRequest, HeliaCore, HeliaError,
+    ListActionsRequest, ProductionConfig, ProjectId, StorageErrorKind,
+};
+use serde_json::json;
+use uuid::Uuid;
+
+/// The command completed successfully.
+const EXIT_OK: u8 = 0;
+/// A storage operation failed.
+const EXIT_STORAGE_FAILURE: u8 = 1;
+// Exit code 2 is used by clap for invalid command-line usage.
+/// The requested item does not exist.
+const EXIT_NOT_FOUND: u8 = 3;
+/// The request conflicts with the current state, e.g. completing a completed action.
+const EXIT_CONFLICT: u8 = 4;
+/// The input was rejected as invalid.
+const EXIT_INVALID_INPUT: u8 = 5;
+/// The database could not be located or opened.
+const EXIT_UNAVAILABLE: u8 = 6;
+
+/// Manage your Helia actions from the terminal.
+#[derive(Debug, Parser)]
+#[command(name = "helia", version)]
+struct Cli {
+    /// Path to the database file. Defaults to `$XDG_DATA_HOME/helia/helia.db`.
+    #[arg(long, global = true, value_name = "PATH")]
+    db: Option<PathBuf>,
+
+    /// How results are printed.
+    #[arg(long, global = true, value_enum, default_value_t = OutputFormat::Human)]
+    output: OutputFormat,
+
+    #[command(subcommand)]
+    command: Command,
+}
+
+#[derive(Debug, Subcommand)]
+enum Command {
+    /// Add a new action.
+    Add {
+        /// The name of the action.
+        name: String,
+        /// The id of the project the action belongs to.
+        #[arg(long, value_name = "PROJECT_ID")]
+        project: Option<Uuid>,
+    },
+    /// List actions.
+    List {
+        /// Which actions to list.
+        #[arg(long, value_enum, default_value_t = StatusArg::Open)]
+        status: StatusArg,
+        /// Only list actions of the project with this id.
+        #[arg(long, value_name = "PROJECT_ID")]
+        project: Option<Uuid>,
+        /// Also list actions on the someday/maybe list.
+        #[arg(long)]
+        include_someday: bool,
+    },
+    /// Mark an action as completed.
+    Complete {
+        /// The id of the action.
+        id: Uuid,
+    },
+    /// Delete an action.
+    Delete {
+        /// The id of the action.
+        id: Uuid,
+    },
+    /// Apply all pending database migrations.
+    Migrate,
+    /// Show the version of the binary and of the database schema.
+    Version,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, ValueEnum)]
+enum OutputFormat {
+    /// Plain text meant for humans.
+    Human,
+    /// JSON meant for scripts.
+    Json,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, ValueEnum)]
+enum StatusArg {
+    Open,
+    Completed,
+    All,
+}
+
+impl From<StatusArg> for ActionStatusFilter {
+    fn from(status: StatusArg) -> Self {
+        match status {
+            StatusArg::Open => ActionStatusFilter::Open,
+            StatusArg::Completed => ActionStatusFilter::Completed,
+            StatusArg::All => ActionStatusFilter::All,
+        }
+    }
+}
+
+fn main() -> ExitCode {
+    let cli = Cli::parse();
+    let output = cli.output;
+    match run(cli) {
+        Ok(()) => ExitCode::from(EXIT_OK),
+        Err(err) => {
+            report_error(&err, output);
+            ExitCode::from(exit_code(&err))
+        }
+    }
+}
+
+/// Opens the database and executes the requested command.
+fn run(cli: Cli) -> Result<(), HeliaError> {
+    let config = match cli.db {
+        Some(db_path) => ProductionConfig::with_db_path(db_path),
+        None => ProductionConfig::default(),
+    };
+    let mut helia_core = helia_backend::new_production(&config)?;
+    let output = cli.output;
+
+    match cli.command {
+        Command::Add { name, project } => {
+            let action = helia_core.create_action(CreateActionRequest {
+                name,
+                project_id: project.map(ProjectId::from_uuid),
+                ..Default::default()
+            })?;
+            match output {
+                OutputFormat::Human => println!("Added action {}.", action.action_id().uuid()),
+                OutputFormat::Json => println!("{}", action_json(&action)),
+            }
+        }
+        Command::List {
+            status,
+            project,
+            include_someday,
+        } => {
+            let actions = helia_core.list_actions(ListActionsRequest {
+                status: status.into(),
+                project_id: project.map(ProjectId::from_uuid),
+                include_someday,
+                ..Default::default()
+            })?;
+            match output {
+                OutputFormat::Human => {
+                    for action in &actions {
+                        println!("{}", action_line(action));
+                    }
+                }
+                OutputFormat::Json => {
+                    let actions: Vec<_> = actions.iter().map(action_json).collect();
+                    println!("{}", serde_json::Value::Array(actions));
+                }
+            }
+        }
+        Command::Complete { id } => {
+            let completed = helia_core.complete_action(&ActionId::from_uuid(id))?;
+            match output {
+                OutputFormat::Human => {
+                    println!("Completed action {id}.");
+                    if let Some(next_occurrence) = &completed.next_occurrence {
+                        println!("Next occurrence: {}", action_line(next_occurrence));
+                    }
+                }
+                OutputFormat::Json => println!(
+                    "{}",
+                    json!({
+                        "action": action_json(&completed.action),
+                        "next_occurrence": completed.next_occurrence.as_ref().map(action_json),
+                    })
+                ),
+            }
+        }
+        Command::Delete { id } => {
+            helia_core.delete_action(&ActionId::from_uuid(id))?;
+            match output {
+                OutputFormat::Human => println!("Deleted action {id}."),
+                OutputFormat::Json => println!("{}", json!({ "deleted": id })),
+            }
+        }
+        Command::Migrate => {
+            let version = helia_core.run_migrations()?;
+            match output {
+                OutputFormat::Human => println!("Database schema is at version {version}."),
+                OutputFormat::Json => println!("{}", json!({ "schema_version": version })),
+            }
+        }
+        Command::Version => {
+            let version = helia_core.storage_version()?;
+            match output {
+                OutputFormat::Human => {
+                    println!("helia {}", env!("CARGO_PKG_VERSION"));
+                    println!("database schema version {version}");
+                }
+                OutputFormat::Json => println!(
+                    "{}",
+                    json!({ "version": env!("CARGO_PKG_VERSION"), "schema_version": version })
+                ),
+            }
+        }
+    }
+
+    Ok(())
+}
+
+/// Maps an error to the exit code of the process.
+fn exit_code(err: &HeliaError) -> u8 {
+    match err {
+        HeliaError::ActionNotFound { .. }
+        | HeliaError::AreaNotFound { .. }
+        | HeliaError::ContextNotFound { .. }
+        | HeliaError::InboxItemNotFound { .. }
+        | HeliaError::PerspectiveNotFound { .. }
+        | HeliaError::ProjectNotFound { .. }
+        | HeliaError::TagNotFound { .. } => EXIT_NOT_FOUND,
+        HeliaError::ActionAlreadyCompleted { .. }
+        | HeliaError::ActionInProject { .. }
+        | HeliaError::ActionNotCompleted { .. }
+        | HeliaError::ActionNotDelegated { .. }
+        | HeliaError::ContextInUse { .. }
+        | HeliaError::ContextNotAssigned { .. }
+        | HeliaError::NoReviewInProgress
+        | HeliaError::ReviewStepOutOfOrder { .. }
+        | HeliaError::TagNotAssigned { .. }
+        | HeliaError::TagPathTaken { .. } => EXIT_CONFLICT,
+        HeliaError::ActionNotInProject { .. }
+        | HeliaError::InvalidActionQuery { .. }
+        | HeliaError::InvalidImportDocument { .. }
+        | HeliaError::ReadingImportFailed { .. }
+        | HeliaError::UnsupportedExportVersion { .. }
+        | HeliaError::ValidationFailed { .. } => EXIT_INVALID_INPUT,
+        HeliaError::CreatingDataDirFailed { .. }
+        | HeliaError::DataDirUnavailable
+        | HeliaError::OpeningDatabaseFailed { .. }
+        | HeliaError::StorageConnectionFailed { .. } => EXIT_UNAVAILABLE,
+        _ => match err.storage_error().map(|storage_err| storage_err.kind()) {
+            Some(StorageErrorKind::Constraint) => EXIT_CONFLICT,
+            Some(StorageErrorKind::Busy) => EXIT_UNAVAILABLE,
+            _ => EXIT_STORAGE_FAILURE,
+        },
+    }
+}
+
+/// Prints an error, including its causes, to stderr.
+fn report_error(err: &HeliaError, output: OutputFormat) {
+    let mut causes = Vec::new();
+    let mut source = std::error::Error::source(err);
+    while let Some(cause) = source {
+        causes.push(cause.to_string());
+        source = cause.source();
+    }
+
+    match output {
+        OutputFormat::Human => {
+            eprintln!("error: {err}");
+            for cause in causes {
+                eprintln!("  caused by: {cause}");
+            }
+        }
+        OutputFormat::Json => eprintln!(
+            "{}",
+            json!({ "error": { "code": exit_code(err), "message": err.to_string(), "causes": causes } })
+        ),
+    }
+}
+
+/// Formats an action as one line of human readable output.
+fn action_line(action: &Action) -> String {
+    let check = if action.is_completed() { 'x' } else { ' ' };
+    let mut line = format!(
+        "{}  [{check}] {}",
+        action.action_id().uuid(),
+        action.action_name().as_str()
+    );
+    if let Some(due_date) = action.action_due_date() {
+        line.push_str(&format!("  (due {})", due_date.value().format("%Y-%m-%d")));
+    }
+    line
+}
+
+/// Formats an action as JSON object.
+fn action_json(action: &Action) -> serde_json::Value {
+    json!({
+        "id": action.action_id().uuid(),
+        "name": action.action_name().as_str(),
+        "created_at": action.action_create_date().value(),
+        "completed_at": action.action_complete_date().map(|date| date.value()),
+        "due_at": action.action_due_date().map(|date| date.value()),
+        "defer_until": action.action_defer_date().map(|date| date.value()),
+        "notes": action.action_notes().map(|notes| notes.as_str()),
+        "recurrence": action.action_recurrence().map(|recurrence| recurrence.to_rrule()),
+        "delegated_to": action.action_delegation().map(|delegation| delegation.delegate()),
+        "follow_up_at": action
+            .action_delegation()
+            .and_then(|delegation| delegation.follow_up_at()),
+        "project_id": action.project_id().map(|id| id.uuid()),
+        "position": action.position(),
+        "area_id": action.area_id().map(|id| id.uuid()),
+        "someday": action.is_someday(),
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_cli_definition_is_valid() {
+        use clap::CommandFactory;
+        Cli::command().debug_assert();
+    }
+
+    #[test]
+    fn test_exit_codes() {
+        let not_found = HeliaError::ActionNotFound {
+            action_id: ActionId::new(),
+        };
+        let conflict = HeliaError::ActionAlreadyCompleted {
+            action_id: ActionId::new(),
+        };
+
+        assert_eq!(exit_code(&not_found), EXIT_NOT_FOUND);
+        assert_eq!(exit_code(&conflict), EXIT_CONFLICT);
+        assert_eq!(exit_code(&HeliaError::DataDirUnavailable), EXIT_UNAVAILABLE);
+    }
+}
@@ -1,28 +1,71 @@
 //! Contains Helia's core API over which her functionality can be accessed.
 
-use std::sync::Once;
+use std::{
+    io::{Read, Write},
+    sync::Once,
+};
+
+use chrono::{DateTime, Utc};
 
-use crate::core::helia_prod::HeliaProd;
+use crate::core::{
+    clock::{FakeClock, SystemClock},
+    config::ProductionConfig,
+    helia_prod::HeliaProd,
+};
 use crate::{
-    core::{helia_error::HeliaError, requests::CreateActionRequest},
-    model::action::Action,
+    core::{
+        helia_error::HeliaError,
+        requests::{
+            ActionQuery, AssignContextRequest, CreateActionRequest, CreateAreaRequest,
+            CreateContextRequest, CreatePerspectiveRequest, CreateProjectRequest, CreateTagRequest,
+            DelegateActionRequest, ListActionsRequest, MergeTagsRequest, MoveActionRequest,
+            ProcessInboxItemRequest, RenameActionRequest, RenameAreaRequest, RenameContextRequest,
+            RenameTagRequest, ReorderActionsRequest, SetActionAreaRequest, SetProjectAreaRequest,
+            TagActionRequest, TagProjectRequest, UpdateActionRequest, UpdatePerspectiveRequest,
+            UpdateProjectRequest,
+        },
+        responses::{
+            AreaSummary, CompletedAction, ImportSummary, ProcessedInboxItem, SearchResult,
+            SomedayMaybeList, StalledProject,
+        },
+    },
+    model::{
+        action::{Action, action_id::ActionId},
+        area::{Area, area_id::AreaId},
+        context::{Context, context_id::ContextId},
+        inbox_item::InboxItem,
+        perspective::{Perspective, perspective_id::PerspectiveId},
+        project::{Project, project_id::ProjectId},
+        reference_item::ReferenceItem,
+        review::{Review, review_step::ReviewStep},
+        tag::{Tag, tag_id::TagId},
+    },
     storage::{self},
 };
 
 // Contains the `Request` types.
 // These are used to issue requests to the API.
 pub mod requests;
+// Contains the `Response` types returned by some API calls.
+pub mod responses;
 // Contains the API error definitions.
 pub mod helia_error;
+// Contains the `Clock` abstraction over the current time.
+pub mod clock;
+// Contains the configuration of production instances.
+pub mod config;
+// Contains the JSON export document format.
+pub mod export;
 // Contains the concrete production implementation of the `HeliaCore` trait.
 mod helia_prod;
 
 /// [`HeliaCore`] is the main way though which the Helia backend can be accessed.
 ///
 /// To access the API, fetch a [`HeliaCore`] instance by calling the [new_production()] function:
-/// ```
-/// use helia_backend::HeliaCore;
-/// let helia_core = helia_backend::new_production().unwrap();
+/// ```no_run
+/// use helia_backend::{HeliaCore, ProductionConfig};
+/// let config = ProductionConfig::with_db_path(std::env::temp_dir().join("helia-doc.db"));
+/// let helia_core = helia_backend::new_production(&config).unwrap();
 /// ```
 pub trait HeliaCore {
     /// Retrieves the storage version of the backend.
@@ -33,28 +76,438 @@ pub trait HeliaCore {
 
     /// Tries to create an [Action].
     fn create_action(&self, request: CreateActionRequest) -> Result<Action, HeliaError>;
+
+    /// Tries to fetch the [Action] with the given [ActionId].
+    fn get_action(&self, action_id: &ActionId) -> Result<Action, HeliaError>;
+
+    /// Fetches the [Action]s matching the request, ordered by their creation date.
+    fn list_actions(&self, request: ListActionsRequest) -> Result<Vec<Action>, HeliaError>;
+
+    /// Fetches the page of [Action]s matching the [ActionQuery], sorted as it asks for.
+    fn query_actions(&self, query: ActionQuery) -> Result<Vec<Action>, HeliaError>;
+
+    /// Parses the textual form of an [ActionQuery], as typed into a search bar, relative to now.
+    ///
+    /// See [ActionQuery::parse] for the syntax. Fails with [HeliaError::InvalidActionQuery] if
+    /// the text can't be parsed.
+    fn parse_action_query(&self, text: &str) -> Result<ActionQuery, HeliaError>;
+
+    /// Tries to rename an [Action].
+    ///
+    /// Returns the renamed action.
+    fn rename_action(&self, request: RenameActionRequest) -> Result<Action, HeliaError>;
+
+    /// Tries to update the details of an [Action], like its due and defer dates.
+    ///
+    /// Returns the updated action.
+    fn update_action(&self, request: UpdateActionRequest) -> Result<Action, HeliaError>;
+
+    /// Fetches the open [Action]s that are neither deferred nor delegated, ordered by their
+    /// creation date.
+    fn list_available_actions(&self) -> Result<Vec<Action>, HeliaError>;
+
+    /// Fetches the open [Action]s that can be worked on next, ordered by their creation date.
+    ///
+    /// These are the available actions, see [HeliaCore::list_available_actions], except that
+    /// only the first open action of a sequential project counts. If that one is deferred or
+    /// delegated, the project has no next action.
+    fn list_next_actions(&self) -> Result<Vec<Action>, HeliaError>;
+
+    /// Fetches the open [Action]s whose due date has passed, ordered by their due date.
+    fn list_overdue_actions(&self) -> Result<Vec<Action>, HeliaError>;
+
+    /// Fetches the open [Action]s that are due within the next `days` days, ordered by their
+    /// due date.
+    ///
+    /// Overdue actions are not included, see [HeliaCore::list_overdue_actions].
+    fn list_actions_due_within(&self, days: u32) -> Result<Vec<Action>, HeliaError>;
+
+    /// Tries to delete the [Action] with the given [ActionId].
+    fn delete_action(&self, action_id: &ActionId) -> Result<(), HeliaError>;
+
+    /// Tries to mark an open [Action] as completed as of now.
+    ///
+    /// If the action repeats, its next occurrence is created in the same step, with due and
+    /// defer dates shifted according to its
+    /// [ActionRecurrence](crate::model::action::action_recurrence::ActionRecurrence). The rule moves on to the next
+    /// occurrence, so the completed action no longer repeats.
+    ///
+    /// Returns the completed action and its next occurrence, if any.
+    fn complete_action(&self, action_id: &ActionId) -> Result<CompletedAction, HeliaError>;
+
+    /// Tries to mark a completed [Action] as open again.
+    ///
+    /// Returns the reopened action.
+    fn reopen_action(&self, action_id: &ActionId) -> Result<Action, HeliaError>;
+
+    /// Tries to delegate an open [Action], putting it on the waiting-for list as of now.
+    ///
+    /// Returns the delegated action.
+    fn delegate_action(&self, request: DelegateActionRequest) -> Result<Action, HeliaError>;
+
+    /// Tries to take a delegated [Action] back, removing it from the waiting-for list.
+    ///
+    /// Returns the reclaimed action.
+    fn reclaim_action(&self, action_id: &ActionId) -> Result<Action, HeliaError>;
+
+    /// Fetches the open, delegated [Action]s, ordered by their follow-up date.
+    ///
+    /// Overdue follow-ups come first and actions without a follow-up date come last.
+    /// Whether a follow-up is due can be checked with `ActionDelegation::is_follow_up_due`.
+    fn list_waiting_for(&self) -> Result<Vec<Action>, HeliaError>;
+
+    /// Fetches the open [Action]s and the [Project]s on the someday/maybe list.
+    ///
+    /// The actions include those belonging to someday/maybe projects.
+    ///
+    /// Someday/maybe items are left out of all other listings.
+    fn list_someday_maybe(&self) -> Result<SomedayMaybeList, HeliaError>;
+
+    /// Moves an open [Action] onto the someday/maybe list.
+    ///
+    /// Demoting an action that is already on the list changes nothing.
+    /// Returns the demoted action.
+    fn demote_action(&self, action_id: &ActionId) -> Result<Action, HeliaError>;
+
+    /// Moves an [Action] off the someday/maybe list, committing to it.
+    ///
+    /// Promoting an action that is not on the list changes nothing. An action of a
+    /// someday/maybe [Project] stays on the list until its project is promoted.
+    /// Returns the promoted action.
+    fn promote_action(&self, action_id: &ActionId) -> Result<Action, HeliaError>;
+
+    /// Moves a [Project] onto the someday/maybe list.
+    ///
+    /// Until the project is promoted again, its actions count as someday/maybe as well.
+    /// Returns the demoted project.
+    fn demote_project(&self, project_id: &ProjectId) -> Result<Project, HeliaError>;
+
+    /// Moves a [Project] off the someday/maybe list, committing to it.
+    ///
+    /// Returns the promoted project.
+    fn promote_project(&self, project_id: &ProjectId) -> Result<Project, HeliaError>;
+
+    /// Tries to move an [Action] into a [Project], or out of its current project.
+    ///
+    /// An action moved into another project is placed behind its last action, and leaves its
+    /// area for the project's.
+    /// Returns the moved action.
+    fn move_action(&self, request: MoveActionRequest) -> Result<Action, HeliaError>;
+
+    /// Tries to create a [Project].
+    fn create_project(&self, request: CreateProjectRequest) -> Result<Project, HeliaError>;
+
+    /// Tries to fetch the [Project] with the given [ProjectId].
+    fn get_project(&self, project_id: &ProjectId) -> Result<Project, HeliaError>;
+
+    /// Fetches all [Project]s, ordered by their creation date.
+    fn list_projects(&self) -> Result<Vec<Project>, HeliaError>;
+
+    /// Fetches the active, committed [Project]s without a next [Action], see
+    /// [HeliaCore::list_next_actions].
+    ///
+    /// Each comes with the date its last action was completed. Projects that have been stalled
+    /// the longest come first, those that never had an action completed before all others.
+    fn stalled_projects(&self) -> Result<Vec<StalledProject>, HeliaError>;
+
+    /// Tries to change the order of the [Action]s within a [Project].
+    ///
+    /// Fails with [HeliaError::ActionNotInProject] if a listed action belongs to another project,
+    /// and with [HeliaError::ValidationFailed] if an action is listed more than once.
+    /// Returns all actions of the project in their new order.
+    fn reorder_actions(&self, request: ReorderActionsRequest) -> Result<Vec<Action>, HeliaError>;
+
+    /// Tries to update the name, status and/or ordering of a [Project].
+    ///
+    /// Returns the updated project.
+    fn update_project(&self, request: UpdateProjectRequest) -> Result<Project, HeliaError>;
+
+    /// Tries to delete the [Project] with the given [ProjectId].
+    ///
+    /// The project's actions are kept as standalone actions.
+    fn delete_project(&self, project_id: &ProjectId) -> Result<(), HeliaError>;
+
+    /// Captures a piece of text into the inbox, to be processed later.
+    ///
+    /// Fails with [HeliaError::ValidationFailed] if the text is empty, longer than
+    /// [InboxItemText::MAX_LEN](crate::model::inbox_item::inbox_item_text::InboxItemText::MAX_LEN)
+    /// characters or spans several lines.
+    fn capture(&self, text: &str) -> Result<InboxItem, HeliaError>;
+
+    /// Fetches all unprocessed [InboxItem]s, ordered by their capture date.
+    fn list_inbox(&self) -> Result<Vec<InboxItem>, HeliaError>;
+
+    /// Tries to process an [InboxItem], turning it into whatever the request specifies.
+    ///
+    /// The inbox item is removed and its replacement is stored in a single transaction.
+    fn process_inbox_item(
+        &self,
+        request: ProcessInboxItemRequest,
+    ) -> Result<ProcessedInboxItem, HeliaError>;
+
+    /// Fetches all [ReferenceItem]s, ordered by their creation date.
+    fn list_reference_items(&self) -> Result<Vec<ReferenceItem>, HeliaError>;
+
+    /// Tries to create a [Context].
+    fn create_context(&self, request: CreateContextRequest) -> Result<Context, HeliaError>;
+
+    /// Fetches all [Context]s, ordered by their name.
+    fn list_contexts(&self) -> Result<Vec<Context>, HeliaError>;
+
+    /// Tries to rename a [Context].
+    ///
+    /// Returns the renamed context.
+    fn rename_context(&self, request: RenameContextRequest) -> Result<Context, HeliaError>;
+
+    /// Tries to delete the [Context] with the given [ContextId].
+    ///
+    /// Fails with [HeliaError::ContextInUse] if any action is still linked to the context.
+    fn delete_context(&self, context_id: &ContextId) -> Result<(), HeliaError>;
+
+    /// Tries to link an [Action] to a [Context].
+    fn assign_context(&self, request: AssignContextRequest) -> Result<(), HeliaError>;
+
+    /// Tries to unlink an [Action] from a [Context].
+    fn unassign_context(&self, request: AssignContextRequest) -> Result<(), HeliaError>;
+
+    /// Fetches the [Context]s an [Action] is linked to, ordered by their name.
+    fn list_action_contexts(&self, action_id: &ActionId) -> Result<Vec<Context>, HeliaError>;
+
+    /// Fetches the open [Action]s linked to the given [Context], ordered by their creation date.
+    ///
+    /// This is the GTD next-action list for that context.
+    fn list_actions_in_context(&self, context_id: &ContextId) -> Result<Vec<Action>, HeliaError>;
+
+    /// Tries to create an [Area].
+    fn create_area(&self, request: CreateAreaRequest) -> Result<Area, HeliaError>;
+
+    /// Tries to fetch the [Area] with the given [AreaId].
+    fn get_area(&self, area_id: &AreaId) -> Result<Area, HeliaError>;
+
+    /// Fetches all [Area]s, ordered by their name.
+    fn list_areas(&self) -> Result<Vec<Area>, HeliaError>;
+
+    /// Tries to rename an [Area].
+    ///
+    /// Returns the renamed area.
+    fn rename_area(&self, request: RenameAreaRequest) -> Result<Area, HeliaError>;
+
+    /// Tries to delete the [Area] with the given [AreaId].
+    ///
+    /// The area's projects and actions are kept without an area.
+    fn delete_area(&self, area_id: &AreaId) -> Result<(), HeliaError>;
+
+    /// Tries to put a [Project] into an [Area], or to take it out of its area.
+    ///
+    /// Returns the updated project.
+    fn set_project_area(&self, request: SetProjectAreaRequest) -> Result<Project, HeliaError>;
+
+    /// Tries to put a standalone [Action] into an [Area], or to take it out of its area.
+    ///
+    /// Actions of a project share the project's area, setting theirs fails with
+    /// [HeliaError::ActionInProject].
+    /// Returns the updated action.
+    fn set_action_area(&self, request: SetActionAreaRequest) -> Result<Action, HeliaError>;
+
+    /// Fetches all [Area]s, ordered by their name, with their number of open actions and of
+    /// stalled projects.
+    ///
+    /// Areas with few open actions or many stalled projects are likely neglected.
+    fn area_summaries(&self) -> Result<Vec<AreaSummary>, HeliaError>;
+
+    /// Tries to create a [Tag].
+    ///
+    /// Its ancestors, like `work` for `work/client-x`, don't need to exist.
+    fn create_tag(&self, request: CreateTagRequest) -> Result<Tag, HeliaError>;
+
+    /// Tries to fetch the [Tag] with the given [TagId].
+    fn get_tag(&self, tag_id: &TagId) -> Result<Tag, HeliaError>;
+
+    /// Fetches all [Tag]s, ordered by their path.
+    fn list_tags(&self) -> Result<Vec<Tag>, HeliaError>;
+
+    /// Tries to give a [Tag] a new path.
+    ///
+    /// Its descendants move along, `work/x` renamed to `job` turns `work/x/y` into `job/y`.
+    /// Fails with [HeliaError::TagPathTaken] if the new path is taken; a moved descendant
+    /// whose new path is taken is merged into the tag at that path instead.
+    /// Returns the renamed tag.
+    fn rename_tag(&self, request: RenameTagRequest) -> Result<Tag, HeliaError>;
+
+    /// Tries to merge a [Tag] into another one.
+    ///
+    /// Items linked to the source tag are linked to the target tag, and descendants of the
+    /// source tag move below the target tag, merging where paths collide. The target must not
+    /// lie within the source.
+    /// Returns the target tag.
+    fn merge_tags(&self, request: MergeTagsRequest) -> Result<Tag, HeliaError>;
+
+    /// Tries to delete the [Tag] with the given [TagId].
+    ///
+    /// Its links are removed, its descendants are kept.
+    fn delete_tag(&self, tag_id: &TagId) -> Result<(), HeliaError>;
+
+    /// Tries to link an [Action] to a [Tag].
+    fn tag_action(&self, request: TagActionRequest) -> Result<(), HeliaError>;
+
+    /// Tries to unlink an [Action] from a [Tag].
+    fn untag_action(&self, request: TagActionRequest) -> Result<(), HeliaError>;
+
+    /// Fetches the [Tag]s an [Action] is linked to, ordered by their path.
+    fn list_action_tags(&self, action_id: &ActionId) -> Result<Vec<Tag>, HeliaError>;
+
+    /// Tries to link a [Project] to a [Tag].
+    fn tag_project(&self, request: TagProjectRequest) -> Result<(), HeliaError>;
+
+    /// Tries to unlink a [Project] from a [Tag].
+    fn untag_project(&self, request: TagProjectRequest) -> Result<(), HeliaError>;
+
+    /// Fetches the [Tag]s a [Project] is linked to, ordered by their path.
+    fn list_project_tags(&self, project_id: &ProjectId) -> Result<Vec<Tag>, HeliaError>;
+
+    /// Fetches the open [Action]s linked to the given [Tag] or to one of its descendants,
+    /// ordered by their creation date.
+    fn list_actions_with_tag(&self, tag_id: &TagId) -> Result<Vec<Action>, HeliaError>;
+
+    /// Fetches the [Project]s linked to the given [Tag] or to one of its descendants, ordered
+    /// by their creation date.
+    fn list_projects_with_tag(&self, tag_id: &TagId) -> Result<Vec<Project>, HeliaError>;
+
+    /// Tries to create a [Perspective].
+    ///
+    /// Fails with [HeliaError::InvalidActionQuery] if its query can't be parsed.
+    fn create_perspective(
+        &self,
+        request: CreatePerspectiveRequest,
+    ) -> Result<Perspective, HeliaError>;
+
+    /// Tries to fetch the [Perspective] with the given [PerspectiveId].
+    fn get_perspective(&self, perspective_id: &PerspectiveId) -> Result<Perspective, HeliaError>;
+
+    /// Fetches all [Perspective]s, ordered by their name.
+    fn list_perspectives(&self) -> Result<Vec<Perspective>, HeliaError>;
+
+    /// Tries to change the name or the query of a [Perspective].
+    ///
+    /// Fails with [HeliaError::InvalidActionQuery] if the new query can't be parsed.
+    /// Returns the updated perspective.
+    fn update_perspective(
+        &self,
+        request: UpdatePerspectiveRequest,
+    ) -> Result<Perspective, HeliaError>;
+
+    /// Tries to delete the [Perspective] with the given [PerspectiveId].
+    fn delete_perspective(&self, perspective_id: &PerspectiveId) -> Result<(), HeliaError>;
+
+    /// Fetches the [Action]s selected by the query of the [Perspective] with the given
+    /// [PerspectiveId].
+    ///
+    /// Relative dates in the query, like `today`, are resolved as of now.
+    fn evaluate_perspective(
+        &self,
+        perspective_id: &PerspectiveId,
+    ) -> Result<Vec<Action>, HeliaError>;
+
+    /// Starts a weekly [Review] as of now, or resumes the one still in progress.
+    ///
+    /// Returns the review, whose [Review::next_step] tells what to do next.
+    fn start_review(&self) -> Result<Review, HeliaError>;
+
+    /// Fetches the [Review] still in progress, if any.
+    fn current_review(&self) -> Result<Option<Review>, HeliaError>;
+
+    /// Records that the given step of the [Review] in progress was completed as of now.
+    ///
+    /// Steps have to be completed in the order of [ReviewStep::ALL]. Completing the last step
+    /// completes the review.
+    /// Returns the updated review.
+    fn complete_review_step(&self, step: ReviewStep) -> Result<Review, HeliaError>;
+
+    /// Returns when the last weekly [Review] was completed, if ever.
+    fn last_reviewed_at(&self) -> Result<Option<DateTime<Utc>>, HeliaError>;
+
+    /// Records that a [Project] was reviewed as of now.
+    ///
+    /// Returns the reviewed project, see [Project::project_review_date].
+    fn mark_project_reviewed(&self, project_id: &ProjectId) -> Result<Project, HeliaError>;
+
+    /// Searches [Action]s, [Project]s and [ReferenceItem]s for the words in `text`, best
+    /// matches first.
+    ///
+    /// Names and notes of actions, names of projects and texts of reference items are searched,
+    /// matches in names rank higher. Every word has to match the start of a word, `rep` finds
+    /// `Write report`. Diacritics and case are ignored.
+    fn search(&self, text: &str) -> Result<Vec<SearchResult>, HeliaError>;
+
+    /// Reverts the last operation that changed something and is not undone yet, in one
+    /// transaction.
+    ///
+    /// Every operation changing data is recorded in a history persisted with the data, the last
+    /// 100 of them can be undone. Fewer are kept when they changed more than 100,000 rows in
+    /// total, but the last operation can always be undone. Operations that are undone can be redone until the next
+    /// operation changes something.
+    /// Returns the name of the undone operation, like `Complete action`, or `None` if there is
+    /// nothing to undo.
+    fn undo(&self) -> Result<Option<String>, HeliaError>;
+
+    /// Reapplies the operation that was undone last, in one transaction.
+    ///
+    /// Returns the name of the redone operation, or `None` if there is nothing to redo, see
+    /// [HeliaCore::undo].
+    fn redo(&self) -> Result<Option<String>, HeliaError>;
+
+    /// Writes the whole content of the database as a versioned JSON document.
+    ///
+    /// The document can be read back with [HeliaCore::import_json].
+    fn export_json(&self, writer: &mut dyn Write) -> Result<(), HeliaError>;
+
+    /// Reads a JSON document written by [HeliaCore::export_json] and adds its content.
+    ///
+    /// The document is validated before anything is written, and the import runs in one
+    /// transaction: if anything fails, e.g. because an item already exists, the database is
+    /// left untouched.
+    fn import_json(&self, reader: &mut dyn Read) -> Result<ImportSummary, HeliaError>;
 }
 
 /// Factory function creating a new [HeliaCore] instance ready for production.
-pub fn new_production() -> Result<impl HeliaCore, HeliaError> {
-    let storage_result = storage::new_production_storage("helia.db");
+///
+/// The database location is taken from the given [ProductionConfig].
+pub fn new_production(config: &ProductionConfig) -> Result<impl HeliaCore + use<>, HeliaError> {
+    let db_path = config.prepare_db_path()?;
+    let storage_result = storage::new_production_storage(&db_path);
     let storage = match storage_result {
         Ok(storage) => storage,
-        Err(err) => return Err(HeliaError::StorageConnectionFailed { storage_err: err }),
+        Err(err) => {
+            return Err(HeliaError::OpeningDatabaseFailed {
+                path: db_path,
+                storage_err: err,
+            });
+        }
     };
 
-    Ok(HeliaProd::new(storage))
+    Ok(HeliaProd::new(storage, SystemClock))
 }
 
 /// Factory function creating a new [HeliaCore] instance for testing purposes (in-memory).
+///
+/// Time is provided by a [FakeClock] standing still at the moment of creation.
+/// Use [new_testing_with_clock()] to control the time from the outside.
 pub fn new_testing() -> Result<impl HeliaCore, HeliaError> {
+    new_testing_with_clock(FakeClock::default())
+}
+
+/// Factory function creating a new [HeliaCore] instance for testing purposes (in-memory),
+/// reading the current time from the given [FakeClock].
+///
+/// Keep a clone of the clock to set or advance the time seen by the core.
+pub fn new_testing_with_clock(clock: FakeClock) -> Result<impl HeliaCore, HeliaError> {
     let storage_result = storage::new_in_memory_storage();
     let storage = match storage_result {
         Ok(storage) => storage,
         Err(err) => return Err(HeliaError::StorageConnectionFailed { storage_err: err }),
     };
 
-    Ok(HeliaProd::new(storage))
+    Ok(HeliaProd::new(storage, clock))
 }
 
 /// Initiates logging for the Helia backend.
@@ -71,9 +524,25 @@ pub fn init_logging() {
 
 #[cfg(test)]
 mod tests {
-    use crate::core;
+    use chrono::{Duration, TimeZone, Utc};
+
+    use crate::{
+        core::{
+            self,
+            requests::{
+                ActionPredicate, ActionSortKey, ActionStatusFilter, InboxItemOutcome, SortDirection,
+            },
+            responses::SearchItem,
+        },
+        model::{
+            project::{project_ordering::ProjectOrdering, project_status::ProjectStatus},
+            tag::tag_path::TagPath,
+        },
+    };
 
     use super::*;
+    use crate::model::validation::{FieldError, Violation};
+    use crate::storage::storage_error::{StorageError, StorageErrorKind};
 
     #[test]
     fn test_storage_version_for_new_storage_is_0() {
@@ -81,4 +550,1950 @@ mod tests {
         let storage_version = helia_core.storage_version().unwrap();
         assert_eq!(storage_version, 0);
     }
+
+    #[test]
+    fn test_action_lifecycle() {
+        let mut helia_core = core::new_testing().unwrap();
+        helia_core.run_migrations().unwrap();
+
+        let action = helia_core
+            .create_action(CreateActionRequest {
+                name: String::from("Call Bob"),
+                ..Default::default()
+            })
+            .unwrap();
+        assert_eq!(helia_core.get_action(action.action_id()).unwrap(), action);
+        assert_eq!(
+            helia_core
+                .list_actions(ListActionsRequest::default())
+                .unwrap(),
+            vec![action.clone()]
+        );
+
+        let renamed = helia_core
+            .rename_action(RenameActionRequest {
+                action_id: action.action_id().clone(),
+                name: String::from("Call Alice"),
+            })
+            .unwrap();
+        assert_eq!(renamed.action_name().as_str(), "Call Alice");
+        assert_eq!(helia_core.get_action(action.action_id()).unwrap(), renamed);
+
+        helia_core.delete_action(action.action_id()).unwrap();
+        assert!(
+            helia_core
+                .list_actions(ListActionsRequest::default())
+                .unwrap()
+                .is_empty()
+        );
+    }
+
+    #[test]
+    fn test_complete_and_reopen_action() {
+        let mut helia_core = core::new_testing().unwrap();
+        helia_core.run_migrations().unwrap();
+
+        let action = helia_core
+            .create_action(CreateActionRequest {
+                name: String::from("File taxes"),
+                ..Default::default()
+            })
+            .unwrap();
+        let open = ListActionsRequest {
+            status: ActionStatusFilter::Open,
+            ..Default::default()
+        };
+        let completed = ListActionsRequest {
+            status: ActionStatusFilter::Completed,
+            ..Default::default()
+        };
+
+        let completed_action = helia_core.complete_action(action.action_id()).unwrap();
+        assert_eq!(completed_action.next_occurrence, None);
+        let completed_action = completed_action.action;
+        assert!(completed_action.is_completed());
+        assert!(matches!(
+            helia_core.complete_action(action.action_id()),
+            Err(HeliaError::ActionAlreadyCompleted { .. })
+        ));
+        assert!(helia_core.list_actions(open.clone()).unwrap().is_empty());
+        assert_eq!(
+            helia_core.list_actions(completed.clone()).unwrap(),
+            vec![completed_action]
+        );
+
+        let reopened_action = helia_core.reopen_action(action.action_id()).unwrap();
+        assert!(!reopened_action.is_completed());
+        assert!(matches!(
+            helia_core.reopen_action(action.action_id()),
+            Err(HeliaError::ActionNotCompleted { .. })
+        ));
+        assert_eq!(
+            helia_core.list_actions(open).unwrap(),
+            vec![reopened_action]
+        );
+        assert!(helia_core.list_actions(completed).unwrap().is_empty());
+    }
+
+    #[test]
+    fn test_unknown_action_is_not_found() {
+        let mut helia_core = core::new_testing().unwrap();
+        helia_core.run_migrations().unwrap();
+
+        let action_id = ActionId::new();
+        assert!(matches!(
+            helia_core.get_action(&action_id),
+            Err(HeliaError::ActionNotFound { .. })
+        ));
+        assert!(matches!(
+            helia_core.rename_action(RenameActionRequest {
+                action_id: action_id.clone(),
+                name: String::from("Nothing"),
+            }),
+            Err(HeliaError::ActionNotFound { .. })
+        ));
+        assert!(matches!(
+            helia_core.delete_action(&action_id),
+            Err(HeliaError::ActionNotFound { .. })
+        ));
+    }
+
+    #[test]
+    fn test_project_lifecycle() {
+        let mut helia_core = core::new_testing().unwrap();
+        helia_core.run_migrations().unwrap();
+
+        let project = helia_core
+            .create_project(CreateProjectRequest {
+                name: String::from("Move to Berlin"),
+                ..Default::default()
+            })
+            .unwrap();
+        assert_eq!(project.project_status(), ProjectStatus::Active);
+        assert_eq!(helia_core.list_projects().unwrap(), vec![project.clone()]);
+
+        let updated = helia_core
+            .update_project(UpdateProjectRequest {
+                project_id: project.project_id().clone(),
+                name: Some(String::from("Move to Hamburg")),
+                status: Some(ProjectStatus::OnHold),
+                ordering: None,
+            })
+            .unwrap();
+        assert_eq!(updated.project_name().as_str(), "Move to Hamburg");
+        assert_eq!(updated.project_status(), ProjectStatus::OnHold);
+        assert_eq!(
+            helia_core.get_project(project.project_id()).unwrap(),
+            updated
+        );
+
+        helia_core.delete_project(project.project_id()).unwrap();
+        assert!(matches!(
+            helia_core.get_project(project.project_id()),
+            Err(HeliaError::ProjectNotFound { .. })
+        ));
+    }
+
+    #[test]
+    fn test_move_action_between_projects() {
+        let mut helia_core = core::new_testing().unwrap();
+        helia_core.run_migrations().unwrap();
+
+        let project_1 = helia_core
+            .create_project(CreateProjectRequest {
+                name: String::from("Project 1"),
+                ..Default::default()
+            })
+            .unwrap();
+        let project_2 = helia_core
+            .create_project(CreateProjectRequest {
+                name: String::from("Project 2"),
+                ..Default::default()
+            })
+            .unwrap();
+        let action = helia_core
+            .create_action(CreateActionRequest {
+                name: String::from("Write plan"),
+                project_id: Some(project_1.project_id().clone()),
+                ..Default::default()
+            })
+            .unwrap();
+        assert_eq!(action.project_id(), Some(project_1.project_id()));
+
+        let moved = helia_core
+            .move_action(MoveActionRequest {
+                action_id: action.action_id().clone(),
+                project_id: Some(project_2.project_id().clone()),
+            })
+            .unwrap();
+        assert_eq!(moved.project_id(), Some(project_2.project_id()));
+        let in_project_2 = helia_core
+            .list_actions(ListActionsRequest {
+                project_id: Some(project_2.project_id().clone()),
+                ..Default::default()
+            })
+            .unwrap();
+        assert_eq!(in_project_2, vec![moved]);
+
+        assert!(matches!(
+            helia_core.move_action(MoveActionRequest {
+                action_id: action.action_id().clone(),
+                project_id: Some(ProjectId::new()),
+            }),
+            Err(HeliaError::ProjectNotFound { .. })
+        ));
+    }
+
+    #[test]
+    fn test_process_inbox_item_into_each_outcome() {
+        let mut helia_core = core::new_testing().unwrap();
+        helia_core.run_migrations().unwrap();
+
+        let capture_and_process = |outcome: InboxItemOutcome| {
+            let inbox_item = helia_core.capture("Plan vacation").unwrap();
+            helia_core
+                .process_inbox_item(ProcessInboxItemRequest {
+                    inbox_item_id: inbox_item.inbox_item_id().clone(),
+                    name: None,
+                    outcome,
+                })
+                .unwrap()
+        };
+
+        let ProcessedInboxItem::Project(project) = capture_and_process(InboxItemOutcome::Project)
+        else {
+            panic!("Expected a project.");
+        };
+        assert_eq!(project.project_name().as_str(), "Plan vacation");
+
+        let ProcessedInboxItem::Action(action) = capture_and_process(InboxItemOutcome::Action {
+            project_id: Some(project.project_id().clone()),
+        }) else {
+            panic!("Expected an action.");
+        };
+        assert_eq!(action.project_id(), Some(project.project_id()));
+        assert!(!action.is_someday());
+
+        let ProcessedInboxItem::SomedayMaybe(someday) =
+            capture_and_process(InboxItemOutcome::SomedayMaybe)
+        else {
+            panic!("Expected a someday/maybe action.");
+        };
+        assert!(someday.is_someday());
+
+        let ProcessedInboxItem::Reference(reference_item) =
+            capture_and_process(InboxItemOutcome::Reference)
+        else {
+            panic!("Expected a reference item.");
+        };
+        assert_eq!(
+            helia_core.list_reference_items().unwrap(),
+            vec![reference_item]
+        );
+
+        assert_eq!(
+            capture_and_process(InboxItemOutcome::Trash),
+            ProcessedInboxItem::Trashed
+        );
+        assert!(helia_core.list_inbox().unwrap().is_empty());
+    }
+
+    #[test]
+    fn test_capture_rejects_blank_text() {
+        let mut helia_core = core::new_testing().unwrap();
+        helia_core.run_migrations().unwrap();
+
+        for text in ["", " \t "] {
+            assert!(matches!(
+                helia_core.capture(text),
+                Err(HeliaError::ValidationFailed { .. })
+            ));
+        }
+        assert!(helia_core.list_inbox().unwrap().is_empty());
+        assert_eq!(
+            helia_core
+                .capture(" Buy milk ")
+                .unwrap()
+                .inbox_item_text()
+                .as_str(),
+            "Buy milk"
+        );
+    }
+
+    #[test]
+    fn test_process_inbox_item_with_unknown_project_keeps_item() {
+        let mut helia_core = core::new_testing().unwrap();
+        helia_core.run_migrations().unwrap();
+
+        let inbox_item = helia_core.capture("Buy milk").unwrap();
+        let result = helia_core.process_inbox_item(ProcessInboxItemRequest {
+            inbox_item_id: inbox_item.inbox_item_id().clone(),
+            name: Some(String::from("Buy oat milk")),
+            outcome: InboxItemOutcome::Action {
+                project_id: Some(ProjectId::new()),
+            },
+        });
+        assert!(matches!(result, Err(HeliaError::ProjectNotFound { .. })));
+        assert_eq!(helia_core.list_inbox().unwrap(), vec![inbox_item]);
+    }
+
+    #[test]
+    fn test_list_actions_in_context() {
+        let mut helia_core = core::new_testing().unwrap();
+        helia_core.run_migrations().unwrap();
+
+        let phone = helia_core
+            .create_context(CreateContextRequest {
+                name: String::from("@phone"),
+            })
+            .unwrap();
+        let call = helia_core
+            .create_action(CreateActionRequest {
+                name: String::from("Call mom"),
+                ..Default::default()
+            })
+            .unwrap();
+        let done_call = helia_core
+            .create_action(CreateActionRequest {
+                name: String::from("Call dad"),
+                ..Default::default()
+            })
+            .unwrap();
+        helia_core
+            .create_action(CreateActionRequest {
+                name: String::from("Mow lawn"),
+                ..Default::default()
+            })
+            .unwrap();
+        for action in [&call, &done_call] {
+            helia_core
+                .assign_context(AssignContextRequest {
+                    action_id: action.action_id().clone(),
+                    context_id: phone.context_id().clone(),
+                })
+                .unwrap();
+        }
+        helia_core.complete_action(done_call.action_id()).unwrap();
+
+        assert_eq!(
+            helia_core
+                .list_actions_in_context(phone.context_id())
+                .unwrap(),
+            vec![call.clone()]
+        );
+        assert_eq!(
+            helia_core.list_action_contexts(call.action_id()).unwrap(),
+            vec![phone]
+        );
+    }
+
+    #[test]
+    fn test_delete_context_in_use_is_rejected() {
+        let mut helia_core = core::new_testing().unwrap();
+        helia_core.run_migrations().unwrap();
+
+        let home = helia_core
+            .create_context(CreateContextRequest {
+                name: String::from("@home"),
+            })
+            .unwrap();
+        let action = helia_core
+            .create_action(CreateActionRequest {
+                name: String::from("Water plants"),
+                ..Default::default()
+            })
+            .unwrap();
+        let assignment = || AssignContextRequest {
+            action_id: action.action_id().clone(),
+            context_id: home.context_id().clone(),
+        };
+        helia_core.assign_context(assignment()).unwrap();
+
+        assert!(matches!(
+            helia_core.delete_context(home.context_id()),
+            Err(HeliaError::ContextInUse {
+                action_count: 1,
+                ..
+            })
+        ));
+
+        helia_core.unassign_context(assignment()).unwrap();
+        assert!(matches!(
+            helia_core.unassign_context(assignment()),
+            Err(HeliaError::ContextNotAssigned { .. })
+        ));
+        helia_core.delete_context(home.context_id()).unwrap();
+        assert!(helia_core.list_contexts().unwrap().is_empty());
+    }
+
+    #[test]
+    fn test_date_based_action_lists() {
+        let now = Utc.with_ymd_and_hms(2024, 5, 6, 12, 0, 0).unwrap();
+        let mut helia_core = core::new_testing_with_clock(FakeClock::new(now)).unwrap();
+        helia_core.run_migrations().unwrap();
+
+        let overdue = helia_core
+            .create_action(CreateActionRequest {
+                name: String::from("Pay rent"),
+                due_date: Some(now - Duration::days(2)),
+                ..Default::default()
+            })
+            .unwrap();
+        let due_soon = helia_core
+            .create_action(CreateActionRequest {
+                name: String::from("Renew passport"),
+                due_date: Some(now + Duration::days(3)),
+                ..Default::default()
+            })
+            .unwrap();
+        let deferred = helia_core
+            .create_action(CreateActionRequest {
+                name: String::from("Plant tomatoes"),
+                defer_date: Some(now + Duration::days(30)),
+                ..Default::default()
+            })
+            .unwrap();
+
+        assert_eq!(
+            helia_core.list_overdue_actions().unwrap(),
+            vec![overdue.clone()]
+        );
+        assert_eq!(
+            helia_core.list_actions_due_within(7).unwrap(),
+            vec![due_soon.clone()]
+        );
+        assert!(helia_core.list_actions_due_within(1).unwrap().is_empty());
+        assert_eq!(
+            helia_core.list_available_actions().unwrap(),
+            vec![overdue, due_soon]
+        );
+
+        let undeferred = helia_core
+            .update_action(UpdateActionRequest {
+                action_id: deferred.action_id().clone(),
+                defer_date: Some(None),
+                ..Default::default()
+            })
+            .unwrap();
+        assert_eq!(undeferred.action_defer_date(), None);
+        assert_eq!(helia_core.list_available_actions().unwrap().len(), 3);
+    }
+
+    #[test]
+    fn test_dates_come_from_clock() {
+        let start = Utc.with_ymd_and_hms(2024, 5, 6, 12, 0, 0).unwrap();
+        let clock = FakeClock::new(start);
+        let mut helia_core = core::new_testing_with_clock(clock.clone()).unwrap();
+        helia_core.run_migrations().unwrap();
+
+        let action = helia_core
+            .create_action(CreateActionRequest {
+                name: String::from("Call mom"),
+                ..Default::default()
+            })
+            .unwrap();
+        assert_eq!(action.action_create_date().value(), &start);
+
+        clock.advance(Duration::hours(3));
+        let completed = helia_core
+            .complete_action(action.action_id())
+            .unwrap()
+            .action;
+        assert_eq!(
+            completed.action_complete_date().unwrap().value(),
+            &(start + Duration::hours(3))
+        );
+    }
+
+    #[test]
+    fn test_deferred_action_becomes_available() {
+        let start = Utc.with_ymd_and_hms(2024, 5, 6, 12, 0, 0).unwrap();
+        let clock = FakeClock::new(start);
+        let mut helia_core = core::new_testing_with_clock(clock.clone()).unwrap();
+        helia_core.run_migrations().unwrap();
+
+        let action = helia_core
+            .create_action(CreateActionRequest {
+                name: String::from("File taxes"),
+                defer_date: Some(start + Duration::days(1)),
+                due_date: Some(start + Duration::days(5)),
+                ..Default::default()
+            })
+            .unwrap();
+        assert!(helia_core.list_available_actions().unwrap().is_empty());
+
+        clock.advance(Duration::days(1));
+        assert_eq!(
+            helia_core.list_available_actions().unwrap(),
+            vec![action.clone()]
+        );
+        assert!(helia_core.list_overdue_actions().unwrap().is_empty());
+
+        clock.advance(Duration::days(5));
+        assert_eq!(helia_core.list_overdue_actions().unwrap(), vec![action]);
+    }
+
+    #[test]
+    fn test_export_import_round_trip() {
+        let mut source = core::new_testing().unwrap();
+        source.run_migrations().unwrap();
+        let project = source
+            .create_project(CreateProjectRequest {
+                name: String::from("Move house"),
+                ..Default::default()
+            })
+            .unwrap();
+        let context = source
+            .create_context(CreateContextRequest {
+                name: String::from("@phone"),
+            })
+            .unwrap();
+        let action = source
+            .create_action(CreateActionRequest {
+                name: String::from("Call movers"),
+                project_id: Some(project.project_id().clone()),
+                ..Default::default()
+            })
+            .unwrap();
+        source
+            .assign_context(AssignContextRequest {
+                action_id: action.action_id().clone(),
+                context_id: context.context_id().clone(),
+            })
+            .unwrap();
+        source.capture("Buy boxes").unwrap();
+        source.start_review().unwrap();
+        for step in ReviewStep::ALL {
+            source.complete_review_step(step).unwrap();
+        }
+        source.start_review().unwrap();
+        source.complete_review_step(ReviewStep::EmptyInbox).unwrap();
+
+        let mut json = Vec::new();
+        source.export_json(&mut json).unwrap();
+
+        let mut target = core::new_testing().unwrap();
+        target.run_migrations().unwrap();
+        let summary = target.import_json(&mut json.as_slice()).unwrap();
+        assert_eq!(
+            summary,
+            ImportSummary {
+                areas: 0,
+                actions: 1,
+                projects: 1,
+                contexts: 1,
+                tags: 0,
+                perspectives: 0,
+                reviews: 2,
+                inbox_items: 1,
+                reference_items: 0,
+            }
+        );
+        assert_eq!(
+            target.get_action(action.action_id()).unwrap(),
+            action.clone()
+        );
+        assert_eq!(target.list_projects().unwrap(), vec![project]);
+        assert_eq!(
+            target.list_action_contexts(action.action_id()).unwrap(),
+            vec![context]
+        );
+        assert_eq!(target.list_inbox().unwrap(), source.list_inbox().unwrap());
+        assert_eq!(
+            target.last_reviewed_at().unwrap(),
+            source.last_reviewed_at().unwrap()
+        );
+        assert_eq!(
+            target.current_review().unwrap(),
+            source.current_review().unwrap()
+        );
+    }
+
+    #[test]
+    fn test_failed_import_changes_nothing() {
+        let mut helia_core = core::new_testing().unwrap();
+        helia_core.run_migrations().unwrap();
+        helia_core
+            .create_action(CreateActionRequest {
+                name: String::from("Already here"),
+                ..Default::default()
+            })
+            .unwrap();
+        let mut json = Vec::new();
+        helia_core.export_json(&mut json).unwrap();
+
+        // The document additionally holds a new project, but its action already exists.
+        let mut document: serde_json::Value = serde_json::from_slice(&json).unwrap();
+        document["projects"] = serde_json::json!([{
+            "id": "5f0c2a3e-8f3e-4d5b-9a59-6f1d2c3b4a5e",
+            "name": "New project",
+            "created_at": "2024-01-01T00:00:00Z",
+            "status": "active"
+        }]);
+        let json = serde_json::to_vec(&document).unwrap();
+
+        assert!(matches!(
+            helia_core.import_json(&mut json.as_slice()),
+            Err(HeliaError::ImportFailed { .. })
+        ));
+        assert!(helia_core.list_projects().unwrap().is_empty());
+        assert_eq!(
+            helia_core
+                .list_actions(ListActionsRequest::default())
+                .unwrap()
+                .len(),
+            1
+        );
+    }
+
+    #[test]
+    fn test_storage_errors_are_classified() {
+        let mut helia_core = core::new_testing().unwrap();
+        helia_core.run_migrations().unwrap();
+        let request = CreateContextRequest {
+            name: String::from("@home"),
+        };
+        helia_core.create_context(request.clone()).unwrap();
+
+        let err = helia_core.create_context(request).unwrap_err();
+        assert!(matches!(err, HeliaError::CreateContextFailed { .. }));
+        assert_eq!(
+            err.storage_error().map(StorageError::kind),
+            Some(StorageErrorKind::Constraint)
+        );
+    }
+
+    #[test]
+    fn test_invalid_action_names_are_rejected() {
+        let mut helia_core = core::new_testing().unwrap();
+        helia_core.run_migrations().unwrap();
+
+        let err = helia_core
+            .create_action(CreateActionRequest {
+                name: String::from("   "),
+                ..Default::default()
+            })
+            .unwrap_err();
+        match err {
+            HeliaError::ValidationFailed { errors } => {
+                assert_eq!(errors, vec![FieldError::new("name", Violation::Empty)]);
+            }
+            other => panic!("unexpected error: {other:?}"),
+        }
+        assert!(
+            helia_core
+                .list_actions(ListActionsRequest::default())
+                .unwrap()
+                .is_empty()
+        );
+
+        let action = helia_core
+            .create_action(CreateActionRequest {
+                name: String::from("  Water plants  "),
+                ..Default::default()
+            })
+            .unwrap();
+        assert_eq!(action.action_name().as_str(), "Water plants");
+
+        let err = helia_core
+            .rename_action(RenameActionRequest {
+                action_id: action.action_id().clone(),
+                name: String::from("Water\nplants"),
+            })
+            .unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "Invalid input: name must not contain control characters"
+        );
+        assert_eq!(helia_core.get_action(action.action_id()).unwrap(), action);
+    }
+
+    #[test]
+    fn test_action_notes() {
+        let mut helia_core = core::new_testing().unwrap();
+        helia_core.run_migrations().unwrap();
+
+        let notes = "Call **Bob**: +49 30 1234\n\nSee https://example.com/offer.\n";
+        let action = helia_core
+            .create_action(CreateActionRequest {
+                name: String::from("Discuss offer"),
+                notes: Some(String::from(notes)),
+                ..Default::default()
+            })
+            .unwrap();
+        let fetched = helia_core.get_action(action.action_id()).unwrap();
+        assert_eq!(fetched.action_notes().unwrap().as_str(), notes);
+        assert_eq!(
+            fetched.action_notes().unwrap().urls(),
+            vec!["https://example.com/offer"]
+        );
+
+        let updated = helia_core
+            .update_action(UpdateActionRequest {
+                action_id: action.action_id().clone(),
+                notes: Some(None),
+                ..Default::default()
+            })
+            .unwrap();
+        assert_eq!(updated.action_notes(), None);
+        assert_eq!(helia_core.get_action(action.action_id()).unwrap(), updated);
+    }
+
+    #[test]
+    fn test_completing_recurring_action_creates_next_occurrence() {
+        use crate::model::action::action_recurrence::{
+            ActionRecurrence, Frequency, RecurrenceBase,
+        };
+
+        let start = Utc.with_ymd_and_hms(2024, 3, 1, 8, 0, 0).unwrap();
+        let clock = FakeClock::new(start);
+        let mut helia_core = core::new_testing_with_clock(clock.clone()).unwrap();
+        helia_core.run_migrations().unwrap();
+        let context = helia_core
+            .create_context(CreateContextRequest {
+                name: String::from("@home"),
+            })
+            .unwrap();
+        let due = Utc.with_ymd_and_hms(2024, 3, 4, 18, 0, 0).unwrap();
+        let action = helia_core
+            .create_action(CreateActionRequest {
+                name: String::from("Take out the trash"),
+                due_date: Some(due),
+                defer_date: Some(due - Duration::hours(12)),
+                recurrence: Some(ActionRecurrence::new(Frequency::Weekly).count(2)),
+                ..Default::default()
+            })
+            .unwrap();
+        helia_core
+            .assign_context(AssignContextRequest {
+                action_id: action.action_id().clone(),
+                context_id: context.context_id().clone(),
+            })
+            .unwrap();
+
+        // Completed late, the schedule is kept.
+        clock.set(due + Duration::days(2));
+        let completed = helia_core.complete_action(action.action_id()).unwrap();
+        assert_eq!(completed.action.action_recurrence(), None);
+        let next = completed.next_occurrence.unwrap();
+        assert_eq!(
+            next.action_due_date().unwrap().value(),
+            &(due + Duration::weeks(1))
+        );
+        assert_eq!(
+            next.action_defer_date().unwrap().value(),
+            &(due + Duration::weeks(1) - Duration::hours(12))
+        );
+        assert_eq!(helia_core.get_action(next.action_id()).unwrap(), next);
+        assert_eq!(
+            helia_core.list_action_contexts(next.action_id()).unwrap(),
+            vec![context]
+        );
+
+        // The last occurrence by count has no successor.
+        let completed = helia_core.complete_action(next.action_id()).unwrap();
+        assert_eq!(completed.next_occurrence, None);
+
+        let action = helia_core
+            .create_action(CreateActionRequest {
+                name: String::from("Water plants"),
+                due_date: Some(due),
+                recurrence: Some(
+                    ActionRecurrence::new(Frequency::Daily)
+                        .with_interval(3)
+                        .based_on(RecurrenceBase::Completion),
+                ),
+                ..Default::default()
+            })
+            .unwrap();
+        let completed_at = due + Duration::days(1) - Duration::hours(10);
+        clock.set(completed_at);
+        let next = helia_core
+            .complete_action(action.action_id())
+            .unwrap()
+            .next_occurrence
+            .unwrap();
+        // Three days after completion, at the original time of day.
+        assert_eq!(
+            next.action_due_date().unwrap().value(),
+            &Utc.with_ymd_and_hms(2024, 3, 8, 18, 0, 0).unwrap()
+        );
+    }
+
+    #[test]
+    fn test_invalid_recurrence_is_rejected() {
+        use crate::model::action::action_recurrence::{ActionRecurrence, Frequency};
+
+        let mut helia_core = core::new_testing().unwrap();
+        helia_core.run_migrations().unwrap();
+        let result = helia_core.create_action(CreateActionRequest {
+            name: String::from("Pay rent"),
+            recurrence: Some(ActionRecurrence::new(Frequency::Yearly).with_month_days(&[1])),
+            ..Default::default()
+        });
+        assert!(matches!(
+            result,
+            Err(HeliaError::ValidationFailed { errors })
+                if errors == vec![FieldError::new(
+                    "recurrence",
+                    Violation::Invalid("days of the month can only be used by monthly rules")
+                )]
+        ));
+    }
+
+    #[test]
+    fn test_waiting_for() {
+        let start = Utc.with_ymd_and_hms(2024, 3, 1, 8, 0, 0).unwrap();
+        let clock = FakeClock::new(start);
+        let mut helia_core = core::new_testing_with_clock(clock.clone()).unwrap();
+        helia_core.run_migrations().unwrap();
+        let create = |name: &str| {
+            helia_core
+                .create_action(CreateActionRequest {
+                    name: String::from(name),
+                    ..Default::default()
+                })
+                .unwrap()
+        };
+        let report = create("Quarterly report");
+        let quote = create("Roofing quote");
+        let groceries = create("Buy groceries");
+
+        let report = helia_core
+            .delegate_action(DelegateActionRequest {
+                action_id: report.action_id().clone(),
+                delegate: String::from(" Alice "),
+                follow_up_date: Some(start + Duration::days(7)),
+            })
+            .unwrap();
+        let delegation = report.action_delegation().unwrap();
+        assert_eq!(delegation.delegate(), "Alice");
+        assert_eq!(delegation.delegated_at(), &start);
+        let quote = helia_core
+            .delegate_action(DelegateActionRequest {
+                action_id: quote.action_id().clone(),
+                delegate: String::from("Roofer"),
+                follow_up_date: Some(start + Duration::days(1)),
+            })
+            .unwrap();
+
+        // Delegated actions are no next actions.
+        assert_eq!(
+            helia_core.list_available_actions().unwrap(),
+            vec![groceries.clone()]
+        );
+
+        clock.advance(Duration::days(2));
+        let waiting_for = helia_core.list_waiting_for().unwrap();
+        assert_eq!(waiting_for, vec![quote.clone(), report.clone()]);
+        let overdue: Vec<_> = waiting_for
+            .iter()
+            .filter(|action| {
+                action
+                    .action_delegation()
+                    .unwrap()
+                    .is_follow_up_due(start + Duration::days(2))
+            })
+            .collect();
+        assert_eq!(overdue, vec![&quote]);
+
+        let reclaimed = helia_core.reclaim_action(quote.action_id()).unwrap();
+        assert!(!reclaimed.is_delegated());
+        assert_eq!(helia_core.list_waiting_for().unwrap(), vec![report]);
+        assert!(matches!(
+            helia_core.reclaim_action(quote.action_id()),
+            Err(HeliaError::ActionNotDelegated { .. })
+        ));
+    }
+
+    #[test]
+    fn test_delegation_is_validated() {
+        let mut helia_core = core::new_testing().unwrap();
+        helia_core.run_migrations().unwrap();
+        let action = helia_core
+            .create_action(CreateActionRequest {
+                name: String::from("Quarterly report"),
+                ..Default::default()
+            })
+            .unwrap();
+        let result = helia_core.delegate_action(DelegateActionRequest {
+            action_id: action.action_id().clone(),
+            delegate: String::from("  "),
+            ..Default::default()
+        });
+        assert!(matches!(
+            result,
+            Err(HeliaError::ValidationFailed { errors })
+                if errors == vec![FieldError::new("delegate", Violation::Empty)]
+        ));
+
+        helia_core.complete_action(action.action_id()).unwrap();
+        let result = helia_core.delegate_action(DelegateActionRequest {
+            action_id: action.action_id().clone(),
+            delegate: String::from("Alice"),
+            ..Default::default()
+        });
+        assert!(matches!(
+            result,
+            Err(HeliaError::ActionAlreadyCompleted { .. })
+        ));
+    }
+
+    #[test]
+    fn test_someday_maybe() {
+        let mut helia_core = core::new_testing().unwrap();
+        helia_core.run_migrations().unwrap();
+        let create = |name: &str, project_id: Option<ProjectId>| {
+            helia_core
+                .create_action(CreateActionRequest {
+                    name: String::from(name),
+                    project_id,
+                    ..Default::default()
+                })
+                .unwrap()
+        };
+        let sailing = helia_core
+            .create_project(CreateProjectRequest {
+                name: String::from("Learn sailing"),
+                ..Default::default()
+            })
+            .unwrap();
+        let course = create("Find a sailing course", Some(sailing.project_id().clone()));
+        let italian = create("Learn Italian", None);
+        let taxes = create("File taxes", None);
+
+        let italian = helia_core.demote_action(italian.action_id()).unwrap();
+        assert!(italian.is_someday());
+        let sailing = helia_core.demote_project(sailing.project_id()).unwrap();
+        assert!(sailing.is_someday());
+
+        // Someday/maybe items and actions of someday/maybe projects are left out by default.
+        assert_eq!(
+            helia_core.list_available_actions().unwrap(),
+            vec![taxes.clone()]
+        );
+        assert_eq!(
+            helia_core
+                .list_actions(ListActionsRequest::default())
+                .unwrap(),
+            vec![taxes.clone()]
+        );
+        assert_eq!(
+            helia_core
+                .list_actions(ListActionsRequest {
+                    include_someday: true,
+                    ..Default::default()
+                })
+                .unwrap()
+                .len(),
+            3
+        );
+        assert!(helia_core.list_projects().unwrap().is_empty());
+        assert_eq!(
+            helia_core.list_someday_maybe().unwrap(),
+            SomedayMaybeList {
+                actions: vec![course.clone(), italian.clone()],
+                projects: vec![sailing.clone()],
+            }
+        );
+
+        let italian = helia_core.promote_action(italian.action_id()).unwrap();
+        assert!(!italian.is_someday());
+        let sailing = helia_core.promote_project(sailing.project_id()).unwrap();
+        assert!(helia_core.list_someday_maybe().unwrap().actions.is_empty());
+        assert_eq!(helia_core.list_projects().unwrap(), vec![sailing]);
+        assert_eq!(
+            helia_core.list_available_actions().unwrap(),
+            vec![course, italian, taxes]
+        );
+    }
+
+    #[test]
+    fn test_weekly_review() {
+        let start = Utc.with_ymd_and_hms(2024, 3, 1, 16, 0, 0).unwrap();
+        let clock = FakeClock::new(start);
+        let mut helia_core = core::new_testing_with_clock(clock.clone()).unwrap();
+        helia_core.run_migrations().unwrap();
+        assert_eq!(helia_core.current_review().unwrap(), None);
+        assert_eq!(helia_core.last_reviewed_at().unwrap(), None);
+        assert!(matches!(
+            helia_core.complete_review_step(ReviewStep::EmptyInbox),
+            Err(HeliaError::NoReviewInProgress)
+        ));
+
+        let review = helia_core.start_review().unwrap();
+        assert_eq!(review.started_at(), &start);
+        assert_eq!(review.next_step(), Some(ReviewStep::EmptyInbox));
+        assert!(matches!(
+            helia_core.complete_review_step(ReviewStep::WaitingFor),
+            Err(HeliaError::ReviewStepOutOfOrder {
+                step: ReviewStep::WaitingFor,
+                expected: ReviewStep::EmptyInbox
+            })
+        ));
+
+        clock.advance(Duration::minutes(10));
+        let review = helia_core
+            .complete_review_step(ReviewStep::EmptyInbox)
+            .unwrap();
+        assert_eq!(
+            review.step_completed_at(ReviewStep::EmptyInbox),
+            Some(&(start + Duration::minutes(10)))
+        );
+        // Starting again resumes the review in progress.
+        assert_eq!(helia_core.start_review().unwrap(), review);
+        assert_eq!(helia_core.current_review().unwrap(), Some(review.clone()));
+
+        let project = helia_core
+            .create_project(CreateProjectRequest {
+                name: String::from("Renovate kitchen"),
+                ..Default::default()
+            })
+            .unwrap();
+        assert_eq!(project.project_review_date(), None);
+        clock.advance(Duration::minutes(5));
+        let project = helia_core
+            .mark_project_reviewed(project.project_id())
+            .unwrap();
+        assert_eq!(
+            project.project_review_date().unwrap().value(),
+            &(start + Duration::minutes(15))
+        );
+        assert_eq!(
+            helia_core.get_project(project.project_id()).unwrap(),
+            project
+        );
+
+        for step in &ReviewStep::ALL[1..] {
+            clock.advance(Duration::minutes(5));
+            helia_core.complete_review_step(*step).unwrap();
+        }
+        assert_eq!(helia_core.current_review().unwrap(), None);
+        assert_eq!(
+            helia_core.last_reviewed_at().unwrap(),
+            Some(start + Duration::minutes(35))
+        );
+
+        clock.advance(Duration::days(7));
+        let next_review = helia_core.start_review().unwrap();
+        assert_ne!(next_review.review_id(), review.review_id());
+    }
+
+    #[test]
+    fn test_stalled_projects() {
+        let start = Utc.with_ymd_and_hms(2024, 5, 6, 12, 0, 0).unwrap();
+        let clock = FakeClock::new(start);
+        let mut helia_core = core::new_testing_with_clock(clock.clone()).unwrap();
+        helia_core.run_migrations().unwrap();
+        let create_project = |name: &str| {
+            helia_core
+                .create_project(CreateProjectRequest {
+                    name: String::from(name),
+                    ..Default::default()
+                })
+                .unwrap()
+        };
+        let create_action = |name: &str, project: &Project, defer_date| {
+            helia_core
+                .create_action(CreateActionRequest {
+                    name: String::from(name),
+                    project_id: Some(project.project_id().clone()),
+                    defer_date,
+                    ..Default::default()
+                })
+                .unwrap()
+        };
+
+        let empty = create_project("Plan holiday");
+        let deferred = create_project("Renovate kitchen");
+        let planned = create_action("Get quotes", &deferred, None);
+        create_action("Order tiles", &deferred, Some(start + Duration::days(3)));
+        let delegated = create_project("Hire assistant");
+        let interview = create_action("Interview candidates", &delegated, None);
+        let busy = create_project("Write report");
+        create_action("Draft outline", &busy, None);
+        let on_hold = create_project("Learn piano");
+        helia_core
+            .update_project(UpdateProjectRequest {
+                project_id: on_hold.project_id().clone(),
+                name: None,
+                status: Some(ProjectStatus::OnHold),
+                ordering: None,
+            })
+            .unwrap();
+
+        let interviewed_at = start + Duration::hours(1);
+        clock.set(interviewed_at);
+        helia_core.complete_action(interview.action_id()).unwrap();
+        let references = create_action("Check references", &delegated, None);
+        helia_core
+            .delegate_action(DelegateActionRequest {
+                action_id: references.action_id().clone(),
+                delegate: String::from("Bob"),
+                follow_up_date: None,
+            })
+            .unwrap();
+        let quoted_at = start + Duration::hours(2);
+        clock.set(quoted_at);
+        helia_core.complete_action(planned.action_id()).unwrap();
+
+        let stalled = helia_core.stalled_projects().unwrap();
+        assert_eq!(
+            stalled
+                .iter()
+                .map(|stalled| (stalled.project.project_id(), stalled.last_completed_at))
+                .collect::<Vec<_>>(),
+            vec![
+                (empty.project_id(), None),
+                (delegated.project_id(), Some(interviewed_at)),
+                (deferred.project_id(), Some(quoted_at)),
+            ]
+        );
+
+        // Once the deferred action becomes available, its project is no longer stalled.
+        clock.advance(Duration::days(3));
+        assert_eq!(helia_core.stalled_projects().unwrap().len(), 2);
+
+        // Someday/maybe projects are not stalled, they wait on purpose.
+        helia_core.demote_project(empty.project_id()).unwrap();
+        assert_eq!(
+            helia_core.stalled_projects().unwrap()[0]
+                .project
+                .project_id(),
+            delegated.project_id()
+        );
+    }
+
+    #[test]
+    fn test_stalled_projects_respect_sequential_projects() {
+        let start = Utc.with_ymd_and_hms(2024, 5, 6, 12, 0, 0).unwrap();
+        let clock = FakeClock::new(start);
+        let mut helia_core = core::new_testing_with_clock(clock.clone()).unwrap();
+        helia_core.run_migrations().unwrap();
+        let move_house = helia_core
+            .create_project(CreateProjectRequest {
+                name: String::from("Move house"),
+                ordering: ProjectOrdering::Sequential,
+                ..Default::default()
+            })
+            .unwrap();
+        let create_action = |name: &str, defer_date| {
+            helia_core
+                .create_action(CreateActionRequest {
+                    name: String::from(name),
+                    project_id: Some(move_house.project_id().clone()),
+                    defer_date,
+                    ..Default::default()
+                })
+                .unwrap()
+        };
+        let book_movers = create_action("Book movers", Some(start + Duration::days(3)));
+        create_action("Pack boxes", None);
+
+        // The available second action is blocked by the deferred first one.
+        assert!(helia_core.list_next_actions().unwrap().is_empty());
+        let stalled = helia_core.stalled_projects().unwrap();
+        assert_eq!(stalled.len(), 1);
+        assert_eq!(stalled[0].project.project_id(), move_house.project_id());
+
+        clock.advance(Duration::days(3));
+        assert_eq!(helia_core.list_next_actions().unwrap(), vec![book_movers]);
+        assert!(helia_core.stalled_projects().unwrap().is_empty());
+    }
+
+    #[test]
+    fn test_next_actions_respect_sequential_projects() {
+        let start = Utc.with_ymd_and_hms(2024, 5, 6, 12, 0, 0).unwrap();
+        let clock = FakeClock::new(start);
+        let mut helia_core = core::new_testing_with_clock(clock.clone()).unwrap();
+        helia_core.run_migrations().unwrap();
+        let move_house = helia_core
+            .create_project(CreateProjectRequest {
+                name: String::from("Move house"),
+                ordering: ProjectOrdering::Sequential,
+                ..Default::default()
+            })
+            .unwrap();
+        let create = |name: &str, project_id: Option<ProjectId>, defer_date| {
+            helia_core
+                .create_action(CreateActionRequest {
+                    name: String::from(name),
+                    project_id,
+                    defer_date,
+                    ..Default::default()
+                })
+                .unwrap()
+        };
+        let project_id = Some(move_house.project_id().clone());
+        let boxes = create(
+            "Buy boxes",
+            project_id.clone(),
+            Some(start + Duration::days(1)),
+        );
+        let pack = create("Pack books", project_id.clone(), None);
+        let call = create("Call Bob", None, None);
+        assert_eq!((boxes.position(), pack.position()), (Some(0), Some(1)));
+
+        // The deferred first action blocks the rest of the sequential project.
+        assert_eq!(helia_core.list_next_actions().unwrap(), vec![call.clone()]);
+        assert_eq!(helia_core.list_available_actions().unwrap().len(), 2);
+
+        clock.advance(Duration::days(1));
+        assert_eq!(
+            helia_core.list_next_actions().unwrap(),
+            vec![boxes.clone(), call.clone()]
+        );
+
+        helia_core.complete_action(boxes.action_id()).unwrap();
+        assert_eq!(
+            helia_core.list_next_actions().unwrap(),
+            vec![pack.clone(), call.clone()]
+        );
+
+        // In a parallel project, every available action is a next action.
+        helia_core
+            .update_project(UpdateProjectRequest {
+                project_id: move_house.project_id().clone(),
+                ordering: Some(ProjectOrdering::Parallel),
+                ..Default::default()
+            })
+            .unwrap();
+        let sell = create("Sell couch", project_id, None);
+        assert_eq!(sell.position(), Some(2));
+        assert_eq!(helia_core.list_next_actions().unwrap().len(), 3);
+    }
+
+    #[test]
+    fn test_reorder_actions() {
+        let mut helia_core = core::new_testing().unwrap();
+        helia_core.run_migrations().unwrap();
+        let project = helia_core
+            .create_project(CreateProjectRequest {
+                name: String::from("Plan trip"),
+                ordering: ProjectOrdering::Sequential,
+                ..Default::default()
+            })
+            .unwrap();
+        let create = |name: &str| {
+            helia_core
+                .create_action(CreateActionRequest {
+                    name: String::from(name),
+                    project_id: Some(project.project_id().clone()),
+                    ..Default::default()
+                })
+                .unwrap()
+        };
+        let flights = create("Book flights");
+        let hotel = create("Book hotel");
+        let visa = create("Apply for visa");
+
+        let reordered = helia_core
+            .reorder_actions(ReorderActionsRequest {
+                project_id: project.project_id().clone(),
+                action_ids: vec![visa.action_id().clone()],
+            })
+            .unwrap();
+        let names: Vec<_> = reordered
+            .iter()
+            .map(|action| action.action_name().as_str())
+            .collect();
+        assert_eq!(names, vec!["Apply for visa", "Book flights", "Book hotel"]);
+        assert_eq!(
+            helia_core
+                .list_actions(ListActionsRequest {
+                    project_id: Some(project.project_id().clone()),
+                    ..Default::default()
+                })
+                .unwrap(),
+            reordered
+        );
+        assert_eq!(
+            helia_core.list_next_actions().unwrap()[0].action_id(),
+            visa.action_id()
+        );
+
+        assert!(matches!(
+            helia_core.reorder_actions(ReorderActionsRequest {
+                project_id: project.project_id().clone(),
+                action_ids: vec![hotel.action_id().clone(), hotel.action_id().clone()],
+            }),
+            Err(HeliaError::ValidationFailed { .. })
+        ));
+        let standalone = helia_core
+            .create_action(CreateActionRequest {
+                name: String::from("Water plants"),
+                ..Default::default()
+            })
+            .unwrap();
+        assert!(matches!(
+            helia_core.reorder_actions(ReorderActionsRequest {
+                project_id: project.project_id().clone(),
+                action_ids: vec![standalone.action_id().clone()],
+            }),
+            Err(HeliaError::ActionNotInProject { .. })
+        ));
+
+        // Moving an action out of the project and back in puts it last.
+        let moved = helia_core
+            .move_action(MoveActionRequest {
+                action_id: flights.action_id().clone(),
+                project_id: None,
+            })
+            .unwrap();
+        assert_eq!(moved.position(), None);
+        let moved = helia_core
+            .move_action(MoveActionRequest {
+                action_id: flights.action_id().clone(),
+                project_id: Some(project.project_id().clone()),
+            })
+            .unwrap();
+        assert_eq!(moved.position(), Some(3));
+    }
+
+    #[test]
+    fn test_areas() {
+        let mut helia_core = core::new_testing().unwrap();
+        helia_core.run_migrations().unwrap();
+        let create_area = |name: &str| {
+            helia_core
+                .create_area(CreateAreaRequest {
+                    name: String::from(name),
+                })
+                .unwrap()
+        };
+        let health = create_area("Health");
+        let finance = create_area("Money");
+        let finance = helia_core
+            .rename_area(RenameAreaRequest {
+                area_id: finance.area_id().clone(),
+                name: String::from(" Finance "),
+            })
+            .unwrap();
+        assert_eq!(finance.area_name().as_str(), "Finance");
+        assert!(matches!(
+            helia_core.create_area(CreateAreaRequest {
+                name: String::from(" "),
+            }),
+            Err(HeliaError::ValidationFailed { .. })
+        ));
+        assert_eq!(
+            helia_core.list_areas().unwrap(),
+            vec![finance.clone(), health.clone()]
+        );
+
+        let running = helia_core
+            .create_project(CreateProjectRequest {
+                name: String::from("Run a marathon"),
+                ..Default::default()
+            })
+            .unwrap();
+        let running = helia_core
+            .set_project_area(SetProjectAreaRequest {
+                project_id: running.project_id().clone(),
+                area_id: Some(health.area_id().clone()),
+            })
+            .unwrap();
+        assert_eq!(running.area_id(), Some(health.area_id()));
+        let create_action = |name: &str, project_id: Option<ProjectId>| {
+            helia_core
+                .create_action(CreateActionRequest {
+                    name: String::from(name),
+                    project_id,
+                    ..Default::default()
+                })
+                .unwrap()
+        };
+        let taxes = create_action("File taxes", None);
+        let budget = create_action("Set up budget", None);
+        for action in [&taxes, &budget] {
+            helia_core
+                .set_action_area(SetActionAreaRequest {
+                    action_id: action.action_id().clone(),
+                    area_id: Some(finance.area_id().clone()),
+                })
+                .unwrap();
+        }
+        let shoes = create_action("Buy running shoes", Some(running.project_id().clone()));
+        assert!(matches!(
+            helia_core.set_action_area(SetActionAreaRequest {
+                action_id: shoes.action_id().clone(),
+                area_id: Some(finance.area_id().clone()),
+            }),
+            Err(HeliaError::ActionInProject { .. })
+        ));
+
+        let summary = |helia_core: &dyn HeliaCore| {
+            helia_core
+                .area_summaries()
+                .unwrap()
+                .into_iter()
+                .map(|summary| {
+                    (
+                        summary.area.area_name().as_str().to_owned(),
+                        summary.open_actions,
+                        summary.stalled_projects,
+                    )
+                })
+                .collect::<Vec<_>>()
+        };
+        assert_eq!(
+            summary(&helia_core),
+            vec![
+                (String::from("Finance"), 2, 0),
+                (String::from("Health"), 1, 0)
+            ]
+        );
+
+        // Without an available action, the project is stalled and its area neglected.
+        helia_core.complete_action(shoes.action_id()).unwrap();
+        helia_core.complete_action(taxes.action_id()).unwrap();
+        assert_eq!(
+            summary(&helia_core),
+            vec![
+                (String::from("Finance"), 1, 0),
+                (String::from("Health"), 0, 1)
+            ]
+        );
+
+        helia_core.delete_area(health.area_id()).unwrap();
+        assert_eq!(
+            helia_core
+                .get_project(running.project_id())
+                .unwrap()
+                .area_id(),
+            None
+        );
+        assert!(matches!(
+            helia_core.get_area(health.area_id()),
+            Err(HeliaError::AreaNotFound { .. })
+        ));
+    }
+
+    #[test]
+    fn test_tags() {
+        let mut helia_core = core::new_testing().unwrap();
+        helia_core.run_migrations().unwrap();
+        let create_tag = |path: &str| {
+            helia_core
+                .create_tag(CreateTagRequest {
+                    path: String::from(path),
+                })
+                .unwrap()
+        };
+        let work = create_tag("work");
+        let client_x = create_tag("work / client-x");
+        let client_y = create_tag("work/client-y");
+        let errand = create_tag("errand");
+        assert_eq!(client_x.tag_path().as_str(), "work/client-x");
+        assert!(matches!(
+            helia_core.create_tag(CreateTagRequest {
+                path: String::from("work"),
+            }),
+            Err(HeliaError::TagPathTaken { .. })
+        ));
+        assert!(matches!(
+            helia_core.create_tag(CreateTagRequest {
+                path: String::from("work//x"),
+            }),
+            Err(HeliaError::ValidationFailed { .. })
+        ));
+
+        let report = helia_core
+            .create_action(CreateActionRequest {
+                name: String::from("Send report"),
+                ..Default::default()
+            })
+            .unwrap();
+        let hardware = helia_core
+            .create_action(CreateActionRequest {
+                name: String::from("Buy screws"),
+                ..Default::default()
+            })
+            .unwrap();
+        let launch = helia_core
+            .create_project(CreateProjectRequest {
+                name: String::from("Launch website"),
+                ..Default::default()
+            })
+            .unwrap();
+        for (action, tag) in [(&report, &client_x), (&hardware, &errand)] {
+            helia_core
+                .tag_action(TagActionRequest {
+                    action_id: action.action_id().clone(),
+                    tag_id: tag.tag_id().clone(),
+                })
+                .unwrap();
+        }
+        helia_core
+            .tag_project(TagProjectRequest {
+                project_id: launch.project_id().clone(),
+                tag_id: client_y.tag_id().clone(),
+            })
+            .unwrap();
+
+        // Tags match their descendants.
+        assert_eq!(
+            helia_core.list_actions_with_tag(work.tag_id()).unwrap(),
+            vec![report.clone()]
+        );
+        assert_eq!(
+            helia_core.list_projects_with_tag(work.tag_id()).unwrap(),
+            vec![launch.clone()]
+        );
+
+        // Renaming moves the descendants along.
+        let job = helia_core
+            .rename_tag(RenameTagRequest {
+                tag_id: work.tag_id().clone(),
+                path: String::from("job"),
+            })
+            .unwrap();
+        assert_eq!(job.tag_path().as_str(), "job");
+        assert_eq!(
+            helia_core.list_action_tags(report.action_id()).unwrap()[0]
+                .tag_path()
+                .as_str(),
+            "job/client-x"
+        );
+        assert_eq!(
+            helia_core.list_actions_with_tag(job.tag_id()).unwrap(),
+            vec![report.clone()]
+        );
+        assert!(matches!(
+            helia_core.rename_tag(RenameTagRequest {
+                tag_id: job.tag_id().clone(),
+                path: String::from("errand"),
+            }),
+            Err(HeliaError::TagPathTaken { .. })
+        ));
+        assert!(matches!(
+            helia_core.rename_tag(RenameTagRequest {
+                tag_id: job.tag_id().clone(),
+                path: String::from("job/old"),
+            }),
+            Err(HeliaError::ValidationFailed { .. })
+        ));
+
+        // Merging relinks the items of the merged tag.
+        let client_x = helia_core
+            .merge_tags(MergeTagsRequest {
+                source_id: client_y.tag_id().clone(),
+                target_id: client_x.tag_id().clone(),
+            })
+            .unwrap();
+        assert_eq!(
+            helia_core.list_project_tags(launch.project_id()).unwrap(),
+            vec![client_x.clone()]
+        );
+        assert!(matches!(
+            helia_core.get_tag(client_y.tag_id()),
+            Err(HeliaError::TagNotFound { .. })
+        ));
+        assert!(matches!(
+            helia_core.merge_tags(MergeTagsRequest {
+                source_id: job.tag_id().clone(),
+                target_id: client_x.tag_id().clone(),
+            }),
+            Err(HeliaError::ValidationFailed { .. })
+        ));
+
+        let untag_hardware = || {
+            helia_core.untag_action(TagActionRequest {
+                action_id: hardware.action_id().clone(),
+                tag_id: errand.tag_id().clone(),
+            })
+        };
+        untag_hardware().unwrap();
+        assert!(matches!(
+            untag_hardware(),
+            Err(HeliaError::TagNotAssigned { .. })
+        ));
+        helia_core.delete_tag(job.tag_id()).unwrap();
+        assert_eq!(helia_core.list_tags().unwrap(), vec![errand, client_x]);
+    }
+
+    #[test]
+    fn test_merge_tags_into_ancestor() {
+        let mut helia_core = core::new_testing().unwrap();
+        helia_core.run_migrations().unwrap();
+        let create_tag = |path: &str| {
+            helia_core
+                .create_tag(CreateTagRequest {
+                    path: String::from(path),
+                })
+                .unwrap()
+        };
+        let x = create_tag("x");
+        let x_y = create_tag("x/y");
+        let x_y_y = create_tag("x/y/y");
+        let action = helia_core
+            .create_action(CreateActionRequest {
+                name: String::from("Send report"),
+                ..Default::default()
+            })
+            .unwrap();
+        helia_core
+            .tag_action(TagActionRequest {
+                action_id: action.action_id().clone(),
+                tag_id: x_y_y.tag_id().clone(),
+            })
+            .unwrap();
+
+        // The descendant takes over the path of the merged tag instead of being merged as well.
+        helia_core
+            .merge_tags(MergeTagsRequest {
+                source_id: x_y.tag_id().clone(),
+                target_id: x.tag_id().clone(),
+            })
+            .unwrap();
+        let paths: Vec<_> = helia_core
+            .list_tags()
+            .unwrap()
+            .iter()
+            .map(|tag| tag.tag_path().as_str().to_owned())
+            .collect();
+        assert_eq!(paths, vec!["x", "x/y"]);
+        let action_tags = helia_core.list_action_tags(action.action_id()).unwrap();
+        assert_eq!(action_tags.len(), 1);
+        assert_eq!(action_tags[0].tag_id(), x_y_y.tag_id());
+        assert_eq!(action_tags[0].tag_path().as_str(), "x/y");
+
+        // Moved descendants have to fit the maximum path length as well.
+        assert!(matches!(
+            helia_core.rename_tag(RenameTagRequest {
+                tag_id: x.tag_id().clone(),
+                path: "a".repeat(TagPath::MAX_LEN - 1),
+            }),
+            Err(HeliaError::ValidationFailed { .. })
+        ));
+        assert_eq!(helia_core.get_tag(x.tag_id()).unwrap(), x);
+    }
+
+    #[test]
+    fn test_query_actions() {
+        let now = Utc.with_ymd_and_hms(2024, 5, 6, 12, 0, 0).unwrap();
+        let mut helia_core = core::new_testing_with_clock(FakeClock::new(now)).unwrap();
+        helia_core.run_migrations().unwrap();
+        let launch = helia_core
+            .create_project(CreateProjectRequest {
+                name: String::from("Launch website"),
+                ..Default::default()
+            })
+            .unwrap();
+        let create_action = |name: &str, project_id: Option<ProjectId>, due_in: Option<i64>| {
+            helia_core
+                .create_action(CreateActionRequest {
+                    name: String::from(name),
+                    project_id,
+                    due_date: due_in.map(|days| now + Duration::days(days)),
+                    ..Default::default()
+                })
+                .unwrap()
+        };
+        let copy = create_action("Write copy", Some(launch.project_id().clone()), Some(1));
+        let bug = create_action("Fix 100% bug", None, Some(10));
+        let call = create_action("Call mom", None, None);
+        let milk = create_action("Buy milk", None, None);
+        helia_core.complete_action(milk.action_id()).unwrap();
+        let milk = helia_core.get_action(milk.action_id()).unwrap();
+        let client_x = helia_core
+            .create_tag(CreateTagRequest {
+                path: String::from("work/client-x"),
+            })
+            .unwrap();
+        helia_core
+            .tag_action(TagActionRequest {
+                action_id: copy.action_id().clone(),
+                tag_id: client_x.tag_id().clone(),
+            })
+            .unwrap();
+        let computer = helia_core
+            .create_context(CreateContextRequest {
+                name: String::from("@computer"),
+            })
+            .unwrap();
+        helia_core
+            .assign_context(AssignContextRequest {
+                action_id: bug.action_id().clone(),
+                context_id: computer.context_id().clone(),
+            })
+            .unwrap();
+        let query = |query: ActionQuery| helia_core.query_actions(query).unwrap();
+        let parsed = |text: &str| query(helia_core.parse_action_query(text).unwrap());
+
+        let work = ActionPredicate::Tag(TagPath::new("work").unwrap());
+        assert_eq!(
+            query(
+                ActionQuery::new()
+                    .filter(ActionPredicate::Open)
+                    .filter(work.or(ActionPredicate::ContextNamed(String::from("@computer"))))
+                    .sort_by(ActionSortKey::DueAt, SortDirection::Descending)
+            ),
+            vec![bug.clone(), copy.clone()]
+        );
+        assert_eq!(
+            parsed(r#"is:open -project:"Launch website" sort:name"#),
+            vec![call.clone(), bug.clone()]
+        );
+        assert_eq!(parsed("due<=tomorrow"), vec![copy.clone()]);
+        assert_eq!(
+            parsed("is:completed OR mom"),
+            vec![call.clone(), milk.clone()]
+        );
+
+        // LIKE wildcards in the text are matched literally.
+        assert_eq!(parsed("0%"), vec![bug.clone()]);
+        assert!(parsed("_").is_empty());
+
+        assert_eq!(
+            query(ActionQuery::new().limit(2).offset(1)),
+            vec![bug.clone(), call.clone()]
+        );
+        assert_eq!(query(ActionQuery::new().offset(3)), vec![milk]);
+        assert!(matches!(
+            helia_core.parse_action_query("tag:(work"),
+            Err(HeliaError::InvalidActionQuery { .. })
+        ));
+    }
+
+    #[test]
+    fn test_perspectives() {
+        let now = Utc.with_ymd_and_hms(2024, 5, 6, 12, 0, 0).unwrap();
+        let mut helia_core = core::new_testing_with_clock(FakeClock::new(now)).unwrap();
+        helia_core.run_migrations().unwrap();
+        let create_action = |name: &str, due_in: i64| {
+            helia_core
+                .create_action(CreateActionRequest {
+                    name: String::from(name),
+                    due_date: Some(now + Duration::days(due_in)),
+                    ..Default::default()
+                })
+                .unwrap()
+        };
+        let report = create_action("Write report", 2);
+        let taxes = create_action("File taxes", 30);
+        let office = helia_core
+            .create_context(CreateContextRequest {
+                name: String::from("@office"),
+            })
+            .unwrap();
+        for action in [&report, &taxes] {
+            helia_core
+                .assign_context(AssignContextRequest {
+                    action_id: action.action_id().clone(),
+                    context_id: office.context_id().clone(),
+                })
+                .unwrap();
+        }
+
+        let perspective = helia_core
+            .create_perspective(CreatePerspectiveRequest {
+                name: String::from(" Office "),
+                query: String::from("is:open context:@office sort:due"),
+            })
+            .unwrap();
+        assert_eq!(perspective.perspective_name().as_str(), "Office");
+        assert_eq!(
+            helia_core
+                .evaluate_perspective(perspective.perspective_id())
+                .unwrap(),
+            vec![report.clone(), taxes]
+        );
+        assert!(matches!(
+            helia_core.create_perspective(CreatePerspectiveRequest {
+                name: String::from("Broken"),
+                query: String::from("due<someday"),
+            }),
+            Err(HeliaError::InvalidActionQuery { .. })
+        ));
+
+        let perspective = helia_core
+            .update_perspective(UpdatePerspectiveRequest {
+                perspective_id: perspective.perspective_id().clone(),
+                name: Some(String::from("Office, due this week")),
+                query: Some(String::from("is:open context:@office due<+7d")),
+            })
+            .unwrap();
+        assert_eq!(
+            helia_core.list_perspectives().unwrap(),
+            vec![perspective.clone()]
+        );
+        assert_eq!(
+            helia_core
+                .evaluate_perspective(perspective.perspective_id())
+                .unwrap(),
+            vec![report]
+        );
+        assert!(matches!(
+            helia_core.update_perspective(UpdatePerspectiveRequest {
+                perspective_id: perspective.perspective_id().clone(),
+                name: None,
+                query: Some(String::from("(is:open")),
+            }),
+            Err(HeliaError::InvalidActionQuery { .. })
+        ));
+
+        helia_core
+            .delete_perspective(perspective.perspective_id())
+            .unwrap();
+        assert!(matches!(
+            helia_core.evaluate_perspective(perspective.perspective_id()),
+            Err(HeliaError::PerspectiveNotFound { .. })
+        ));
+    }
+
+    #[test]
+    fn test_search() {
+        let mut helia_core = core::new_testing().unwrap();
+        helia_core.run_migrations().unwrap();
+        let budget = helia_core
+            .create_project(CreateProjectRequest {
+                name: String::from("Budget 2025"),
+                ..Default::default()
+            })
+            .unwrap();
+        let call = helia_core
+            .create_action(CreateActionRequest {
+                name: String::from("Call the bank"),
+                notes: Some(String::from("Ask about the budget for the new kitchen")),
+                ..Default::default()
+            })
+            .unwrap();
+        let inbox_item = helia_core.capture("Budget spreadsheet template").unwrap();
+        let ProcessedInboxItem::Reference(template) = helia_core
+            .process_inbox_item(ProcessInboxItemRequest {
+                inbox_item_id: inbox_item.inbox_item_id().clone(),
+                name: None,
+                outcome: InboxItemOutcome::Reference,
+            })
+            .unwrap()
+        else {
+            panic!("Expected a reference item.");
+        };
+
+        let results = helia_core.search("budget").unwrap();
+        let items: Vec<_> = results.iter().map(|result| &result.item).collect();
+        assert_eq!(
+            items,
+            vec![
+                &SearchItem::Project(budget.project_id().clone()),
+                &SearchItem::ReferenceItem(template.reference_item_id().clone()),
+                &SearchItem::Action(call.action_id().clone()),
+            ]
+        );
+        assert_eq!(results[0].title, "<mark>Budget</mark> 2025");
+        assert_eq!(results[0].snippet, None);
+        assert_eq!(results[2].title, "Call the bank");
+        assert_eq!(
+            results[2].snippet.as_deref(),
+            Some("Ask about the <mark>budget</mark> for the new kitchen")
+        );
+
+        helia_core.complete_action(call.action_id()).unwrap();
+        assert_eq!(helia_core.search("kitchen bank").unwrap().len(), 1);
+        assert!(helia_core.search("kitchen garden").unwrap().is_empty());
+    }
+
+    #[test]
+    fn test_undo_redo() {
+        let mut helia_core = core::new_testing().unwrap();
+        helia_core.run_migrations().unwrap();
+        assert_eq!(helia_core.undo().unwrap(), None);
+
+        let project = helia_core
+            .create_project(CreateProjectRequest {
+                name: String::from("Launch website"),
+                ..Default::default()
+            })
+            .unwrap();
+        let action = helia_core
+            .create_action(CreateActionRequest {
+                name: String::from("Write copy"),
+                project_id: Some(project.project_id().clone()),
+                ..Default::default()
+            })
+            .unwrap();
+        let context = helia_core
+            .create_context(CreateContextRequest {
+                name: String::from("@computer"),
+            })
+            .unwrap();
+        helia_core
+            .assign_context(AssignContextRequest {
+                action_id: action.action_id().clone(),
+                context_id: context.context_id().clone(),
+            })
+            .unwrap();
+
+        helia_core.complete_action(action.action_id()).unwrap();
+        assert_eq!(
+            helia_core.undo().unwrap().as_deref(),
+            Some("Complete action")
+        );
+        assert!(
+            !helia_core
+                .get_action(action.action_id())
+                .unwrap()
+                .is_completed()
+        );
+        assert_eq!(
+            helia_core.redo().unwrap().as_deref(),
+            Some("Complete action")
+        );
+        assert!(
+            helia_core
+                .get_action(action.action_id())
+                .unwrap()
+                .is_completed()
+        );
+        assert_eq!(helia_core.redo().unwrap(), None);
+        helia_core.undo().unwrap();
+
+        // Deleting the project unlinks its action, deleting the action removes its links.
+        helia_core.delete_project(project.project_id()).unwrap();
+        helia_core.delete_action(action.action_id()).unwrap();
+        assert_eq!(helia_core.undo().unwrap().as_deref(), Some("Delete action"));
+        assert_eq!(
+            helia_core.undo().unwrap().as_deref(),
+            Some("Delete project")
+        );
+        assert_eq!(helia_core.get_action(action.action_id()).unwrap(), action);
+        assert_eq!(
+            helia_core.list_action_contexts(action.action_id()).unwrap(),
+            vec![context.clone()]
+        );
+        assert_eq!(helia_core.search("copy").unwrap().len(), 1);
+
+        assert_eq!(
+            helia_core.redo().unwrap().as_deref(),
+            Some("Delete project")
+        );
+        assert_eq!(
+            helia_core
+                .get_action(action.action_id())
+                .unwrap()
+                .project_id(),
+            None
+        );
+
+        // A new change drops the operations that could still be redone.
+        helia_core
+            .rename_context(RenameContextRequest {
+                context_id: context.context_id().clone(),
+                name: String::from("@laptop"),
+            })
+            .unwrap();
+        assert_eq!(helia_core.redo().unwrap(), None);
+
+        // Failed operations and operations that change nothing are not recorded.
+        assert!(helia_core.delete_action(&ActionId::new()).is_err());
+        helia_core.start_review().unwrap();
+        helia_core.start_review().unwrap();
+        assert_eq!(helia_core.undo().unwrap().as_deref(), Some("Start review"));
+        assert_eq!(
+            helia_core.undo().unwrap().as_deref(),
+            Some("Rename context")
+        );
+        assert_eq!(helia_core.list_contexts().unwrap(), vec![context]);
+    }
 }
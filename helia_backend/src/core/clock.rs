@@ -0,0 +1,101 @@
+//! Sources of the current time used by the Helia core.
+//!
+//! Everything in the core that depends on "now" asks a [Clock] instead of calling
+//! [Utc::now] directly, so time based behaviour can be tested deterministically.
+
+use std::sync::{Arc, Mutex};
+
+use chrono::{DateTime, Duration, Utc};
+
+/// A source of the current date and time.
+pub trait Clock {
+    /// Returns the current date and time.
+    fn now(&self) -> DateTime<Utc>;
+}
+
+/// [Clock] reading the system time. Used in production.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct SystemClock;
+
+impl Clock for SystemClock {
+    fn now(&self) -> DateTime<Utc> {
+        Utc::now()
+    }
+}
+
+/// [Clock] whose time only changes when it is told to. Used for testing.
+///
+/// Clones share the same time, so a test can keep a handle to the clock it passed to the core
+/// and move the time forward from the outside.
+#[derive(Debug, Clone)]
+pub struct FakeClock {
+    now: Arc<Mutex<DateTime<Utc>>>,
+}
+
+impl FakeClock {
+    /// Creates a new [FakeClock] standing still at `now`.
+    pub fn new(now: DateTime<Utc>) -> Self {
+        Self {
+            now: Arc::new(Mutex::new(now)),
+        }
+    }
+
+    /// Sets the time of this clock (and all of its clones) to `now`.
+    pub fn set(&self, now: DateTime<Utc>) {
+        *self.lock() = now;
+    }
+
+    /// Moves the time of this clock (and all of its clones) forward by `duration`.
+    pub fn advance(&self, duration: Duration) {
+        *self.lock() += duration;
+    }
+
+    fn lock(&self) -> std::sync::MutexGuard<'_, DateTime<Utc>> {
+        // The guarded value is a plain timestamp, it can't be left in an inconsistent state.
+        self.now
+            .lock()
+            .unwrap_or_else(|poisoned| poisoned.into_inner())
+    }
+}
+
+impl Default for FakeClock {
+    /// Default constructor for [FakeClock], starting at the current system time.
+    fn default() -> Self {
+        Self::new(Utc::now())
+    }
+}
+
+impl Clock for FakeClock {
+    fn now(&self) -> DateTime<Utc> {
+        *self.lock()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use chrono::TimeZone;
+
+    use super::*;
+
+    #[test]
+    fn test_fake_clock_stands_still() {
+        let start = Utc.with_ymd_and_hms(2024, 3, 1, 9, 0, 0).unwrap();
+        let clock = FakeClock::new(start);
+
+        assert_eq!(clock.now(), start);
+        assert_eq!(clock.now(), start);
+    }
+
+    #[test]
+    fn test_fake_clock_clones_share_time() {
+        let start = Utc.with_ymd_and_hms(2024, 3, 1, 9, 0, 0).unwrap();
+        let clock = FakeClock::new(start);
+        let handle = clock.clone();
+
+        handle.advance(Duration::days(2));
+        assert_eq!(clock.now(), start + Duration::days(2));
+
+        handle.set(start);
+        assert_eq!(clock.now(), start);
+    }
+}
@@ -0,0 +1,204 @@
+//! Configuration of a production [HeliaCore](super::HeliaCore) instance.
+
+use std::{
+    ffi::OsString,
+    path::{Path, PathBuf},
+};
+
+use crate::core::helia_error::HeliaError;
+
+/// Name of Helia's directory inside the user data directory.
+const APP_DIR_NAME: &str = "helia";
+
+/// File name of the database inside Helia's data directory.
+const DB_FILE_NAME: &str = "helia.db";
+
+/// Where the database of a production instance lives.
+#[derive(Debug, Clone, PartialEq, Eq, Default)]
+pub enum DatabaseLocation {
+    /// The per-user data directory as specified by the XDG Base Directory Specification:
+    /// `$XDG_DATA_HOME/helia/helia.db`, falling back to `$HOME/.local/share/helia/helia.db`.
+    #[default]
+    XdgDataDir,
+    /// An explicit path to the database file.
+    Path(PathBuf),
+}
+
+/// Configuration for [new_production()](super::new_production).
+///
+/// By default the database is stored in the XDG data directory and missing parent directories
+/// are created:
+///
+/// ```no_run
+/// use helia_backend::ProductionConfig;
+///
+/// let helia_core = helia_backend::new_production(&ProductionConfig::default()).unwrap();
+/// let helia_core =
+///     helia_backend::new_production(&ProductionConfig::with_db_path("/tmp/helia.db")).unwrap();
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ProductionConfig {
+    database_location: DatabaseLocation,
+    create_parent_dirs: bool,
+}
+
+impl ProductionConfig {
+    /// Creates a configuration storing the database at the given path.
+    pub fn with_db_path(db_path: impl Into<PathBuf>) -> Self {
+        Self::default().database_location(DatabaseLocation::Path(db_path.into()))
+    }
+
+    /// Sets where the database is stored.
+    pub fn database_location(mut self, database_location: DatabaseLocation) -> Self {
+        self.database_location = database_location;
+        self
+    }
+
+    /// Sets whether missing parent directories of the database file are created.
+    pub fn create_parent_dirs(mut self, create_parent_dirs: bool) -> Self {
+        self.create_parent_dirs = create_parent_dirs;
+        self
+    }
+
+    /// Returns where the database is stored.
+    pub fn location(&self) -> &DatabaseLocation {
+        &self.database_location
+    }
+
+    /// Returns whether missing parent directories of the database file are created.
+    pub fn creates_parent_dirs(&self) -> bool {
+        self.create_parent_dirs
+    }
+
+    /// Resolves the path of the database file.
+    ///
+    /// Fails with [HeliaError::DataDirUnavailable] if the XDG data directory is requested but
+    /// neither `XDG_DATA_HOME` nor `HOME` is usable.
+    pub fn resolve_db_path(&self) -> Result<PathBuf, HeliaError> {
+        match &self.database_location {
+            DatabaseLocation::Path(path) => Ok(path.clone()),
+            DatabaseLocation::XdgDataDir => {
+                xdg_db_path(std::env::var_os("XDG_DATA_HOME"), std::env::var_os("HOME"))
+                    .ok_or(HeliaError::DataDirUnavailable)
+            }
+        }
+    }
+
+    /// Resolves the path of the database file and prepares its parent directory if configured.
+    pub(crate) fn prepare_db_path(&self) -> Result<PathBuf, HeliaError> {
+        let db_path = self.resolve_db_path()?;
+        if self.create_parent_dirs {
+            create_parent_dirs(&db_path)?;
+        }
+        Ok(db_path)
+    }
+}
+
+impl Default for ProductionConfig {
+    /// Default constructor for [ProductionConfig].
+    fn default() -> Self {
+        Self {
+            database_location: DatabaseLocation::default(),
+            create_parent_dirs: true,
+        }
+    }
+}
+
+/// Builds the database path inside the XDG data directory.
+///
+/// Relative values of `XDG_DATA_HOME` are invalid per the specification and ignored.
+fn xdg_db_path(xdg_data_home: Option<OsString>, home: Option<OsString>) -> Option<PathBuf> {
+    let data_home = xdg_data_home
+        .map(PathBuf::from)
+        .filter(|path| path.is_absolute())
+        .or_else(|| {
+            home.map(PathBuf::from)
+                .filter(|path| path.is_absolute())
+                .map(|home| home.join(".local").join("share"))
+        })?;
+    Some(data_home.join(APP_DIR_NAME).join(DB_FILE_NAME))
+}
+
+/// Creates all missing parent directories of `db_path`.
+fn create_parent_dirs(db_path: &Path) -> Result<(), HeliaError> {
+    let Some(parent) = db_path
+        .parent()
+        .filter(|parent| !parent.as_os_str().is_empty())
+    else {
+        return Ok(());
+    };
+    std::fs::create_dir_all(parent).map_err(|io_err| HeliaError::CreatingDataDirFailed {
+        path: parent.to_path_buf(),
+        io_err,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_xdg_data_home_takes_precedence() {
+        let path = xdg_db_path(Some("/data".into()), Some("/home/user".into()));
+        assert_eq!(path, Some(PathBuf::from("/data/helia/helia.db")));
+    }
+
+    #[test]
+    fn test_xdg_falls_back_to_home() {
+        let path = xdg_db_path(None, Some("/home/user".into()));
+        assert_eq!(
+            path,
+            Some(PathBuf::from("/home/user/.local/share/helia/helia.db"))
+        );
+
+        let path = xdg_db_path(Some("relative".into()), Some("/home/user".into()));
+        assert_eq!(
+            path,
+            Some(PathBuf::from("/home/user/.local/share/helia/helia.db"))
+        );
+    }
+
+    #[test]
+    fn test_xdg_without_any_home() {
+        assert_eq!(xdg_db_path(None, None), None);
+    }
+
+    #[test]
+    fn test_explicit_path_is_used_verbatim() {
+        let config = ProductionConfig::with_db_path("some/dir/my.db");
+        assert_eq!(
+            config.resolve_db_path().unwrap(),
+            PathBuf::from("some/dir/my.db")
+        );
+    }
+
+    #[test]
+    fn test_prepare_creates_parent_dirs() {
+        let root = std::env::temp_dir().join(format!("helia-config-{}", uuid::Uuid::new_v4()));
+        let db_path = root.join("nested").join("helia.db");
+
+        let config = ProductionConfig::with_db_path(&db_path).create_parent_dirs(false);
+        assert_eq!(config.prepare_db_path().unwrap(), db_path);
+        assert!(!root.exists());
+
+        let config = config.create_parent_dirs(true);
+        assert_eq!(config.prepare_db_path().unwrap(), db_path);
+        assert!(db_path.parent().unwrap().is_dir());
+
+        std::fs::remove_dir_all(root).unwrap();
+    }
+
+    #[test]
+    fn test_prepare_reports_failing_dir() {
+        let root = std::env::temp_dir().join(format!("helia-config-{}", uuid::Uuid::new_v4()));
+        std::fs::write(&root, "not a directory").unwrap();
+
+        let config = ProductionConfig::with_db_path(root.join("helia.db"));
+        match config.prepare_db_path() {
+            Err(HeliaError::CreatingDataDirFailed { path, .. }) => assert_eq!(path, root),
+            other => panic!("unexpected result: {other:?}"),
+        }
+
+        std::fs::remove_file(root).unwrap();
+    }
+}
@@ -0,0 +1,835 @@
+//! The JSON document format used by [HeliaCore::export_json](super::HeliaCore::export_json) and
+//! [HeliaCore::import_json](super::HeliaCore::import_json).
+//!
+//! The document is versioned by [EXPORT_FORMAT_VERSION]. It is decoupled from the model types,
+//! so the model can evolve without breaking existing exports.
+
+use std::{collections::HashSet, io::Read};
+
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use uuid::Uuid;
+
+use crate::{
+    core::{helia_error::HeliaError, requests::ActionQuery},
+    model::{
+        action::{
+            Action,
+            acion_create_date::ActionCreateDate,
+            action_complete_date::ActionCompleteDate,
+            action_defer_date::ActionDeferDate,
+            action_delegation::ActionDelegation,
+            action_due_date::ActionDueDate,
+            action_id::ActionId,
+            action_name::ActionName,
+            action_notes::ActionNotes,
+            action_recurrence::{ActionRecurrence, RecurrenceBase},
+        },
+        area::{Area, area_id::AreaId, area_name::AreaName},
+        context::{Context, context_id::ContextId, context_name::ContextName},
+        inbox_item::{
+            InboxItem, inbox_item_capture_date::InboxItemCaptureDate, inbox_item_id::InboxItemId,
+            inbox_item_text::InboxItemText,
+        },
+        perspective::{
+            Perspective, perspective_id::PerspectiveId, perspective_name::PerspectiveName,
+            perspective_query::PerspectiveQuery,
+        },
+        project::{
+            Project, project_create_date::ProjectCreateDate, project_id::ProjectId,
+            project_name::ProjectName, project_ordering::ProjectOrdering,
+            project_review_date::ProjectReviewDate,
+        },
+        reference_item::{
+            ReferenceItem, reference_item_create_date::ReferenceItemCreateDate,
+            reference_item_id::ReferenceItemId, reference_item_text::ReferenceItemText,
+        },
+        review::{Review, review_id::ReviewId, review_step::ReviewStep},
+        tag::{Tag, tag_id::TagId, tag_path::TagPath},
+    },
+    storage::DataSet,
+};
+
+/// The version of the export document written by this version of Helia.
+///
+/// Documents of other versions are rejected on import.
+pub const EXPORT_FORMAT_VERSION: u32 = 1;
+
+/// The root of an export document.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub(crate) struct ExportDocument {
+    pub version: u32,
+    pub exported_at: DateTime<Utc>,
+    #[serde(default)]
+    pub areas: Vec<AreaRecord>,
+    #[serde(default)]
+    pub projects: Vec<ProjectRecord>,
+    #[serde(default)]
+    pub contexts: Vec<ContextRecord>,
+    #[serde(default)]
+    pub tags: Vec<TagRecord>,
+    #[serde(default)]
+    pub actions: Vec<ActionRecord>,
+    #[serde(default)]
+    pub perspectives: Vec<PerspectiveRecord>,
+    #[serde(default)]
+    pub reviews: Vec<ReviewRecord>,
+    #[serde(default)]
+    pub inbox_items: Vec<InboxItemRecord>,
+    #[serde(default)]
+    pub reference_items: Vec<ReferenceItemRecord>,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub(crate) struct ActionRecord {
+    pub id: Uuid,
+    pub name: String,
+    pub created_at: DateTime<Utc>,
+    #[serde(default)]
+    pub completed_at: Option<DateTime<Utc>>,
+    #[serde(default)]
+    pub due_at: Option<DateTime<Utc>>,
+    #[serde(default)]
+    pub defer_until: Option<DateTime<Utc>>,
+    #[serde(default)]
+    pub notes: Option<String>,
+    /// The recurrence rule in its `RRULE` form.
+    #[serde(default)]
+    pub recurrence: Option<String>,
+    #[serde(default)]
+    pub recur_from_completion: bool,
+    /// Who the action is delegated to, if anyone.
+    #[serde(default)]
+    pub delegated_to: Option<String>,
+    #[serde(default)]
+    pub delegated_at: Option<DateTime<Utc>>,
+    #[serde(default)]
+    pub follow_up_at: Option<DateTime<Utc>>,
+    #[serde(default)]
+    pub project_id: Option<Uuid>,
+    /// The position of the action within its project.
+    #[serde(default)]
+    pub position: Option<u32>,
+    #[serde(default)]
+    pub area_id: Option<Uuid>,
+    #[serde(default)]
+    pub someday: bool,
+    /// Ids of the contexts the action is linked to.
+    #[serde(default)]
+    pub context_ids: Vec<Uuid>,
+    /// Ids of the tags the action is linked to.
+    #[serde(default)]
+    pub tag_ids: Vec<Uuid>,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub(crate) struct ProjectRecord {
+    pub id: Uuid,
+    pub name: String,
+    pub created_at: DateTime<Utc>,
+    pub status: String,
+    #[serde(default)]
+    pub someday: bool,
+    #[serde(default)]
+    pub last_reviewed_at: Option<DateTime<Utc>>,
+    #[serde(default = "default_ordering")]
+    pub ordering: String,
+    #[serde(default)]
+    pub area_id: Option<Uuid>,
+    /// Ids of the tags the project is linked to.
+    #[serde(default)]
+    pub tag_ids: Vec<Uuid>,
+}
+
+/// The ordering of projects exported before orderings existed.
+fn default_ordering() -> String {
+    String::from(ProjectOrdering::default().as_str())
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub(crate) struct AreaRecord {
+    pub id: Uuid,
+    pub name: String,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub(crate) struct ContextRecord {
+    pub id: Uuid,
+    pub name: String,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub(crate) struct TagRecord {
+    pub id: Uuid,
+    pub path: String,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub(crate) struct PerspectiveRecord {
+    pub id: Uuid,
+    pub name: String,
+    /// The textual form of the perspective's action query.
+    pub query: String,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub(crate) struct ReviewRecord {
+    pub id: Uuid,
+    pub started_at: DateTime<Utc>,
+    /// The completed steps, in the order of completion. A review is completed once all of its
+    /// steps are.
+    #[serde(default)]
+    pub completed_steps: Vec<ReviewStepRecord>,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub(crate) struct ReviewStepRecord {
+    pub step: String,
+    pub completed_at: DateTime<Utc>,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub(crate) struct InboxItemRecord {
+    pub id: Uuid,
+    pub text: String,
+    pub captured_at: DateTime<Utc>,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub(crate) struct ReferenceItemRecord {
+    pub id: Uuid,
+    pub text: String,
+    pub created_at: DateTime<Utc>,
+}
+
+impl ExportDocument {
+    /// Reads a document, checking its version before anything else.
+    ///
+    /// Documents of other versions may be structured differently, so they are rejected with
+    /// [HeliaError::UnsupportedExportVersion] rather than a parse error.
+    pub fn read(reader: &mut dyn Read) -> Result<Self, HeliaError> {
+        let value: serde_json::Value = serde_json::from_reader(reader)
+            .map_err(|json_err| HeliaError::ReadingImportFailed { json_err })?;
+        match value.get("version").and_then(serde_json::Value::as_u64) {
+            Some(version) if version == u64::from(EXPORT_FORMAT_VERSION) => {}
+            Some(version) => {
+                return Err(HeliaError::UnsupportedExportVersion {
+                    version: u32::try_from(version).unwrap_or(u32::MAX),
+                });
+            }
+            None => return Err(invalid(String::from("the format version is missing"))),
+        }
+        serde_json::from_value(value)
+            .map_err(|json_err| HeliaError::ReadingImportFailed { json_err })
+    }
+
+    /// Builds the document describing `data`.
+    pub fn from_data_set(data: &DataSet, exported_at: DateTime<Utc>) -> Self {
+        let actions = data
+            .actions
+            .iter()
+            .map(|action| ActionRecord {
+                id: *action.action_id().uuid(),
+                name: action.action_name().as_str().to_owned(),
+                created_at: *action.action_create_date().value(),
+                completed_at: action.action_complete_date().map(|date| *date.value()),
+                due_at: action.action_due_date().map(|date| *date.value()),
+                defer_until: action.action_defer_date().map(|date| *date.value()),
+                notes: action.action_notes().map(|notes| notes.as_str().to_owned()),
+                recurrence: action
+                    .action_recurrence()
+                    .map(|recurrence| recurrence.to_rrule()),
+                recur_from_completion: action
+                    .action_recurrence()
+                    .is_some_and(|recurrence| recurrence.base() == RecurrenceBase::Completion),
+                delegated_to: action
+                    .action_delegation()
+                    .map(|delegation| delegation.delegate().to_owned()),
+                delegated_at: action
+                    .action_delegation()
+                    .map(|delegation| *delegation.delegated_at()),
+                follow_up_at: action
+                    .action_delegation()
+                    .and_then(|delegation| delegation.follow_up_at().copied()),
+                project_id: action.project_id().map(|id| *id.uuid()),
+                position: action.position(),
+                area_id: action.area_id().map(|id| *id.uuid()),
+                someday: action.is_someday(),
+                context_ids: data
+                    .action_contexts
+                    .iter()
+                    .filter(|(action_id, _)| action_id == action.action_id())
+                    .map(|(_, context_id)| *context_id.uuid())
+                    .collect(),
+                tag_ids: data
+                    .action_tags
+                    .iter()
+                    .filter(|(action_id, _)| action_id == action.action_id())
+                    .map(|(_, tag_id)| *tag_id.uuid())
+                    .collect(),
+            })
+            .collect();
+        let projects = data
+            .projects
+            .iter()
+            .map(|project| ProjectRecord {
+                id: *project.project_id().uuid(),
+                name: project.project_name().as_str().to_owned(),
+                created_at: *project.project_create_date().value(),
+                status: project.project_status().as_str().to_owned(),
+                someday: project.is_someday(),
+                last_reviewed_at: project.project_review_date().map(|date| *date.value()),
+                ordering: project.project_ordering().as_str().to_owned(),
+                area_id: project.area_id().map(|id| *id.uuid()),
+                tag_ids: data
+                    .project_tags
+                    .iter()
+                    .filter(|(project_id, _)| project_id == project.project_id())
+                    .map(|(_, tag_id)| *tag_id.uuid())
+                    .collect(),
+            })
+            .collect();
+        let areas = data
+            .areas
+            .iter()
+            .map(|area| AreaRecord {
+                id: *area.area_id().uuid(),
+                name: area.area_name().as_str().to_owned(),
+            })
+            .collect();
+        let contexts = data
+            .contexts
+            .iter()
+            .map(|context| ContextRecord {
+                id: *context.context_id().uuid(),
+                name: context.context_name().as_str().to_owned(),
+            })
+            .collect();
+        let tags = data
+            .tags
+            .iter()
+            .map(|tag| TagRecord {
+                id: *tag.tag_id().uuid(),
+                path: tag.tag_path().as_str().to_owned(),
+            })
+            .collect();
+        let perspectives = data
+            .perspectives
+            .iter()
+            .map(|perspective| PerspectiveRecord {
+                id: *perspective.perspective_id().uuid(),
+                name: perspective.perspective_name().as_str().to_owned(),
+                query: perspective.perspective_query().as_str().to_owned(),
+            })
+            .collect();
+        let reviews = data
+            .reviews
+            .iter()
+            .map(|review| ReviewRecord {
+                id: *review.review_id().uuid(),
+                started_at: *review.started_at(),
+                completed_steps: review
+                    .completed_steps()
+                    .iter()
+                    .map(|(step, completed_at)| ReviewStepRecord {
+                        step: step.as_str().to_owned(),
+                        completed_at: *completed_at,
+                    })
+                    .collect(),
+            })
+            .collect();
+        let inbox_items = data
+            .inbox_items
+            .iter()
+            .map(|inbox_item| InboxItemRecord {
+                id: *inbox_item.inbox_item_id().uuid(),
+                text: inbox_item.inbox_item_text().as_str().to_owned(),
+                captured_at: *inbox_item.inbox_item_capture_date().value(),
+            })
+            .collect();
+        let reference_items = data
+            .reference_items
+            .iter()
+            .map(|reference_item| ReferenceItemRecord {
+                id: *reference_item.reference_item_id().uuid(),
+                text: reference_item.reference_item_text().as_str().to_owned(),
+                created_at: *reference_item.reference_item_create_date().value(),
+            })
+            .collect();
+
+        Self {
+            version: EXPORT_FORMAT_VERSION,
+            exported_at,
+            areas,
+            projects,
+            contexts,
+            tags,
+            actions,
+            perspectives,
+            reviews,
+            inbox_items,
+            reference_items,
+        }
+    }
+
+    /// Validates the document and turns it into the [DataSet] to import.
+    ///
+    /// The document has to be self-contained: every area, project, context and tag referenced
+    /// by an action or project has to be part of it.
+    pub fn into_data_set(self) -> Result<DataSet, HeliaError> {
+        if self.version != EXPORT_FORMAT_VERSION {
+            return Err(HeliaError::UnsupportedExportVersion {
+                version: self.version,
+            });
+        }
+
+        let area_ids = unique_ids("area", self.areas.iter().map(|a| a.id))?;
+        let project_ids = unique_ids("project", self.projects.iter().map(|p| p.id))?;
+        let context_ids = unique_ids("context", self.contexts.iter().map(|c| c.id))?;
+        let tag_ids = unique_ids("tag", self.tags.iter().map(|t| t.id))?;
+        unique_ids("action", self.actions.iter().map(|a| a.id))?;
+        unique_ids("perspective", self.perspectives.iter().map(|p| p.id))?;
+        unique_ids("review", self.reviews.iter().map(|r| r.id))?;
+        unique_ids("inbox item", self.inbox_items.iter().map(|i| i.id))?;
+        unique_ids("reference item", self.reference_items.iter().map(|r| r.id))?;
+
+        let mut data = DataSet::default();
+        for record in self.areas {
+            let area_name = AreaName::new(&record.name)
+                .map_err(|violation| invalid(format!("area {}: name {violation}", record.id)))?;
+            data.areas
+                .push(Area::new(AreaId::from_uuid(record.id), area_name));
+        }
+        for record in self.tags {
+            let tag_path = TagPath::new(&record.path)
+                .map_err(|violation| invalid(format!("tag {}: path {violation}", record.id)))?;
+            data.tags
+                .push(Tag::new(TagId::from_uuid(record.id), tag_path));
+        }
+        for record in self.perspectives {
+            let name = PerspectiveName::new(&record.name).map_err(|violation| {
+                invalid(format!("perspective {}: name {violation}", record.id))
+            })?;
+            let query = PerspectiveQuery::new(&record.query).map_err(|violation| {
+                invalid(format!("perspective {}: query {violation}", record.id))
+            })?;
+            ActionQuery::parse(query.as_str(), self.exported_at)
+                .map_err(|err| invalid(format!("perspective {}: query {err}", record.id)))?;
+            data.perspectives.push(Perspective::new(
+                PerspectiveId::from_uuid(record.id),
+                name,
+                query,
+            ));
+        }
+        for record in self.projects {
+            if let Some(area_id) = record.area_id
+                && !area_ids.contains(&area_id)
+            {
+                return Err(invalid(format!(
+                    "project {} belongs to unknown area {area_id}",
+                    record.id
+                )));
+            }
+            let mut tagged = HashSet::new();
+            for tag_id in record.tag_ids {
+                if !tag_ids.contains(&tag_id) {
+                    return Err(invalid(format!(
+                        "project {} is linked to unknown tag {tag_id}",
+                        record.id
+                    )));
+                }
+                if tagged.insert(tag_id) {
+                    data.project_tags
+                        .push((ProjectId::from_uuid(record.id), TagId::from_uuid(tag_id)));
+                }
+            }
+            let status = record.status.parse().map_err(|_| {
+                invalid(format!(
+                    "project {} has unknown status '{}'",
+                    record.id, record.status
+                ))
+            })?;
+            let mut project = Project::new(
+                ProjectId::from_uuid(record.id),
+                ProjectName::new(&record.name),
+                ProjectCreateDate::from_value(record.created_at),
+                status,
+            );
+            let ordering = record.ordering.parse().map_err(|_| {
+                invalid(format!(
+                    "project {} has unknown ordering '{}'",
+                    record.id, record.ordering
+                ))
+            })?;
+            project.set_project_ordering(ordering);
+            project.set_area_id(record.area_id.map(AreaId::from_uuid));
+            project.set_someday(record.someday);
+            project.set_project_review_date(
+                record.last_reviewed_at.map(ProjectReviewDate::from_value),
+            );
+            data.projects.push(project);
+        }
+        for record in self.contexts {
+            data.contexts.push(Context::new(
+                ContextId::from_uuid(record.id),
+                ContextName::new(&record.name),
+            ));
+        }
+        for record in self.actions {
+            if let Some(project_id) = record.project_id
+                && !project_ids.contains(&project_id)
+            {
+                return Err(invalid(format!(
+                    "action {} belongs to unknown project {project_id}",
+                    record.id
+                )));
+            }
+            if let Some(area_id) = record.area_id
+                && !area_ids.contains(&area_id)
+            {
+                return Err(invalid(format!(
+                    "action {} belongs to unknown area {area_id}",
+                    record.id
+                )));
+            }
+            let mut linked = HashSet::new();
+            for context_id in record.context_ids {
+                if !context_ids.contains(&context_id) {
+                    return Err(invalid(format!(
+                        "action {} is linked to unknown context {context_id}",
+                        record.id
+                    )));
+                }
+                if linked.insert(context_id) {
+                    data.action_contexts.push((
+                        ActionId::from_uuid(record.id),
+                        ContextId::from_uuid(context_id),
+                    ));
+                }
+            }
+            let mut tagged = HashSet::new();
+            for tag_id in record.tag_ids {
+                if !tag_ids.contains(&tag_id) {
+                    return Err(invalid(format!(
+                        "action {} is linked to unknown tag {tag_id}",
+                        record.id
+                    )));
+                }
+                if tagged.insert(tag_id) {
+                    data.action_tags
+                        .push((ActionId::from_uuid(record.id), TagId::from_uuid(tag_id)));
+                }
+            }
+
+            let name = ActionName::new(&record.name)
+                .map_err(|violation| invalid(format!("action {}: name {violation}", record.id)))?;
+            let mut action = Action::new(
+                ActionId::from_uuid(record.id),
+                name,
+                ActionCreateDate::from_value(record.created_at),
+            );
+            action
+                .set_action_complete_date(record.completed_at.map(ActionCompleteDate::from_value));
+            action.set_action_due_date(record.due_at.map(ActionDueDate::from_value));
+            action.set_action_defer_date(record.defer_until.map(ActionDeferDate::from_value));
+            action.set_action_notes(record.notes.as_deref().map(ActionNotes::new));
+            if let Some(rrule) = &record.recurrence {
+                let base = match record.recur_from_completion {
+                    true => RecurrenceBase::Completion,
+                    false => RecurrenceBase::Schedule,
+                };
+                let recurrence = ActionRecurrence::from_rrule(rrule, base)
+                    .map_err(|err| invalid(format!("action {}: {err}", record.id)))?;
+                action.set_action_recurrence(Some(recurrence));
+            }
+            if let Some(delegate) = &record.delegated_to {
+                let delegated_at = record.delegated_at.ok_or_else(|| {
+                    invalid(format!(
+                        "action {} is delegated without a delegation date",
+                        record.id
+                    ))
+                })?;
+                let delegation = ActionDelegation::new(delegate, delegated_at, record.follow_up_at)
+                    .map_err(|violation| {
+                        invalid(format!("action {}: delegate {violation}", record.id))
+                    })?;
+                action.set_action_delegation(Some(delegation));
+            }
+            action.set_position(record.project_id.and(record.position));
+            action.set_project_id(record.project_id.map(ProjectId::from_uuid));
+            action.set_area_id(record.area_id.map(AreaId::from_uuid));
+            action.set_someday(record.someday);
+            data.actions.push(action);
+        }
+        for record in self.reviews {
+            let mut review = Review::new(ReviewId::from_uuid(record.id), record.started_at);
+            for step_record in record.completed_steps {
+                let step: ReviewStep = step_record.step.parse().map_err(|_| {
+                    invalid(format!(
+                        "review {} has unknown step '{}'",
+                        record.id, step_record.step
+                    ))
+                })?;
+                if review.step_completed_at(step).is_some() {
+                    return Err(invalid(format!(
+                        "review {} completes step '{}' twice",
+                        record.id, step_record.step
+                    )));
+                }
+                review.complete_step(step, step_record.completed_at);
+            }
+            if !review.is_completed() && data.reviews.iter().any(|r| !r.is_completed()) {
+                return Err(invalid(format!(
+                    "review {} is in progress while another one is",
+                    record.id
+                )));
+            }
+            data.reviews.push(review);
+        }
+        for record in self.inbox_items {
+            let text = InboxItemText::new(&record.text).map_err(|violation| {
+                invalid(format!("inbox item {}: text {violation}", record.id))
+            })?;
+            data.inbox_items.push(InboxItem::new(
+                InboxItemId::from_uuid(record.id),
+                text,
+                InboxItemCaptureDate::from_value(record.captured_at),
+            ));
+        }
+        for record in self.reference_items {
+            data.reference_items.push(ReferenceItem::new(
+                ReferenceItemId::from_uuid(record.id),
+                ReferenceItemText::new(&record.text),
+                ReferenceItemCreateDate::from_value(record.created_at),
+            ));
+        }
+
+        Ok(data)
+    }
+}
+
+/// Collects the ids of one kind of item, failing on the first duplicate.
+fn unique_ids(kind: &str, ids: impl Iterator<Item = Uuid>) -> Result<HashSet<Uuid>, HeliaError> {
+    let mut seen = HashSet::new();
+    for id in ids {
+        if !seen.insert(id) {
+            return Err(invalid(format!("{kind} {id} occurs more than once")));
+        }
+    }
+    Ok(seen)
+}
+
+fn invalid(reason: String) -> HeliaError {
+    HeliaError::InvalidImportDocument { reason }
+}
+
+#[cfg(test)]
+mod tests {
+    use chrono::TimeZone;
+
+    use super::*;
+
+    fn exported_at() -> DateTime<Utc> {
+        Utc.with_ymd_and_hms(2024, 1, 2, 3, 4, 5).unwrap()
+    }
+
+    fn completed_review() -> Review {
+        let mut review = Review::new(ReviewId::new(), exported_at());
+        for step in ReviewStep::ALL {
+            review.complete_step(step, exported_at());
+        }
+        review
+    }
+
+    fn in_progress_review() -> Review {
+        let mut review = Review::new(ReviewId::new(), exported_at());
+        review.complete_step(ReviewStep::EmptyInbox, exported_at());
+        review
+    }
+
+    #[test]
+    fn test_round_trip_keeps_data() {
+        let area = crate::model::area::test_utils::dummy_area();
+        let mut project = crate::model::project::test_utils::dummy_project();
+        project.set_area_id(Some(area.area_id().clone()));
+        let context = crate::model::context::test_utils::dummy_context();
+        let tag = crate::model::tag::test_utils::dummy_tag();
+        let mut action = crate::model::action::test_utils::dummy_action();
+        action.set_project_id(Some(project.project_id().clone()));
+        action.set_action_due_date(Some(ActionDueDate::from_value(exported_at())));
+        let data = DataSet {
+            areas: vec![area],
+            actions: vec![action.clone()],
+            action_contexts: vec![(action.action_id().clone(), context.context_id().clone())],
+            contexts: vec![context],
+            action_tags: vec![(action.action_id().clone(), tag.tag_id().clone())],
+            project_tags: vec![(project.project_id().clone(), tag.tag_id().clone())],
+            projects: vec![project],
+            tags: vec![tag],
+            perspectives: vec![crate::model::perspective::test_utils::dummy_perspective()],
+            reviews: vec![completed_review(), in_progress_review()],
+            inbox_items: vec![crate::model::inbox_item::test_utils::dummy_inbox_item()],
+            reference_items: Vec::new(),
+        };
+
+        let document = ExportDocument::from_data_set(&data, exported_at());
+        let json = serde_json::to_string(&document).unwrap();
+        let parsed: ExportDocument = serde_json::from_str(&json).unwrap();
+
+        assert_eq!(parsed, document);
+        assert_eq!(parsed.into_data_set().unwrap(), data);
+    }
+
+    #[test]
+    fn test_rejects_other_versions() {
+        let mut document = ExportDocument::from_data_set(&DataSet::default(), exported_at());
+        document.version = EXPORT_FORMAT_VERSION + 1;
+
+        assert!(matches!(
+            document.into_data_set(),
+            Err(HeliaError::UnsupportedExportVersion { version }) if version == EXPORT_FORMAT_VERSION + 1
+        ));
+    }
+
+    #[test]
+    fn test_read_checks_version_first() {
+        let json = r#"{"version": 99, "something": "else"}"#;
+        assert!(matches!(
+            ExportDocument::read(&mut json.as_bytes()),
+            Err(HeliaError::UnsupportedExportVersion { version: 99 })
+        ));
+
+        let json = r#"{"actions": []}"#;
+        assert!(matches!(
+            ExportDocument::read(&mut json.as_bytes()),
+            Err(HeliaError::InvalidImportDocument { .. })
+        ));
+
+        let json = r#"{"version": 1, "exported_at": "yesterday"}"#;
+        assert!(matches!(
+            ExportDocument::read(&mut json.as_bytes()),
+            Err(HeliaError::ReadingImportFailed { .. })
+        ));
+    }
+
+    #[test]
+    fn test_rejects_duplicate_ids() {
+        let context = crate::model::context::test_utils::dummy_context();
+        let data = DataSet {
+            contexts: vec![context.clone(), context],
+            ..Default::default()
+        };
+        let document = ExportDocument::from_data_set(&data, exported_at());
+
+        assert!(matches!(
+            document.into_data_set(),
+            Err(HeliaError::InvalidImportDocument { .. })
+        ));
+    }
+
+    #[test]
+    fn test_rejects_invalid_action_names() {
+        let data = DataSet {
+            actions: vec![crate::model::action::test_utils::dummy_action()],
+            ..Default::default()
+        };
+        let mut document = ExportDocument::from_data_set(&data, exported_at());
+        document.actions[0].name = String::from("  \u{7}bad\n");
+
+        assert!(matches!(
+            document.into_data_set(),
+            Err(HeliaError::InvalidImportDocument { reason }) if reason.contains("name")
+        ));
+    }
+
+    #[test]
+    fn test_rejects_invalid_delegates() {
+        let data = DataSet {
+            actions: vec![crate::model::action::test_utils::dummy_action()],
+            ..Default::default()
+        };
+        let mut document = ExportDocument::from_data_set(&data, exported_at());
+        document.actions[0].delegated_to = Some(String::new());
+        document.actions[0].delegated_at = Some(exported_at());
+
+        assert!(matches!(
+            document.into_data_set(),
+            Err(HeliaError::InvalidImportDocument { reason }) if reason.contains("delegate")
+        ));
+    }
+
+    #[test]
+    fn test_rejects_invalid_area_names() {
+        let data = DataSet {
+            areas: vec![crate::model::area::test_utils::dummy_area()],
+            ..Default::default()
+        };
+        let mut document = ExportDocument::from_data_set(&data, exported_at());
+        document.areas[0].name = String::new();
+
+        assert!(matches!(
+            document.into_data_set(),
+            Err(HeliaError::InvalidImportDocument { reason }) if reason.contains("name")
+        ));
+    }
+
+    #[test]
+    fn test_rejects_unknown_review_steps() {
+        let data = DataSet {
+            reviews: vec![in_progress_review()],
+            ..Default::default()
+        };
+        let mut document = ExportDocument::from_data_set(&data, exported_at());
+        document.reviews[0].completed_steps[0].step = String::from("clean_desk");
+
+        assert!(matches!(
+            document.into_data_set(),
+            Err(HeliaError::InvalidImportDocument { reason }) if reason.contains("clean_desk")
+        ));
+    }
+
+    #[test]
+    fn test_rejects_second_review_in_progress() {
+        let data = DataSet {
+            reviews: vec![in_progress_review(), in_progress_review()],
+            ..Default::default()
+        };
+        let document = ExportDocument::from_data_set(&data, exported_at());
+
+        assert!(matches!(
+            document.into_data_set(),
+            Err(HeliaError::InvalidImportDocument { reason }) if reason.contains("in progress")
+        ));
+    }
+
+    #[test]
+    fn test_rejects_dangling_references() {
+        let mut action = crate::model::action::test_utils::dummy_action();
+        action.set_project_id(Some(ProjectId::new()));
+        let data = DataSet {
+            actions: vec![action],
+            ..Default::default()
+        };
+        let document = ExportDocument::from_data_set(&data, exported_at());
+
+        assert!(matches!(
+            document.into_data_set(),
+            Err(HeliaError::InvalidImportDocument { .. })
+        ));
+    }
+
+    #[test]
+    fn test_rejects_unknown_project_status() {
+        let data = DataSet {
+            projects: vec![crate::model::project::test_utils::dummy_project()],
+            ..Default::default()
+        };
+        let mut document = ExportDocument::from_data_set(&data, exported_at());
+        document.projects[0].status = String::from("paused");
+
+        assert!(matches!(
+            document.into_data_set(),
+            Err(HeliaError::InvalidImportDocument { .. })
+        ));
+    }
+}
@@ -1,21 +1,518 @@
 //! Error definitions for the Helia core.
 
-use crate::storage::storage_error::StorageError;
+use std::path::PathBuf;
+
+use crate::{
+    core::requests::ParseActionQueryError,
+    model::{
+        action::action_id::ActionId,
+        area::area_id::AreaId,
+        context::context_id::ContextId,
+        inbox_item::inbox_item_id::InboxItemId,
+        perspective::perspective_id::PerspectiveId,
+        project::project_id::ProjectId,
+        review::review_step::ReviewStep,
+        tag::{tag_id::TagId, tag_path::TagPath},
+        validation::{FieldError, Violation},
+    },
+    storage::storage_error::StorageError,
+};
 
 use thiserror::Error;
 
 /// This enum encodes all the possible errors that can occur during Helia's runtime.
+///
+/// Errors caused by the storage layer carry the [StorageError] as their
+/// [source](std::error::Error::source), see [HeliaError::storage_error].
 #[derive(Error, Debug)]
 pub enum HeliaError {
+    #[error("Action {action_id} is already completed.")]
+    ActionAlreadyCompleted { action_id: ActionId },
+
+    #[error("Action {action_id} belongs to a project, whose area it shares.")]
+    ActionInProject { action_id: ActionId },
+
+    #[error("Action {action_id} is not completed.")]
+    ActionNotCompleted { action_id: ActionId },
+
+    #[error("Action {action_id} is not delegated.")]
+    ActionNotDelegated { action_id: ActionId },
+
+    #[error("Action {action_id} does not exist.")]
+    ActionNotFound { action_id: ActionId },
+
+    #[error("Action {action_id} does not belong to project {project_id}.")]
+    ActionNotInProject {
+        action_id: ActionId,
+        project_id: ProjectId,
+    },
+
+    #[error("Area {area_id} does not exist.")]
+    AreaNotFound { area_id: AreaId },
+
+    #[error("Failed to link action to context.")]
+    AssignContextFailed {
+        #[source]
+        storage_err: StorageError,
+    },
+
+    #[error("Failed to capture inbox item.")]
+    CaptureFailed {
+        #[source]
+        storage_err: StorageError,
+    },
+
+    #[error("Failed to complete action.")]
+    CompleteActionFailed {
+        #[source]
+        storage_err: StorageError,
+    },
+
+    #[error("Failed to complete review step.")]
+    CompleteReviewStepFailed {
+        #[source]
+        storage_err: StorageError,
+    },
+
+    #[error("Context {context_id} is still used by {action_count} action(s).")]
+    ContextInUse {
+        context_id: ContextId,
+        action_count: usize,
+    },
+
+    #[error("Action {action_id} is not linked to context {context_id}.")]
+    ContextNotAssigned {
+        action_id: ActionId,
+        context_id: ContextId,
+    },
+
+    #[error("Context {context_id} does not exist.")]
+    ContextNotFound { context_id: ContextId },
+
     #[error("Failed to create action.")]
-    CreateActionFailed { storage_err: StorageError },
+    CreateActionFailed {
+        #[source]
+        storage_err: StorageError,
+    },
+
+    #[error("Failed to create area.")]
+    CreateAreaFailed {
+        #[source]
+        storage_err: StorageError,
+    },
+
+    #[error("Failed to create context.")]
+    CreateContextFailed {
+        #[source]
+        storage_err: StorageError,
+    },
+
+    #[error("Failed to create perspective.")]
+    CreatePerspectiveFailed {
+        #[source]
+        storage_err: StorageError,
+    },
+
+    #[error("Failed to create project.")]
+    CreateProjectFailed {
+        #[source]
+        storage_err: StorageError,
+    },
+
+    #[error("Failed to create tag.")]
+    CreateTagFailed {
+        #[source]
+        storage_err: StorageError,
+    },
+
+    #[error("Failed to create data directory {}.", path.display())]
+    CreatingDataDirFailed {
+        path: PathBuf,
+        #[source]
+        io_err: std::io::Error,
+    },
+
+    #[error("Could not determine the user data directory: neither XDG_DATA_HOME nor HOME is set.")]
+    DataDirUnavailable,
+
+    #[error("Failed to delegate action.")]
+    DelegateActionFailed {
+        #[source]
+        storage_err: StorageError,
+    },
+
+    #[error("Failed to delete action.")]
+    DeleteActionFailed {
+        #[source]
+        storage_err: StorageError,
+    },
+
+    #[error("Failed to delete area.")]
+    DeleteAreaFailed {
+        #[source]
+        storage_err: StorageError,
+    },
+
+    #[error("Failed to delete context.")]
+    DeleteContextFailed {
+        #[source]
+        storage_err: StorageError,
+    },
+
+    #[error("Failed to delete perspective.")]
+    DeletePerspectiveFailed {
+        #[source]
+        storage_err: StorageError,
+    },
+
+    #[error("Failed to delete project.")]
+    DeleteProjectFailed {
+        #[source]
+        storage_err: StorageError,
+    },
+
+    #[error("Failed to delete tag.")]
+    DeleteTagFailed {
+        #[source]
+        storage_err: StorageError,
+    },
+
+    #[error("Failed to read data for export.")]
+    ExportFailed {
+        #[source]
+        storage_err: StorageError,
+    },
+
+    #[error("Failed to fetch action.")]
+    FetchingActionFailed {
+        #[source]
+        storage_err: StorageError,
+    },
+
+    #[error("Failed to fetch area.")]
+    FetchingAreaFailed {
+        #[source]
+        storage_err: StorageError,
+    },
+
+    #[error("Failed to fetch context.")]
+    FetchingContextFailed {
+        #[source]
+        storage_err: StorageError,
+    },
+
+    #[error("Failed to fetch inbox item.")]
+    FetchingInboxItemFailed {
+        #[source]
+        storage_err: StorageError,
+    },
+
+    #[error("Failed to fetch perspective.")]
+    FetchingPerspectiveFailed {
+        #[source]
+        storage_err: StorageError,
+    },
+
+    #[error("Failed to fetch project.")]
+    FetchingProjectFailed {
+        #[source]
+        storage_err: StorageError,
+    },
+
+    #[error("Failed to fetch review.")]
+    FetchingReviewFailed {
+        #[source]
+        storage_err: StorageError,
+    },
 
     #[error("Failed to fetch database schema version.")]
-    FetchingStorageVersionFailed { storage_err: StorageError },
+    FetchingStorageVersionFailed {
+        #[source]
+        storage_err: StorageError,
+    },
+
+    #[error("Failed to fetch tag.")]
+    FetchingTagFailed {
+        #[source]
+        storage_err: StorageError,
+    },
+
+    #[error("Failed to import data.")]
+    ImportFailed {
+        #[source]
+        storage_err: StorageError,
+    },
+
+    #[error("Inbox item {inbox_item_id} does not exist.")]
+    InboxItemNotFound { inbox_item_id: InboxItemId },
+
+    #[error("Invalid action query: {parse_err}.")]
+    InvalidActionQuery {
+        #[source]
+        parse_err: ParseActionQueryError,
+    },
+
+    #[error("Invalid import document: {reason}.")]
+    InvalidImportDocument { reason: String },
+
+    #[error("Failed to list actions.")]
+    ListingActionsFailed {
+        #[source]
+        storage_err: StorageError,
+    },
+
+    #[error("Failed to list areas.")]
+    ListingAreasFailed {
+        #[source]
+        storage_err: StorageError,
+    },
+
+    #[error("Failed to list contexts.")]
+    ListingContextsFailed {
+        #[source]
+        storage_err: StorageError,
+    },
+
+    #[error("Failed to list inbox items.")]
+    ListingInboxFailed {
+        #[source]
+        storage_err: StorageError,
+    },
+
+    #[error("Failed to list perspectives.")]
+    ListingPerspectivesFailed {
+        #[source]
+        storage_err: StorageError,
+    },
+
+    #[error("Failed to list projects.")]
+    ListingProjectsFailed {
+        #[source]
+        storage_err: StorageError,
+    },
+
+    #[error("Failed to list reference items.")]
+    ListingReferenceItemsFailed {
+        #[source]
+        storage_err: StorageError,
+    },
+
+    #[error("Failed to list tags.")]
+    ListingTagsFailed {
+        #[source]
+        storage_err: StorageError,
+    },
+
+    #[error("Failed to merge tags.")]
+    MergeTagsFailed {
+        #[source]
+        storage_err: StorageError,
+    },
 
     #[error("Failed to run migrations.")]
-    MigrationsFailed { storage_err: StorageError },
+    MigrationsFailed {
+        #[source]
+        storage_err: StorageError,
+    },
+
+    #[error("Failed to move action.")]
+    MoveActionFailed {
+        #[source]
+        storage_err: StorageError,
+    },
+
+    #[error("No review is in progress.")]
+    NoReviewInProgress,
+
+    #[error("Could not open database at {}.", path.display())]
+    OpeningDatabaseFailed {
+        path: PathBuf,
+        #[source]
+        storage_err: StorageError,
+    },
+
+    #[error("Perspective {perspective_id} does not exist.")]
+    PerspectiveNotFound { perspective_id: PerspectiveId },
+
+    #[error("Failed to process inbox item.")]
+    ProcessInboxItemFailed {
+        #[source]
+        storage_err: StorageError,
+    },
+
+    #[error("Project {project_id} does not exist.")]
+    ProjectNotFound { project_id: ProjectId },
+
+    #[error("Failed to read import document: {json_err}")]
+    ReadingImportFailed {
+        #[source]
+        json_err: serde_json::Error,
+    },
+
+    #[error("Failed to rename action.")]
+    RenameActionFailed {
+        #[source]
+        storage_err: StorageError,
+    },
+
+    #[error("Failed to rename area.")]
+    RenameAreaFailed {
+        #[source]
+        storage_err: StorageError,
+    },
+
+    #[error("Failed to rename context.")]
+    RenameContextFailed {
+        #[source]
+        storage_err: StorageError,
+    },
+
+    #[error("Failed to rename tag.")]
+    RenameTagFailed {
+        #[source]
+        storage_err: StorageError,
+    },
+
+    #[error("Failed to reclaim action.")]
+    ReclaimActionFailed {
+        #[source]
+        storage_err: StorageError,
+    },
+
+    #[error("Failed to record the operation in the undo history.")]
+    RecordingHistoryFailed {
+        #[source]
+        storage_err: StorageError,
+    },
+
+    #[error("Failed to redo operation.")]
+    RedoFailed {
+        #[source]
+        storage_err: StorageError,
+    },
+
+    #[error("Failed to reopen action.")]
+    ReopenActionFailed {
+        #[source]
+        storage_err: StorageError,
+    },
+
+    #[error("Failed to reorder actions.")]
+    ReorderActionsFailed {
+        #[source]
+        storage_err: StorageError,
+    },
+
+    #[error("Review step {step} can't be completed before {expected}.")]
+    ReviewStepOutOfOrder {
+        step: ReviewStep,
+        expected: ReviewStep,
+    },
+
+    #[error("Failed to search.")]
+    SearchFailed {
+        #[source]
+        storage_err: StorageError,
+    },
+
+    #[error("Failed to start review.")]
+    StartReviewFailed {
+        #[source]
+        storage_err: StorageError,
+    },
 
     #[error("Could not connect to the storage backend.")]
-    StorageConnectionFailed { storage_err: StorageError },
+    StorageConnectionFailed {
+        #[source]
+        storage_err: StorageError,
+    },
+
+    #[error("Failed to link item to tag.")]
+    TagItemFailed {
+        #[source]
+        storage_err: StorageError,
+    },
+
+    #[error("Tag {tag_id} is not linked to the item.")]
+    TagNotAssigned { tag_id: TagId },
+
+    #[error("Tag {tag_id} does not exist.")]
+    TagNotFound { tag_id: TagId },
+
+    #[error("Tag path {} is already taken.", path.as_str())]
+    TagPathTaken { path: TagPath },
+
+    #[error("Failed to unlink action from context.")]
+    UnassignContextFailed {
+        #[source]
+        storage_err: StorageError,
+    },
+
+    #[error("Failed to undo operation.")]
+    UndoFailed {
+        #[source]
+        storage_err: StorageError,
+    },
+
+    #[error("Export format version {version} is not supported.")]
+    UnsupportedExportVersion { version: u32 },
+
+    #[error("Failed to unlink item from tag.")]
+    UntagItemFailed {
+        #[source]
+        storage_err: StorageError,
+    },
+
+    #[error("Failed to update action.")]
+    UpdateActionFailed {
+        #[source]
+        storage_err: StorageError,
+    },
+
+    #[error("Failed to update perspective.")]
+    UpdatePerspectiveFailed {
+        #[source]
+        storage_err: StorageError,
+    },
+
+    #[error("Failed to update project.")]
+    UpdateProjectFailed {
+        #[source]
+        storage_err: StorageError,
+    },
+
+    #[error("Invalid input: {}", join_field_errors(errors))]
+    ValidationFailed { errors: Vec<FieldError> },
+
+    #[error("Failed to write export document: {json_err}")]
+    WritingExportFailed {
+        #[source]
+        json_err: serde_json::Error,
+    },
+}
+
+impl HeliaError {
+    /// Creates a [HeliaError::ValidationFailed] for a single invalid field.
+    pub(crate) fn invalid_field(field: &'static str, violation: Violation) -> Self {
+        HeliaError::ValidationFailed {
+            errors: vec![FieldError::new(field, violation)],
+        }
+    }
+
+    /// Returns the [StorageError] that caused this error, if any.
+    ///
+    /// Use its [kind](StorageError::kind) to react to the cause, e.g. to retry when the
+    /// database is busy.
+    pub fn storage_error(&self) -> Option<&StorageError> {
+        std::error::Error::source(self).and_then(|source| source.downcast_ref())
+    }
+}
+
+/// Formats field errors as one comma separated list.
+fn join_field_errors(errors: &[FieldError]) -> String {
+    errors
+        .iter()
+        .map(FieldError::to_string)
+        .collect::<Vec<_>>()
+        .join(", ")
 }
@@ -1,24 +1,217 @@
 //! Production implementation of the [`HeliaCore`] trait.
 
+use std::{
+    cell::Cell,
+    io::{Read, Write},
+};
+
+use chrono::{DateTime, Duration, Utc};
+use tracing::error;
+
 use crate::{
-    core::{helia_error::HeliaError, requests::CreateActionRequest, HeliaCore},
-    model::action::Action,
-    storage::{migration, Storage},
+    core::{
+        HeliaCore,
+        clock::Clock,
+        export::ExportDocument,
+        helia_error::HeliaError,
+        requests::{
+            ActionQuery, ActionStatusFilter, AssignContextRequest, CreateActionRequest,
+            CreateAreaRequest, CreateContextRequest, CreatePerspectiveRequest,
+            CreateProjectRequest, CreateTagRequest, DelegateActionRequest, InboxItemOutcome,
+            ListActionsRequest, MergeTagsRequest, MoveActionRequest, ProcessInboxItemRequest,
+            RenameActionRequest, RenameAreaRequest, RenameContextRequest, RenameTagRequest,
+            ReorderActionsRequest, SetActionAreaRequest, SetProjectAreaRequest, TagActionRequest,
+            TagProjectRequest, UpdateActionRequest, UpdatePerspectiveRequest, UpdateProjectRequest,
+        },
+        responses::{
+            AreaSummary, CompletedAction, ImportSummary, ProcessedInboxItem, SearchResult,
+            SomedayMaybeList, StalledProject,
+        },
+    },
+    model::{
+        action::{
+            Action, acion_create_date::ActionCreateDate, action_complete_date::ActionCompleteDate,
+            action_defer_date::ActionDeferDate, action_delegation::ActionDelegation,
+            action_due_date::ActionDueDate, action_id::ActionId, action_name::ActionName,
+            action_notes::ActionNotes,
+        },
+        area::{Area, area_id::AreaId, area_name::AreaName},
+        context::{Context, context_id::ContextId, context_name::ContextName},
+        inbox_item::{
+            InboxItem, inbox_item_capture_date::InboxItemCaptureDate, inbox_item_id::InboxItemId,
+            inbox_item_text::InboxItemText,
+        },
+        perspective::{
+            Perspective, perspective_id::PerspectiveId, perspective_name::PerspectiveName,
+            perspective_query::PerspectiveQuery,
+        },
+        project::{
+            Project, project_create_date::ProjectCreateDate, project_id::ProjectId,
+            project_name::ProjectName, project_review_date::ProjectReviewDate,
+            project_status::ProjectStatus,
+        },
+        reference_item::{
+            ReferenceItem, reference_item_create_date::ReferenceItemCreateDate,
+            reference_item_id::ReferenceItemId, reference_item_text::ReferenceItemText,
+        },
+        review::{Review, review_id::ReviewId, review_step::ReviewStep},
+        tag::{Tag, tag_id::TagId, tag_path::TagPath},
+        validation::Violation,
+    },
+    storage::{
+        ActionFilter, ActionOrder, InboxItemTarget, ProjectFilter, Storage, migration,
+        storage_error::StorageError,
+    },
 };
 
+/// The number of operations that can be undone, see [HeliaCore::undo].
+const MAX_HISTORY_STEPS: usize = 100;
+
+/// The number of logged changes the undo history keeps at most, see [HeliaCore::undo].
+const MAX_HISTORY_ENTRIES: usize = 100_000;
+
 /// [`HeliaProd`] is the production implementation of the [`HeliaCore`] trait.
-pub struct HeliaProd<S: Storage> {
+///
+/// All time based behaviour reads the current time from its [Clock].
+pub struct HeliaProd<S: Storage, C: Clock> {
     storage: S,
+    clock: C,
+    /// Whether a recorded operation is running, see [HeliaProd::record].
+    recording: Cell<bool>,
 }
 
-impl<S: Storage> HeliaProd<S> {
+impl<S: Storage, C: Clock> HeliaProd<S, C> {
     /// Returns a new [HeliaProd] instance.
-    pub fn new(storage: S) -> Self {
-        Self { storage }
+    pub fn new(storage: S, clock: C) -> Self {
+        Self {
+            storage,
+            clock,
+            recording: Cell::new(false),
+        }
+    }
+
+    /// Runs a mutating operation as one step of the undo history, see [HeliaCore::undo].
+    ///
+    /// Operations run by a recorded operation are part of its step. Changes made before an
+    /// operation failed are recorded as well. The changes are already saved once `run` returns,
+    /// so failing to record them is only logged; they just can't be undone.
+    fn record<T>(
+        &self,
+        operation: &str,
+        run: impl FnOnce() -> Result<T, HeliaError>,
+    ) -> Result<T, HeliaError> {
+        if self.recording.get() {
+            return run();
+        }
+        if let Err(err) = self.storage.start_history_step() {
+            return Err(HeliaError::RecordingHistoryFailed { storage_err: err });
+        }
+        self.recording.set(true);
+        let result = run();
+        self.recording.set(false);
+        if let Err(err) =
+            self.storage
+                .finish_history_step(operation, MAX_HISTORY_STEPS, MAX_HISTORY_ENTRIES)
+        {
+            error!(error = %err, operation, "Failed to record the operation in the undo history.");
+        }
+        result
+    }
+
+    /// Checks that the tag at `from` and its descendants still have valid paths once moved below
+    /// `to`, reporting violations for `field`.
+    fn check_moved_tag_paths(
+        &self,
+        from: &TagPath,
+        to: &TagPath,
+        field: &'static str,
+    ) -> Result<(), HeliaError> {
+        let tags = self.list_tags()?;
+        for tag in tags.iter().filter(|tag| from.contains(tag.tag_path())) {
+            let rest = &tag.tag_path().as_str()[from.as_str().len()..];
+            TagPath::new(&format!("{}{rest}", to.as_str()))
+                .map_err(|violation| HeliaError::invalid_field(field, violation))?;
+        }
+        Ok(())
+    }
+
+    /// Fetches the actions matching the given [ActionFilter].
+    fn list_actions_matching(&self, filter: &ActionFilter) -> Result<Vec<Action>, HeliaError> {
+        match self.storage.list_actions(filter) {
+            Ok(actions) => Ok(actions),
+            Err(err) => Err(HeliaError::ListingActionsFailed { storage_err: err }),
+        }
+    }
+
+    /// Returns the position behind the last action of the given project, or `None` if no
+    /// project is given.
+    fn next_position(&self, project_id: Option<&ProjectId>) -> Result<Option<u32>, StorageError> {
+        match project_id {
+            Some(project_id) => self.storage.next_action_position(project_id).map(Some),
+            None => Ok(None),
+        }
+    }
+
+    /// Fetches the projects matching the given [ProjectFilter].
+    fn list_projects_matching(&self, filter: &ProjectFilter) -> Result<Vec<Project>, HeliaError> {
+        match self.storage.list_projects(filter) {
+            Ok(projects) => Ok(projects),
+            Err(err) => Err(HeliaError::ListingProjectsFailed { storage_err: err }),
+        }
+    }
+
+    /// Moves an open action onto (`true`) or off (`false`) the someday/maybe list.
+    fn set_action_someday(
+        &self,
+        action_id: &ActionId,
+        someday: bool,
+    ) -> Result<Action, HeliaError> {
+        let mut action = self.get_action(action_id)?;
+        if action.is_completed() {
+            return Err(HeliaError::ActionAlreadyCompleted {
+                action_id: action_id.clone(),
+            });
+        }
+        action.set_someday(someday);
+        match self.storage.update_action(&action) {
+            Ok(_) => Ok(action),
+            Err(StorageError::NotFound) => Err(HeliaError::ActionNotFound {
+                action_id: action_id.clone(),
+            }),
+            Err(err) => Err(HeliaError::UpdateActionFailed { storage_err: err }),
+        }
+    }
+
+    /// Moves a project onto (`true`) or off (`false`) the someday/maybe list.
+    fn set_project_someday(
+        &self,
+        project_id: &ProjectId,
+        someday: bool,
+    ) -> Result<Project, HeliaError> {
+        let mut project = self.get_project(project_id)?;
+        project.set_someday(someday);
+        match self.storage.update_project(&project) {
+            Ok(_) => Ok(project),
+            Err(StorageError::NotFound) => Err(HeliaError::ProjectNotFound {
+                project_id: project_id.clone(),
+            }),
+            Err(err) => Err(HeliaError::UpdateProjectFailed { storage_err: err }),
+        }
+    }
+
+    /// Tries to fetch the [Context] with the given [ContextId].
+    fn get_context(&self, context_id: &ContextId) -> Result<Context, HeliaError> {
+        match self.storage.get_context(context_id) {
+            Ok(context) => Ok(context),
+            Err(StorageError::NotFound) => Err(HeliaError::ContextNotFound {
+                context_id: context_id.clone(),
+            }),
+            Err(err) => Err(HeliaError::FetchingContextFailed { storage_err: err }),
+        }
     }
 }
 
-impl<S: Storage> HeliaCore for HeliaProd<S> {
+impl<S: Storage, C: Clock> HeliaCore for HeliaProd<S, C> {
     fn storage_version(&self) -> Result<u32, HeliaError> {
         match self.storage.schema_version() {
             Ok(storage_version) => Ok(storage_version),
@@ -37,11 +230,1184 @@ impl<S: Storage> HeliaCore for HeliaProd<S> {
     }
 
     fn create_action(&self, request: CreateActionRequest) -> Result<Action, HeliaError> {
-        let action = request.into_action();
-        let result = self.storage.insert_action(&action);
-        match result {
-            Ok(_) => Ok(action),
-            Err(err) => Err(HeliaError::CreateActionFailed { storage_err: err }),
+        self.record("Create action", || {
+            let project_id = request.project_id.clone();
+            let mut action = request.into_action(ActionCreateDate::from_value(self.clock.now()))?;
+            if let Some(project_id) = &project_id {
+                self.get_project(project_id)?;
+            }
+            match self.next_position(project_id.as_ref()) {
+                Ok(position) => action.set_position(position),
+                Err(err) => return Err(HeliaError::CreateActionFailed { storage_err: err }),
+            }
+            let result = self.storage.insert_action(&action);
+            match result {
+                Ok(_) => Ok(action),
+                Err(err) => Err(HeliaError::CreateActionFailed { storage_err: err }),
+            }
+        })
+    }
+
+    fn get_action(&self, action_id: &ActionId) -> Result<Action, HeliaError> {
+        match self.storage.get_action(action_id) {
+            Ok(action) => Ok(action),
+            Err(StorageError::NotFound) => Err(HeliaError::ActionNotFound {
+                action_id: action_id.clone(),
+            }),
+            Err(err) => Err(HeliaError::FetchingActionFailed { storage_err: err }),
+        }
+    }
+
+    fn list_actions(&self, request: ListActionsRequest) -> Result<Vec<Action>, HeliaError> {
+        let completed = match request.status {
+            ActionStatusFilter::All => None,
+            ActionStatusFilter::Open => Some(false),
+            ActionStatusFilter::Completed => Some(true),
+        };
+        let order = match request.project_id {
+            Some(_) => ActionOrder::Position,
+            None => ActionOrder::CreateDate,
+        };
+        let tag = match &request.tag_id {
+            Some(tag_id) => Some(self.get_tag(tag_id)?.tag_path().clone()),
+            None => None,
+        };
+        let filter = ActionFilter {
+            order,
+            completed,
+            project_id: request.project_id,
+            context_id: request.context_id,
+            tag,
+            someday: (!request.include_someday).then_some(false),
+            ..Default::default()
+        };
+        self.list_actions_matching(&filter)
+    }
+
+    fn query_actions(&self, query: ActionQuery) -> Result<Vec<Action>, HeliaError> {
+        match self.storage.query_actions(&query) {
+            Ok(actions) => Ok(actions),
+            Err(err) => Err(HeliaError::ListingActionsFailed { storage_err: err }),
+        }
+    }
+
+    fn parse_action_query(&self, text: &str) -> Result<ActionQuery, HeliaError> {
+        ActionQuery::parse(text, self.clock.now())
+            .map_err(|parse_err| HeliaError::InvalidActionQuery { parse_err })
+    }
+
+    fn rename_action(&self, request: RenameActionRequest) -> Result<Action, HeliaError> {
+        self.record("Rename action", || {
+            let name = ActionName::new(&request.name)
+                .map_err(|violation| HeliaError::invalid_field("name", violation))?;
+            let mut action = self.get_action(&request.action_id)?;
+            action.set_action_name(name);
+            match self.storage.update_action(&action) {
+                Ok(_) => Ok(action),
+                Err(StorageError::NotFound) => Err(HeliaError::ActionNotFound {
+                    action_id: request.action_id,
+                }),
+                Err(err) => Err(HeliaError::RenameActionFailed { storage_err: err }),
+            }
+        })
+    }
+
+    fn update_action(&self, request: UpdateActionRequest) -> Result<Action, HeliaError> {
+        self.record("Update action", || {
+            if let Some(Some(recurrence)) = &request.recurrence {
+                recurrence
+                    .validate()
+                    .map_err(|err| HeliaError::invalid_field("recurrence", err.into()))?;
+            }
+            let mut action = self.get_action(&request.action_id)?;
+            if let Some(due_date) = request.due_date {
+                action.set_action_due_date(due_date.map(ActionDueDate::from_value));
+            }
+            if let Some(defer_date) = request.defer_date {
+                action.set_action_defer_date(defer_date.map(ActionDeferDate::from_value));
+            }
+            if let Some(notes) = request.notes {
+                action.set_action_notes(notes.as_deref().map(ActionNotes::new));
+            }
+            if let Some(recurrence) = request.recurrence {
+                action.set_action_recurrence(recurrence);
+            }
+            match self.storage.update_action(&action) {
+                Ok(_) => Ok(action),
+                Err(StorageError::NotFound) => Err(HeliaError::ActionNotFound {
+                    action_id: request.action_id,
+                }),
+                Err(err) => Err(HeliaError::UpdateActionFailed { storage_err: err }),
+            }
+        })
+    }
+
+    fn list_available_actions(&self) -> Result<Vec<Action>, HeliaError> {
+        self.list_actions_matching(&ActionFilter {
+            completed: Some(false),
+            available_at: Some(self.clock.now()),
+            delegated: Some(false),
+            someday: Some(false),
+            ..Default::default()
+        })
+    }
+
+    fn list_next_actions(&self) -> Result<Vec<Action>, HeliaError> {
+        self.list_actions_matching(&ActionFilter {
+            completed: Some(false),
+            available_at: Some(self.clock.now()),
+            delegated: Some(false),
+            someday: Some(false),
+            unblocked: true,
+            ..Default::default()
+        })
+    }
+
+    fn list_overdue_actions(&self) -> Result<Vec<Action>, HeliaError> {
+        self.list_actions_matching(&ActionFilter {
+            completed: Some(false),
+            due_before: Some(self.clock.now()),
+            someday: Some(false),
+            order: ActionOrder::DueDate,
+            ..Default::default()
+        })
+    }
+
+    fn list_actions_due_within(&self, days: u32) -> Result<Vec<Action>, HeliaError> {
+        let now = self.clock.now();
+        self.list_actions_matching(&ActionFilter {
+            completed: Some(false),
+            due_from: Some(now),
+            due_before: Some(now + Duration::days(i64::from(days))),
+            someday: Some(false),
+            order: ActionOrder::DueDate,
+            ..Default::default()
+        })
+    }
+
+    fn delete_action(&self, action_id: &ActionId) -> Result<(), HeliaError> {
+        self.record("Delete action", || {
+            match self.storage.delete_action(action_id) {
+                Ok(_) => Ok(()),
+                Err(StorageError::NotFound) => Err(HeliaError::ActionNotFound {
+                    action_id: action_id.clone(),
+                }),
+                Err(err) => Err(HeliaError::DeleteActionFailed { storage_err: err }),
+            }
+        })
+    }
+
+    fn complete_action(&self, action_id: &ActionId) -> Result<CompletedAction, HeliaError> {
+        self.record("Complete action", || {
+            let mut action = self.get_action(action_id)?;
+            if action.is_completed() {
+                return Err(HeliaError::ActionAlreadyCompleted {
+                    action_id: action_id.clone(),
+                });
+            }
+            let now = self.clock.now();
+            action.set_action_complete_date(Some(ActionCompleteDate::from_value(now)));
+            let next_occurrence =
+                action.next_occurrence(ActionId::new(), ActionCreateDate::from_value(now), now);
+            let result = match &next_occurrence {
+                Some(next_occurrence) => {
+                    // The rule moves on to the next occurrence, so reopening and completing this
+                    // one again doesn't start a second series.
+                    action.set_action_recurrence(None);
+                    self.storage
+                        .complete_recurring_action(&action, next_occurrence)
+                }
+                None => self.storage.update_action(&action),
+            };
+            match result {
+                Ok(_) => Ok(CompletedAction {
+                    action,
+                    next_occurrence,
+                }),
+                Err(StorageError::NotFound) => Err(HeliaError::ActionNotFound {
+                    action_id: action_id.clone(),
+                }),
+                Err(err) => Err(HeliaError::CompleteActionFailed { storage_err: err }),
+            }
+        })
+    }
+
+    fn reopen_action(&self, action_id: &ActionId) -> Result<Action, HeliaError> {
+        self.record("Reopen action", || {
+            let mut action = self.get_action(action_id)?;
+            if !action.is_completed() {
+                return Err(HeliaError::ActionNotCompleted {
+                    action_id: action_id.clone(),
+                });
+            }
+            action.set_action_complete_date(None);
+            match self.storage.update_action(&action) {
+                Ok(_) => Ok(action),
+                Err(StorageError::NotFound) => Err(HeliaError::ActionNotFound {
+                    action_id: action_id.clone(),
+                }),
+                Err(err) => Err(HeliaError::ReopenActionFailed { storage_err: err }),
+            }
+        })
+    }
+
+    fn delegate_action(&self, request: DelegateActionRequest) -> Result<Action, HeliaError> {
+        self.record("Delegate action", || {
+            let delegation =
+                ActionDelegation::new(&request.delegate, self.clock.now(), request.follow_up_date)
+                    .map_err(|violation| HeliaError::invalid_field("delegate", violation))?;
+            let mut action = self.get_action(&request.action_id)?;
+            if action.is_completed() {
+                return Err(HeliaError::ActionAlreadyCompleted {
+                    action_id: request.action_id,
+                });
+            }
+            action.set_action_delegation(Some(delegation));
+            match self.storage.update_action(&action) {
+                Ok(_) => Ok(action),
+                Err(StorageError::NotFound) => Err(HeliaError::ActionNotFound {
+                    action_id: request.action_id,
+                }),
+                Err(err) => Err(HeliaError::DelegateActionFailed { storage_err: err }),
+            }
+        })
+    }
+
+    fn reclaim_action(&self, action_id: &ActionId) -> Result<Action, HeliaError> {
+        self.record("Reclaim action", || {
+            let mut action = self.get_action(action_id)?;
+            if !action.is_delegated() {
+                return Err(HeliaError::ActionNotDelegated {
+                    action_id: action_id.clone(),
+                });
+            }
+            action.set_action_delegation(None);
+            match self.storage.update_action(&action) {
+                Ok(_) => Ok(action),
+                Err(StorageError::NotFound) => Err(HeliaError::ActionNotFound {
+                    action_id: action_id.clone(),
+                }),
+                Err(err) => Err(HeliaError::ReclaimActionFailed { storage_err: err }),
+            }
+        })
+    }
+
+    fn list_waiting_for(&self) -> Result<Vec<Action>, HeliaError> {
+        self.list_actions_matching(&ActionFilter {
+            completed: Some(false),
+            delegated: Some(true),
+            someday: Some(false),
+            order: ActionOrder::FollowUp,
+            ..Default::default()
+        })
+    }
+
+    fn list_someday_maybe(&self) -> Result<SomedayMaybeList, HeliaError> {
+        let actions = self.list_actions_matching(&ActionFilter {
+            completed: Some(false),
+            someday: Some(true),
+            ..Default::default()
+        })?;
+        let projects = self.list_projects_matching(&ProjectFilter {
+            someday: Some(true),
+            ..Default::default()
+        })?;
+        Ok(SomedayMaybeList { actions, projects })
+    }
+
+    fn demote_action(&self, action_id: &ActionId) -> Result<Action, HeliaError> {
+        self.record("Demote action", || self.set_action_someday(action_id, true))
+    }
+
+    fn promote_action(&self, action_id: &ActionId) -> Result<Action, HeliaError> {
+        self.record("Promote action", || {
+            self.set_action_someday(action_id, false)
+        })
+    }
+
+    fn demote_project(&self, project_id: &ProjectId) -> Result<Project, HeliaError> {
+        self.record("Demote project", || {
+            self.set_project_someday(project_id, true)
+        })
+    }
+
+    fn promote_project(&self, project_id: &ProjectId) -> Result<Project, HeliaError> {
+        self.record("Promote project", || {
+            self.set_project_someday(project_id, false)
+        })
+    }
+
+    fn move_action(&self, request: MoveActionRequest) -> Result<Action, HeliaError> {
+        self.record("Move action", || {
+            let mut action = self.get_action(&request.action_id)?;
+            if let Some(project_id) = &request.project_id {
+                self.get_project(project_id)?;
+            }
+            if action.project_id() != request.project_id.as_ref() {
+                match self.next_position(request.project_id.as_ref()) {
+                    Ok(position) => action.set_position(position),
+                    Err(err) => return Err(HeliaError::MoveActionFailed { storage_err: err }),
+                }
+            }
+            if request.project_id.is_some() {
+                action.set_area_id(None);
+            }
+            action.set_project_id(request.project_id);
+            match self.storage.update_action(&action) {
+                Ok(_) => Ok(action),
+                Err(StorageError::NotFound) => Err(HeliaError::ActionNotFound {
+                    action_id: request.action_id,
+                }),
+                Err(err) => Err(HeliaError::MoveActionFailed { storage_err: err }),
+            }
+        })
+    }
+
+    fn create_project(&self, request: CreateProjectRequest) -> Result<Project, HeliaError> {
+        self.record("Create project", || {
+            let project = request.into_project(ProjectCreateDate::from_value(self.clock.now()));
+            match self.storage.insert_project(&project) {
+                Ok(_) => Ok(project),
+                Err(err) => Err(HeliaError::CreateProjectFailed { storage_err: err }),
+            }
+        })
+    }
+
+    fn get_project(&self, project_id: &ProjectId) -> Result<Project, HeliaError> {
+        match self.storage.get_project(project_id) {
+            Ok(project) => Ok(project),
+            Err(StorageError::NotFound) => Err(HeliaError::ProjectNotFound {
+                project_id: project_id.clone(),
+            }),
+            Err(err) => Err(HeliaError::FetchingProjectFailed { storage_err: err }),
+        }
+    }
+
+    fn list_projects(&self) -> Result<Vec<Project>, HeliaError> {
+        self.list_projects_matching(&ProjectFilter {
+            someday: Some(false),
+            ..Default::default()
+        })
+    }
+
+    fn stalled_projects(&self) -> Result<Vec<StalledProject>, HeliaError> {
+        match self.storage.list_stalled_projects(self.clock.now()) {
+            Ok(projects) => Ok(projects
+                .into_iter()
+                .map(|(project, last_completed_at)| StalledProject {
+                    project,
+                    last_completed_at,
+                })
+                .collect()),
+            Err(err) => Err(HeliaError::ListingProjectsFailed { storage_err: err }),
+        }
+    }
+
+    fn update_project(&self, request: UpdateProjectRequest) -> Result<Project, HeliaError> {
+        self.record("Update project", || {
+            let mut project = self.get_project(&request.project_id)?;
+            if let Some(name) = &request.name {
+                project.set_project_name(ProjectName::new(name));
+            }
+            if let Some(status) = request.status {
+                project.set_project_status(status);
+            }
+            if let Some(ordering) = request.ordering {
+                project.set_project_ordering(ordering);
+            }
+            match self.storage.update_project(&project) {
+                Ok(_) => Ok(project),
+                Err(StorageError::NotFound) => Err(HeliaError::ProjectNotFound {
+                    project_id: request.project_id,
+                }),
+                Err(err) => Err(HeliaError::UpdateProjectFailed { storage_err: err }),
+            }
+        })
+    }
+
+    fn reorder_actions(&self, request: ReorderActionsRequest) -> Result<Vec<Action>, HeliaError> {
+        self.record("Reorder actions", || {
+            self.get_project(&request.project_id)?;
+            let mut remaining = self.list_actions_matching(&ActionFilter {
+                order: ActionOrder::Position,
+                project_id: Some(request.project_id.clone()),
+                ..Default::default()
+            })?;
+
+            let mut actions = Vec::with_capacity(remaining.len());
+            for action_id in &request.action_ids {
+                if let Some(index) = remaining
+                    .iter()
+                    .position(|action| action.action_id() == action_id)
+                {
+                    actions.push(remaining.remove(index));
+                } else if actions
+                    .iter()
+                    .any(|action: &Action| action.action_id() == action_id)
+                {
+                    return Err(HeliaError::invalid_field(
+                        "action_ids",
+                        Violation::Invalid("lists an action more than once"),
+                    ));
+                } else {
+                    self.get_action(action_id)?;
+                    return Err(HeliaError::ActionNotInProject {
+                        action_id: action_id.clone(),
+                        project_id: request.project_id,
+                    });
+                }
+            }
+            actions.append(&mut remaining);
+
+            for (position, action) in actions.iter_mut().enumerate() {
+                action.set_position(Some(position as u32));
+            }
+            let action_ids: Vec<ActionId> = actions
+                .iter()
+                .map(|action| action.action_id().clone())
+                .collect();
+            match self
+                .storage
+                .set_action_positions(&request.project_id, &action_ids)
+            {
+                Ok(_) => Ok(actions),
+                Err(err) => Err(HeliaError::ReorderActionsFailed { storage_err: err }),
+            }
+        })
+    }
+
+    fn delete_project(&self, project_id: &ProjectId) -> Result<(), HeliaError> {
+        self.record("Delete project", || {
+            match self.storage.delete_project(project_id) {
+                Ok(_) => Ok(()),
+                Err(StorageError::NotFound) => Err(HeliaError::ProjectNotFound {
+                    project_id: project_id.clone(),
+                }),
+                Err(err) => Err(HeliaError::DeleteProjectFailed { storage_err: err }),
+            }
+        })
+    }
+
+    fn capture(&self, text: &str) -> Result<InboxItem, HeliaError> {
+        self.record("Capture inbox item", || {
+            let text = InboxItemText::new(text)
+                .map_err(|violation| HeliaError::invalid_field("text", violation))?;
+            let inbox_item = InboxItem::new(
+                InboxItemId::new(),
+                text,
+                InboxItemCaptureDate::from_value(self.clock.now()),
+            );
+            match self.storage.insert_inbox_item(&inbox_item) {
+                Ok(_) => Ok(inbox_item),
+                Err(err) => Err(HeliaError::CaptureFailed { storage_err: err }),
+            }
+        })
+    }
+
+    fn list_inbox(&self) -> Result<Vec<InboxItem>, HeliaError> {
+        match self.storage.list_inbox_items() {
+            Ok(inbox_items) => Ok(inbox_items),
+            Err(err) => Err(HeliaError::ListingInboxFailed { storage_err: err }),
+        }
+    }
+
+    fn process_inbox_item(
+        &self,
+        request: ProcessInboxItemRequest,
+    ) -> Result<ProcessedInboxItem, HeliaError> {
+        self.record("Process inbox item", || {
+            let inbox_item = match self.storage.get_inbox_item(&request.inbox_item_id) {
+                Ok(inbox_item) => inbox_item,
+                Err(StorageError::NotFound) => {
+                    return Err(HeliaError::InboxItemNotFound {
+                        inbox_item_id: request.inbox_item_id,
+                    });
+                }
+                Err(err) => return Err(HeliaError::FetchingInboxItemFailed { storage_err: err }),
+            };
+            let name = match &request.name {
+                Some(name) => name.as_str(),
+                None => inbox_item.inbox_item_text().as_str(),
+            };
+
+            let (target, processed) = match request.outcome {
+                InboxItemOutcome::Action { project_id } => {
+                    if let Some(project_id) = &project_id {
+                        self.get_project(project_id)?;
+                    }
+                    let mut action = Action::new(
+                        ActionId::new(),
+                        ActionName::new(name)
+                            .map_err(|violation| HeliaError::invalid_field("name", violation))?,
+                        ActionCreateDate::from_value(self.clock.now()),
+                    );
+                    match self.next_position(project_id.as_ref()) {
+                        Ok(position) => action.set_position(position),
+                        Err(err) => {
+                            return Err(HeliaError::ProcessInboxItemFailed { storage_err: err });
+                        }
+                    }
+                    action.set_project_id(project_id);
+                    (
+                        InboxItemTarget::Action(Box::new(action.clone())),
+                        ProcessedInboxItem::Action(action),
+                    )
+                }
+                InboxItemOutcome::Project => {
+                    let project = Project::new(
+                        ProjectId::new(),
+                        ProjectName::new(name),
+                        ProjectCreateDate::from_value(self.clock.now()),
+                        ProjectStatus::Active,
+                    );
+                    (
+                        InboxItemTarget::Project(project.clone()),
+                        ProcessedInboxItem::Project(project),
+                    )
+                }
+                InboxItemOutcome::SomedayMaybe => {
+                    let mut action = Action::new(
+                        ActionId::new(),
+                        ActionName::new(name)
+                            .map_err(|violation| HeliaError::invalid_field("name", violation))?,
+                        ActionCreateDate::from_value(self.clock.now()),
+                    );
+                    action.set_someday(true);
+                    (
+                        InboxItemTarget::Action(Box::new(action.clone())),
+                        ProcessedInboxItem::SomedayMaybe(action),
+                    )
+                }
+                InboxItemOutcome::Reference => {
+                    let reference_item = ReferenceItem::new(
+                        ReferenceItemId::new(),
+                        ReferenceItemText::new(name),
+                        ReferenceItemCreateDate::from_value(self.clock.now()),
+                    );
+                    (
+                        InboxItemTarget::Reference(reference_item.clone()),
+                        ProcessedInboxItem::Reference(reference_item),
+                    )
+                }
+                InboxItemOutcome::Trash => (InboxItemTarget::Trash, ProcessedInboxItem::Trashed),
+            };
+
+            match self
+                .storage
+                .process_inbox_item(&request.inbox_item_id, &target)
+            {
+                Ok(_) => Ok(processed),
+                Err(StorageError::NotFound) => Err(HeliaError::InboxItemNotFound {
+                    inbox_item_id: request.inbox_item_id,
+                }),
+                Err(err) => Err(HeliaError::ProcessInboxItemFailed { storage_err: err }),
+            }
+        })
+    }
+
+    fn list_reference_items(&self) -> Result<Vec<ReferenceItem>, HeliaError> {
+        match self.storage.list_reference_items() {
+            Ok(reference_items) => Ok(reference_items),
+            Err(err) => Err(HeliaError::ListingReferenceItemsFailed { storage_err: err }),
+        }
+    }
+
+    fn create_context(&self, request: CreateContextRequest) -> Result<Context, HeliaError> {
+        self.record("Create context", || {
+            let context = Context::new(ContextId::new(), ContextName::new(&request.name));
+            match self.storage.insert_context(&context) {
+                Ok(_) => Ok(context),
+                Err(err) => Err(HeliaError::CreateContextFailed { storage_err: err }),
+            }
+        })
+    }
+
+    fn list_contexts(&self) -> Result<Vec<Context>, HeliaError> {
+        match self.storage.list_contexts() {
+            Ok(contexts) => Ok(contexts),
+            Err(err) => Err(HeliaError::ListingContextsFailed { storage_err: err }),
+        }
+    }
+
+    fn rename_context(&self, request: RenameContextRequest) -> Result<Context, HeliaError> {
+        self.record("Rename context", || {
+            let mut context = self.get_context(&request.context_id)?;
+            context.set_context_name(ContextName::new(&request.name));
+            match self.storage.update_context(&context) {
+                Ok(_) => Ok(context),
+                Err(StorageError::NotFound) => Err(HeliaError::ContextNotFound {
+                    context_id: request.context_id,
+                }),
+                Err(err) => Err(HeliaError::RenameContextFailed { storage_err: err }),
+            }
+        })
+    }
+
+    fn delete_context(&self, context_id: &ContextId) -> Result<(), HeliaError> {
+        self.record("Delete context", || {
+            self.get_context(context_id)?;
+            let filter = ActionFilter {
+                context_id: Some(context_id.clone()),
+                ..Default::default()
+            };
+            let action_count = match self.storage.list_actions(&filter) {
+                Ok(actions) => actions.len(),
+                Err(err) => return Err(HeliaError::DeleteContextFailed { storage_err: err }),
+            };
+            if action_count > 0 {
+                return Err(HeliaError::ContextInUse {
+                    context_id: context_id.clone(),
+                    action_count,
+                });
+            }
+
+            match self.storage.delete_context(context_id) {
+                Ok(_) => Ok(()),
+                Err(StorageError::NotFound) => Err(HeliaError::ContextNotFound {
+                    context_id: context_id.clone(),
+                }),
+                Err(err) => Err(HeliaError::DeleteContextFailed { storage_err: err }),
+            }
+        })
+    }
+
+    fn assign_context(&self, request: AssignContextRequest) -> Result<(), HeliaError> {
+        self.record("Assign context", || {
+            self.get_action(&request.action_id)?;
+            self.get_context(&request.context_id)?;
+            match self
+                .storage
+                .insert_action_context(&request.action_id, &request.context_id)
+            {
+                Ok(_) => Ok(()),
+                Err(err) => Err(HeliaError::AssignContextFailed { storage_err: err }),
+            }
+        })
+    }
+
+    fn unassign_context(&self, request: AssignContextRequest) -> Result<(), HeliaError> {
+        self.record("Unassign context", || {
+            match self
+                .storage
+                .delete_action_context(&request.action_id, &request.context_id)
+            {
+                Ok(_) => Ok(()),
+                Err(StorageError::NotFound) => Err(HeliaError::ContextNotAssigned {
+                    action_id: request.action_id,
+                    context_id: request.context_id,
+                }),
+                Err(err) => Err(HeliaError::UnassignContextFailed { storage_err: err }),
+            }
+        })
+    }
+
+    fn list_action_contexts(&self, action_id: &ActionId) -> Result<Vec<Context>, HeliaError> {
+        self.get_action(action_id)?;
+        match self.storage.list_action_contexts(action_id) {
+            Ok(contexts) => Ok(contexts),
+            Err(err) => Err(HeliaError::ListingContextsFailed { storage_err: err }),
+        }
+    }
+
+    fn list_actions_in_context(&self, context_id: &ContextId) -> Result<Vec<Action>, HeliaError> {
+        self.get_context(context_id)?;
+        self.list_actions(ListActionsRequest {
+            status: ActionStatusFilter::Open,
+            context_id: Some(context_id.clone()),
+            ..Default::default()
+        })
+    }
+
+    fn create_area(&self, request: CreateAreaRequest) -> Result<Area, HeliaError> {
+        self.record("Create area", || {
+            let area = request.into_area()?;
+            match self.storage.insert_area(&area) {
+                Ok(_) => Ok(area),
+                Err(err) => Err(HeliaError::CreateAreaFailed { storage_err: err }),
+            }
+        })
+    }
+
+    fn get_area(&self, area_id: &AreaId) -> Result<Area, HeliaError> {
+        match self.storage.get_area(area_id) {
+            Ok(area) => Ok(area),
+            Err(StorageError::NotFound) => Err(HeliaError::AreaNotFound {
+                area_id: area_id.clone(),
+            }),
+            Err(err) => Err(HeliaError::FetchingAreaFailed { storage_err: err }),
+        }
+    }
+
+    fn list_areas(&self) -> Result<Vec<Area>, HeliaError> {
+        match self.storage.list_areas() {
+            Ok(areas) => Ok(areas),
+            Err(err) => Err(HeliaError::ListingAreasFailed { storage_err: err }),
+        }
+    }
+
+    fn rename_area(&self, request: RenameAreaRequest) -> Result<Area, HeliaError> {
+        self.record("Rename area", || {
+            let name = AreaName::new(&request.name)
+                .map_err(|violation| HeliaError::invalid_field("name", violation))?;
+            let mut area = self.get_area(&request.area_id)?;
+            area.set_area_name(name);
+            match self.storage.update_area(&area) {
+                Ok(_) => Ok(area),
+                Err(StorageError::NotFound) => Err(HeliaError::AreaNotFound {
+                    area_id: request.area_id,
+                }),
+                Err(err) => Err(HeliaError::RenameAreaFailed { storage_err: err }),
+            }
+        })
+    }
+
+    fn delete_area(&self, area_id: &AreaId) -> Result<(), HeliaError> {
+        self.record("Delete area", || match self.storage.delete_area(area_id) {
+            Ok(_) => Ok(()),
+            Err(StorageError::NotFound) => Err(HeliaError::AreaNotFound {
+                area_id: area_id.clone(),
+            }),
+            Err(err) => Err(HeliaError::DeleteAreaFailed { storage_err: err }),
+        })
+    }
+
+    fn set_project_area(&self, request: SetProjectAreaRequest) -> Result<Project, HeliaError> {
+        self.record("Set project area", || {
+            let mut project = self.get_project(&request.project_id)?;
+            if let Some(area_id) = &request.area_id {
+                self.get_area(area_id)?;
+            }
+            project.set_area_id(request.area_id);
+            match self.storage.update_project(&project) {
+                Ok(_) => Ok(project),
+                Err(StorageError::NotFound) => Err(HeliaError::ProjectNotFound {
+                    project_id: request.project_id,
+                }),
+                Err(err) => Err(HeliaError::UpdateProjectFailed { storage_err: err }),
+            }
+        })
+    }
+
+    fn set_action_area(&self, request: SetActionAreaRequest) -> Result<Action, HeliaError> {
+        self.record("Set action area", || {
+            let mut action = self.get_action(&request.action_id)?;
+            if action.project_id().is_some() {
+                return Err(HeliaError::ActionInProject {
+                    action_id: request.action_id,
+                });
+            }
+            if let Some(area_id) = &request.area_id {
+                self.get_area(area_id)?;
+            }
+            action.set_area_id(request.area_id);
+            match self.storage.update_action(&action) {
+                Ok(_) => Ok(action),
+                Err(StorageError::NotFound) => Err(HeliaError::ActionNotFound {
+                    action_id: request.action_id,
+                }),
+                Err(err) => Err(HeliaError::UpdateActionFailed { storage_err: err }),
+            }
+        })
+    }
+
+    fn area_summaries(&self) -> Result<Vec<AreaSummary>, HeliaError> {
+        match self.storage.list_area_summaries(self.clock.now()) {
+            Ok(areas) => Ok(areas
+                .into_iter()
+                .map(|(area, open_actions, stalled_projects)| AreaSummary {
+                    area,
+                    open_actions,
+                    stalled_projects,
+                })
+                .collect()),
+            Err(err) => Err(HeliaError::ListingAreasFailed { storage_err: err }),
+        }
+    }
+
+    fn create_tag(&self, request: CreateTagRequest) -> Result<Tag, HeliaError> {
+        self.record("Create tag", || {
+            let tag = request.into_tag()?;
+            match self.storage.get_tag_by_path(tag.tag_path()) {
+                Ok(_) => {
+                    return Err(HeliaError::TagPathTaken {
+                        path: tag.tag_path().clone(),
+                    });
+                }
+                Err(StorageError::NotFound) => {}
+                Err(err) => return Err(HeliaError::FetchingTagFailed { storage_err: err }),
+            }
+            match self.storage.insert_tag(&tag) {
+                Ok(_) => Ok(tag),
+                Err(err) => Err(HeliaError::CreateTagFailed { storage_err: err }),
+            }
+        })
+    }
+
+    fn get_tag(&self, tag_id: &TagId) -> Result<Tag, HeliaError> {
+        match self.storage.get_tag(tag_id) {
+            Ok(tag) => Ok(tag),
+            Err(StorageError::NotFound) => Err(HeliaError::TagNotFound {
+                tag_id: tag_id.clone(),
+            }),
+            Err(err) => Err(HeliaError::FetchingTagFailed { storage_err: err }),
+        }
+    }
+
+    fn list_tags(&self) -> Result<Vec<Tag>, HeliaError> {
+        match self.storage.list_tags() {
+            Ok(tags) => Ok(tags),
+            Err(err) => Err(HeliaError::ListingTagsFailed { storage_err: err }),
+        }
+    }
+
+    fn rename_tag(&self, request: RenameTagRequest) -> Result<Tag, HeliaError> {
+        self.record("Rename tag", || {
+            let path = TagPath::new(&request.path)
+                .map_err(|violation| HeliaError::invalid_field("path", violation))?;
+            let tag = self.get_tag(&request.tag_id)?;
+            if tag.tag_path() == &path {
+                return Ok(tag);
+            }
+            match self.storage.get_tag_by_path(&path) {
+                Ok(_) => return Err(HeliaError::TagPathTaken { path }),
+                Err(StorageError::NotFound) => {}
+                Err(err) => return Err(HeliaError::FetchingTagFailed { storage_err: err }),
+            }
+            if tag.tag_path().contains(&path) {
+                return Err(HeliaError::invalid_field(
+                    "path",
+                    Violation::Invalid("lies within the renamed tag"),
+                ));
+            }
+            self.check_moved_tag_paths(tag.tag_path(), &path, "path")?;
+            match self.storage.move_tags(tag.tag_path(), &path) {
+                Ok(_) => self.get_tag(&request.tag_id),
+                Err(err) => Err(HeliaError::RenameTagFailed { storage_err: err }),
+            }
+        })
+    }
+
+    fn merge_tags(&self, request: MergeTagsRequest) -> Result<Tag, HeliaError> {
+        self.record("Merge tags", || {
+            let source = self.get_tag(&request.source_id)?;
+            let target = self.get_tag(&request.target_id)?;
+            if source.tag_path().contains(target.tag_path()) {
+                return Err(HeliaError::invalid_field(
+                    "target_id",
+                    Violation::Invalid("lies within the merged tag"),
+                ));
+            }
+            self.check_moved_tag_paths(source.tag_path(), target.tag_path(), "target_id")?;
+            match self.storage.move_tags(source.tag_path(), target.tag_path()) {
+                Ok(_) => Ok(target),
+                Err(err) => Err(HeliaError::MergeTagsFailed { storage_err: err }),
+            }
+        })
+    }
+
+    fn delete_tag(&self, tag_id: &TagId) -> Result<(), HeliaError> {
+        self.record("Delete tag", || match self.storage.delete_tag(tag_id) {
+            Ok(_) => Ok(()),
+            Err(StorageError::NotFound) => Err(HeliaError::TagNotFound {
+                tag_id: tag_id.clone(),
+            }),
+            Err(err) => Err(HeliaError::DeleteTagFailed { storage_err: err }),
+        })
+    }
+
+    fn tag_action(&self, request: TagActionRequest) -> Result<(), HeliaError> {
+        self.record("Tag action", || {
+            self.get_action(&request.action_id)?;
+            self.get_tag(&request.tag_id)?;
+            match self
+                .storage
+                .insert_action_tag(&request.action_id, &request.tag_id)
+            {
+                Ok(_) => Ok(()),
+                Err(err) => Err(HeliaError::TagItemFailed { storage_err: err }),
+            }
+        })
+    }
+
+    fn untag_action(&self, request: TagActionRequest) -> Result<(), HeliaError> {
+        self.record("Untag action", || {
+            match self
+                .storage
+                .delete_action_tag(&request.action_id, &request.tag_id)
+            {
+                Ok(_) => Ok(()),
+                Err(StorageError::NotFound) => Err(HeliaError::TagNotAssigned {
+                    tag_id: request.tag_id,
+                }),
+                Err(err) => Err(HeliaError::UntagItemFailed { storage_err: err }),
+            }
+        })
+    }
+
+    fn list_action_tags(&self, action_id: &ActionId) -> Result<Vec<Tag>, HeliaError> {
+        self.get_action(action_id)?;
+        match self.storage.list_action_tags(action_id) {
+            Ok(tags) => Ok(tags),
+            Err(err) => Err(HeliaError::ListingTagsFailed { storage_err: err }),
+        }
+    }
+
+    fn tag_project(&self, request: TagProjectRequest) -> Result<(), HeliaError> {
+        self.record("Tag project", || {
+            self.get_project(&request.project_id)?;
+            self.get_tag(&request.tag_id)?;
+            match self
+                .storage
+                .insert_project_tag(&request.project_id, &request.tag_id)
+            {
+                Ok(_) => Ok(()),
+                Err(err) => Err(HeliaError::TagItemFailed { storage_err: err }),
+            }
+        })
+    }
+
+    fn untag_project(&self, request: TagProjectRequest) -> Result<(), HeliaError> {
+        self.record("Untag project", || {
+            match self
+                .storage
+                .delete_project_tag(&request.project_id, &request.tag_id)
+            {
+                Ok(_) => Ok(()),
+                Err(StorageError::NotFound) => Err(HeliaError::TagNotAssigned {
+                    tag_id: request.tag_id,
+                }),
+                Err(err) => Err(HeliaError::UntagItemFailed { storage_err: err }),
+            }
+        })
+    }
+
+    fn list_project_tags(&self, project_id: &ProjectId) -> Result<Vec<Tag>, HeliaError> {
+        self.get_project(project_id)?;
+        match self.storage.list_project_tags(project_id) {
+            Ok(tags) => Ok(tags),
+            Err(err) => Err(HeliaError::ListingTagsFailed { storage_err: err }),
+        }
+    }
+
+    fn list_actions_with_tag(&self, tag_id: &TagId) -> Result<Vec<Action>, HeliaError> {
+        self.list_actions(ListActionsRequest {
+            status: ActionStatusFilter::Open,
+            tag_id: Some(tag_id.clone()),
+            ..Default::default()
+        })
+    }
+
+    fn list_projects_with_tag(&self, tag_id: &TagId) -> Result<Vec<Project>, HeliaError> {
+        let tag = self.get_tag(tag_id)?;
+        self.list_projects_matching(&ProjectFilter {
+            tag: Some(tag.tag_path().clone()),
+            ..Default::default()
+        })
+    }
+
+    fn create_perspective(
+        &self,
+        request: CreatePerspectiveRequest,
+    ) -> Result<Perspective, HeliaError> {
+        self.record("Create perspective", || {
+            let perspective = request.into_perspective()?;
+            self.parse_action_query(perspective.perspective_query().as_str())?;
+            match self.storage.insert_perspective(&perspective) {
+                Ok(_) => Ok(perspective),
+                Err(err) => Err(HeliaError::CreatePerspectiveFailed { storage_err: err }),
+            }
+        })
+    }
+
+    fn get_perspective(&self, perspective_id: &PerspectiveId) -> Result<Perspective, HeliaError> {
+        match self.storage.get_perspective(perspective_id) {
+            Ok(perspective) => Ok(perspective),
+            Err(StorageError::NotFound) => Err(HeliaError::PerspectiveNotFound {
+                perspective_id: perspective_id.clone(),
+            }),
+            Err(err) => Err(HeliaError::FetchingPerspectiveFailed { storage_err: err }),
         }
     }
+
+    fn list_perspectives(&self) -> Result<Vec<Perspective>, HeliaError> {
+        match self.storage.list_perspectives() {
+            Ok(perspectives) => Ok(perspectives),
+            Err(err) => Err(HeliaError::ListingPerspectivesFailed { storage_err: err }),
+        }
+    }
+
+    fn update_perspective(
+        &self,
+        request: UpdatePerspectiveRequest,
+    ) -> Result<Perspective, HeliaError> {
+        self.record("Update perspective", || {
+            let name = request
+                .name
+                .map(|name| PerspectiveName::new(&name))
+                .transpose()
+                .map_err(|violation| HeliaError::invalid_field("name", violation))?;
+            let query = request
+                .query
+                .map(|query| PerspectiveQuery::new(&query))
+                .transpose()
+                .map_err(|violation| HeliaError::invalid_field("query", violation))?;
+            let mut perspective = self.get_perspective(&request.perspective_id)?;
+            if let Some(name) = name {
+                perspective.set_perspective_name(name);
+            }
+            if let Some(query) = query {
+                self.parse_action_query(query.as_str())?;
+                perspective.set_perspective_query(query);
+            }
+            match self.storage.update_perspective(&perspective) {
+                Ok(_) => Ok(perspective),
+                Err(StorageError::NotFound) => Err(HeliaError::PerspectiveNotFound {
+                    perspective_id: request.perspective_id,
+                }),
+                Err(err) => Err(HeliaError::UpdatePerspectiveFailed { storage_err: err }),
+            }
+        })
+    }
+
+    fn delete_perspective(&self, perspective_id: &PerspectiveId) -> Result<(), HeliaError> {
+        self.record("Delete perspective", || {
+            match self.storage.delete_perspective(perspective_id) {
+                Ok(_) => Ok(()),
+                Err(StorageError::NotFound) => Err(HeliaError::PerspectiveNotFound {
+                    perspective_id: perspective_id.clone(),
+                }),
+                Err(err) => Err(HeliaError::DeletePerspectiveFailed { storage_err: err }),
+            }
+        })
+    }
+
+    fn evaluate_perspective(
+        &self,
+        perspective_id: &PerspectiveId,
+    ) -> Result<Vec<Action>, HeliaError> {
+        let perspective = self.get_perspective(perspective_id)?;
+        let query = self.parse_action_query(perspective.perspective_query().as_str())?;
+        self.query_actions(query)
+    }
+
+    fn start_review(&self) -> Result<Review, HeliaError> {
+        self.record("Start review", || {
+            if let Some(review) = self.current_review()? {
+                return Ok(review);
+            }
+            let review = Review::new(ReviewId::new(), self.clock.now());
+            match self.storage.insert_review(&review) {
+                Ok(_) => Ok(review),
+                Err(err) => Err(HeliaError::StartReviewFailed { storage_err: err }),
+            }
+        })
+    }
+
+    fn current_review(&self) -> Result<Option<Review>, HeliaError> {
+        match self.storage.get_review_in_progress() {
+            Ok(review) => Ok(Some(review)),
+            Err(StorageError::NotFound) => Ok(None),
+            Err(err) => Err(HeliaError::FetchingReviewFailed { storage_err: err }),
+        }
+    }
+
+    fn complete_review_step(&self, step: ReviewStep) -> Result<Review, HeliaError> {
+        self.record("Complete review step", || {
+            let mut review = self
+                .current_review()?
+                .ok_or(HeliaError::NoReviewInProgress)?;
+            // A review in progress always has a next step.
+            if let Some(expected) = review.next_step()
+                && expected != step
+            {
+                return Err(HeliaError::ReviewStepOutOfOrder { step, expected });
+            }
+            review.complete_step(step, self.clock.now());
+            match self.storage.update_review(&review) {
+                Ok(_) => Ok(review),
+                Err(StorageError::NotFound) => Err(HeliaError::NoReviewInProgress),
+                Err(err) => Err(HeliaError::CompleteReviewStepFailed { storage_err: err }),
+            }
+        })
+    }
+
+    fn last_reviewed_at(&self) -> Result<Option<DateTime<Utc>>, HeliaError> {
+        match self.storage.get_last_completed_review() {
+            Ok(review) => Ok(review.completed_at().copied()),
+            Err(StorageError::NotFound) => Ok(None),
+            Err(err) => Err(HeliaError::FetchingReviewFailed { storage_err: err }),
+        }
+    }
+
+    fn mark_project_reviewed(&self, project_id: &ProjectId) -> Result<Project, HeliaError> {
+        self.record("Mark project reviewed", || {
+            let mut project = self.get_project(project_id)?;
+            project.set_project_review_date(Some(ProjectReviewDate::from_value(self.clock.now())));
+            match self.storage.update_project(&project) {
+                Ok(_) => Ok(project),
+                Err(StorageError::NotFound) => Err(HeliaError::ProjectNotFound {
+                    project_id: project_id.clone(),
+                }),
+                Err(err) => Err(HeliaError::UpdateProjectFailed { storage_err: err }),
+            }
+        })
+    }
+
+    fn search(&self, text: &str) -> Result<Vec<SearchResult>, HeliaError> {
+        match self.storage.search(text) {
+            Ok(rows) => Ok(rows
+                .into_iter()
+                .map(|(item, title, snippet)| SearchResult {
+                    item,
+                    title,
+                    snippet,
+                })
+                .collect()),
+            Err(err) => Err(HeliaError::SearchFailed { storage_err: err }),
+        }
+    }
+
+    fn undo(&self) -> Result<Option<String>, HeliaError> {
+        match self.storage.undo_history_step() {
+            Ok(operation) => Ok(operation),
+            Err(err) => Err(HeliaError::UndoFailed { storage_err: err }),
+        }
+    }
+
+    fn redo(&self) -> Result<Option<String>, HeliaError> {
+        match self.storage.redo_history_step() {
+            Ok(operation) => Ok(operation),
+            Err(err) => Err(HeliaError::RedoFailed { storage_err: err }),
+        }
+    }
+
+    fn export_json(&self, writer: &mut dyn Write) -> Result<(), HeliaError> {
+        let data = match self.storage.export_data() {
+            Ok(data) => data,
+            Err(err) => return Err(HeliaError::ExportFailed { storage_err: err }),
+        };
+        let document = ExportDocument::from_data_set(&data, self.clock.now());
+        serde_json::to_writer_pretty(writer, &document)
+            .map_err(|json_err| HeliaError::WritingExportFailed { json_err })
+    }
+
+    fn import_json(&self, reader: &mut dyn Read) -> Result<ImportSummary, HeliaError> {
+        self.record("Import", || {
+            let data = ExportDocument::read(reader)?.into_data_set()?;
+            match self.storage.import_data(&data) {
+                Ok(_) => Ok(ImportSummary {
+                    areas: data.areas.len(),
+                    actions: data.actions.len(),
+                    projects: data.projects.len(),
+                    contexts: data.contexts.len(),
+                    tags: data.tags.len(),
+                    perspectives: data.perspectives.len(),
+                    reviews: data.reviews.len(),
+                    inbox_items: data.inbox_items.len(),
+                    reference_items: data.reference_items.len(),
+                }),
+                Err(err) => Err(HeliaError::ImportFailed { storage_err: err }),
+            }
+        })
+    }
 }
@@ -4,19 +4,518 @@
 //! For example, when inserting data, rather than providing the domain model directly,
 //! you need to create a `Request`, which you can then give to the API.
 
-use crate::model::action::{acion_create_date::ActionCreateDate, action_id::ActionId, action_name::ActionName, Action};
+use chrono::{DateTime, Utc};
+
+pub use crate::core::requests::action_query_parser::ParseActionQueryError;
+
+use crate::core::helia_error::HeliaError;
+use crate::model::{
+    action::{
+        Action, acion_create_date::ActionCreateDate, action_defer_date::ActionDeferDate,
+        action_due_date::ActionDueDate, action_id::ActionId, action_name::ActionName,
+        action_notes::ActionNotes, action_recurrence::ActionRecurrence,
+    },
+    area::{Area, area_id::AreaId, area_name::AreaName},
+    context::context_id::ContextId,
+    inbox_item::inbox_item_id::InboxItemId,
+    perspective::{
+        Perspective, perspective_id::PerspectiveId, perspective_name::PerspectiveName,
+        perspective_query::PerspectiveQuery,
+    },
+    project::{
+        Project, project_create_date::ProjectCreateDate, project_id::ProjectId,
+        project_name::ProjectName, project_ordering::ProjectOrdering,
+        project_status::ProjectStatus,
+    },
+    tag::{Tag, tag_id::TagId, tag_path::TagPath},
+};
+
+// The parser of the textual form of an `ActionQuery`.
+mod action_query_parser;
 
 /// A request to create an [`Action`].
+#[derive(Debug, Clone, Default)]
 pub struct CreateActionRequest {
     pub name: String,
+    /// The project the action belongs to, if any.
+    pub project_id: Option<ProjectId>,
+    /// The date by which the action has to be done, if any.
+    pub due_date: Option<DateTime<Utc>>,
+    /// The date until which the action is deferred, if any.
+    pub defer_date: Option<DateTime<Utc>>,
+    /// Free-text notes, usually Markdown, kept verbatim.
+    pub notes: Option<String>,
+    /// The rule by which the action repeats, if any.
+    pub recurrence: Option<ActionRecurrence>,
 }
 
 impl CreateActionRequest {
-    pub fn into_action(self) -> Action {
-        Action::new(
-            ActionId::new(),
-            ActionName::new(&self.name),
-            ActionCreateDate::now(),
-        )
+    /// Turns this request into a new [`Action`] created at `create_date`.
+    ///
+    /// Fails with [`HeliaError::ValidationFailed`] if the name or the recurrence is invalid.
+    pub fn into_action(self, create_date: ActionCreateDate) -> Result<Action, HeliaError> {
+        let name = ActionName::new(&self.name)
+            .map_err(|violation| HeliaError::invalid_field("name", violation))?;
+        if let Some(recurrence) = &self.recurrence {
+            recurrence
+                .validate()
+                .map_err(|err| HeliaError::invalid_field("recurrence", err.into()))?;
+        }
+        let mut action = Action::new(ActionId::new(), name, create_date);
+        action.set_project_id(self.project_id);
+        action.set_action_due_date(self.due_date.map(ActionDueDate::from_value));
+        action.set_action_defer_date(self.defer_date.map(ActionDeferDate::from_value));
+        action.set_action_notes(self.notes.as_deref().map(ActionNotes::new));
+        action.set_action_recurrence(self.recurrence);
+        Ok(action)
+    }
+}
+
+/// A request to update the details of an existing [`Action`].
+///
+/// Fields that are `None` are left unchanged.
+/// Fields holding `Some(None)` are cleared.
+#[derive(Debug, Clone, Default)]
+pub struct UpdateActionRequest {
+    pub action_id: ActionId,
+    pub due_date: Option<Option<DateTime<Utc>>>,
+    pub defer_date: Option<Option<DateTime<Utc>>>,
+    pub notes: Option<Option<String>>,
+    pub recurrence: Option<Option<ActionRecurrence>>,
+}
+
+/// A request to delegate an existing [`Action`] and put it on the waiting-for list.
+///
+/// Delegating an already delegated action replaces its delegation.
+#[derive(Debug, Clone, Default)]
+pub struct DelegateActionRequest {
+    pub action_id: ActionId,
+    /// Who the action is delegated to.
+    pub delegate: String,
+    /// When to follow up with the delegate, if planned.
+    pub follow_up_date: Option<DateTime<Utc>>,
+}
+
+/// A request to rename an existing [`Action`].
+pub struct RenameActionRequest {
+    pub action_id: ActionId,
+    pub name: String,
+}
+
+/// Selects actions by their completion status.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum ActionStatusFilter {
+    /// Both open and completed actions.
+    #[default]
+    All,
+    /// Only actions that have not been completed yet.
+    Open,
+    /// Only completed actions.
+    Completed,
+}
+
+/// A request to list [`Action`]s.
+#[derive(Debug, Clone, Default)]
+pub struct ListActionsRequest {
+    pub status: ActionStatusFilter,
+    /// Only list actions belonging to this project.
+    pub project_id: Option<ProjectId>,
+    /// Only list actions linked to this context.
+    pub context_id: Option<ContextId>,
+    /// Only list actions linked to this tag or to one of its descendants.
+    pub tag_id: Option<TagId>,
+    /// Also list actions on the someday/maybe list, which are left out by default.
+    pub include_someday: bool,
+}
+
+/// A request to move an [`Action`] into a [`Project`], or out of its project.
+pub struct MoveActionRequest {
+    pub action_id: ActionId,
+    /// The target project, or `None` to make the action a standalone action.
+    pub project_id: Option<ProjectId>,
+}
+
+/// A request to create a [`Project`].
+#[derive(Debug, Clone, Default)]
+pub struct CreateProjectRequest {
+    pub name: String,
+    pub status: ProjectStatus,
+    /// Whether the project's actions are done one after the other or in any order.
+    pub ordering: ProjectOrdering,
+}
+
+impl CreateProjectRequest {
+    /// Turns this request into a new [`Project`] created at `create_date`.
+    pub fn into_project(self, create_date: ProjectCreateDate) -> Project {
+        let mut project = Project::new(
+            ProjectId::new(),
+            ProjectName::new(&self.name),
+            create_date,
+            self.status,
+        );
+        project.set_project_ordering(self.ordering);
+        project
+    }
+}
+
+/// A request to update an existing [`Project`].
+///
+/// Fields that are `None` are left unchanged.
+#[derive(Debug, Clone, Default)]
+pub struct UpdateProjectRequest {
+    pub project_id: ProjectId,
+    pub name: Option<String>,
+    pub status: Option<ProjectStatus>,
+    pub ordering: Option<ProjectOrdering>,
+}
+
+/// A request to change the order of the [`Action`]s within a [`Project`].
+///
+/// The listed actions are moved to the front, in the given order.
+/// Actions of the project that are not listed keep their relative order behind them.
+pub struct ReorderActionsRequest {
+    pub project_id: ProjectId,
+    pub action_ids: Vec<ActionId>,
+}
+
+/// What an inbox item should be turned into when it is processed.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum InboxItemOutcome {
+    /// The item is actionable in a single step: it becomes an [`Action`].
+    Action {
+        /// The project the new action belongs to, if any.
+        project_id: Option<ProjectId>,
+    },
+    /// The item needs more than one step: it becomes a [`Project`].
+    Project,
+    /// The item is not committed to yet: it becomes an action on the someday/maybe list.
+    SomedayMaybe,
+    /// The item is not actionable, but worth keeping: it becomes reference material.
+    Reference,
+    /// The item is not needed: it is thrown away.
+    Trash,
+}
+
+/// A request to process an inbox item.
+pub struct ProcessInboxItemRequest {
+    pub inbox_item_id: InboxItemId,
+    /// The name of whatever the item is turned into.
+    ///
+    /// If `None`, the captured text of the item is used.
+    pub name: Option<String>,
+    pub outcome: InboxItemOutcome,
+}
+
+/// A request to create a context.
+#[derive(Debug, Clone, Default)]
+pub struct CreateContextRequest {
+    pub name: String,
+}
+
+/// A request to rename an existing context.
+pub struct RenameContextRequest {
+    pub context_id: ContextId,
+    pub name: String,
+}
+
+/// A request to create an [`Area`].
+#[derive(Debug, Clone, Default)]
+pub struct CreateAreaRequest {
+    pub name: String,
+}
+
+impl CreateAreaRequest {
+    /// Turns this request into a new [`Area`].
+    ///
+    /// Fails with [`HeliaError::ValidationFailed`] if the name is invalid.
+    pub fn into_area(self) -> Result<Area, HeliaError> {
+        let name = AreaName::new(&self.name)
+            .map_err(|violation| HeliaError::invalid_field("name", violation))?;
+        Ok(Area::new(AreaId::new(), name))
+    }
+}
+
+/// A request to rename an existing [`Area`].
+pub struct RenameAreaRequest {
+    pub area_id: AreaId,
+    pub name: String,
+}
+
+/// A request to put a [`Project`] into an [`Area`], or to take it out of its area.
+pub struct SetProjectAreaRequest {
+    pub project_id: ProjectId,
+    /// The target area, or `None` to take the project out of its area.
+    pub area_id: Option<AreaId>,
+}
+
+/// A request to put a standalone [`Action`] into an [`Area`], or to take it out of its area.
+pub struct SetActionAreaRequest {
+    pub action_id: ActionId,
+    /// The target area, or `None` to take the action out of its area.
+    pub area_id: Option<AreaId>,
+}
+
+/// A request to create a [`Tag`].
+#[derive(Debug, Clone, Default)]
+pub struct CreateTagRequest {
+    /// The path of the tag, like `work/client-x`.
+    pub path: String,
+}
+
+impl CreateTagRequest {
+    /// Turns this request into a new [`Tag`].
+    ///
+    /// Fails with [`HeliaError::ValidationFailed`] if the path is invalid.
+    pub fn into_tag(self) -> Result<Tag, HeliaError> {
+        let path = TagPath::new(&self.path)
+            .map_err(|violation| HeliaError::invalid_field("path", violation))?;
+        Ok(Tag::new(TagId::new(), path))
+    }
+}
+
+/// A request to give an existing [`Tag`] a new path, moving its descendants along.
+pub struct RenameTagRequest {
+    pub tag_id: TagId,
+    pub path: String,
+}
+
+/// A request to merge a [`Tag`] and its descendants into another tag.
+pub struct MergeTagsRequest {
+    /// The tag that is merged and removed.
+    pub source_id: TagId,
+    /// The tag that is kept.
+    pub target_id: TagId,
+}
+
+/// A request to link an [`Action`] to a [`Tag`], or to unlink it.
+pub struct TagActionRequest {
+    pub action_id: ActionId,
+    pub tag_id: TagId,
+}
+
+/// A request to link a [`Project`] to a [`Tag`], or to unlink it.
+pub struct TagProjectRequest {
+    pub project_id: ProjectId,
+    pub tag_id: TagId,
+}
+
+/// A request to create a [`Perspective`].
+#[derive(Debug, Clone, Default)]
+pub struct CreatePerspectiveRequest {
+    pub name: String,
+    /// The textual form of the perspective's [`ActionQuery`], see [`ActionQuery::parse`].
+    pub query: String,
+}
+
+impl CreatePerspectiveRequest {
+    /// Turns this request into a new [`Perspective`].
+    ///
+    /// Fails with [`HeliaError::ValidationFailed`] if the name or the query is invalid. The
+    /// syntax of the query is not checked here.
+    pub fn into_perspective(self) -> Result<Perspective, HeliaError> {
+        let name = PerspectiveName::new(&self.name)
+            .map_err(|violation| HeliaError::invalid_field("name", violation))?;
+        let query = PerspectiveQuery::new(&self.query)
+            .map_err(|violation| HeliaError::invalid_field("query", violation))?;
+        Ok(Perspective::new(PerspectiveId::new(), name, query))
+    }
+}
+
+/// A request to change the name or the query of an existing [`Perspective`].
+///
+/// Fields that are `None` are kept.
+pub struct UpdatePerspectiveRequest {
+    pub perspective_id: PerspectiveId,
+    pub name: Option<String>,
+    /// The textual form of the new [`ActionQuery`], see [`ActionQuery::parse`].
+    pub query: Option<String>,
+}
+
+/// A request to link an [`Action`] to a context, or to unlink it.
+pub struct AssignContextRequest {
+    pub action_id: ActionId,
+    pub context_id: ContextId,
+}
+
+/// A query selecting [`Action`]s, built from [`ActionPredicate`]s, sort keys and a page.
+///
+/// ```
+/// use helia_backend::{ActionPredicate, ActionQuery, ActionSortKey, SortDirection, TagPath};
+///
+/// let query = ActionQuery::new()
+///     .filter(ActionPredicate::Open.and(ActionPredicate::Tag(TagPath::new("work").unwrap())))
+///     .filter(!ActionPredicate::Delegated)
+///     .sort_by(ActionSortKey::DueAt, SortDirection::Ascending)
+///     .limit(20);
+/// ```
+///
+/// Queries can also be written as text, see [`ActionQuery::parse`].
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct ActionQuery {
+    pub(crate) predicate: Option<ActionPredicate>,
+    pub(crate) sort: Vec<(ActionSortKey, SortDirection)>,
+    pub(crate) limit: Option<u32>,
+    pub(crate) offset: u32,
+}
+
+impl ActionQuery {
+    /// Returns a query matching all actions, oldest first.
+    pub fn new() -> Self {
+        Self::default()
     }
+
+    /// Parses the textual form of a query, as typed into a search bar.
+    ///
+    /// Terms separated by spaces must all match, `OR` and parentheses group alternatives, and
+    /// `NOT` or a leading `-` negates a term:
+    ///
+    /// | Term | Matches actions |
+    /// |---|---|
+    /// | `report`, `"status report"` | whose name contains the text |
+    /// | `is:open`, `is:completed` | by their completion |
+    /// | `is:someday`, `is:delegated` | on the someday/maybe list or delegated |
+    /// | `is:available` | not deferred past `now` |
+    /// | `tag:work/client-x` | linked to the tag or one of its descendants |
+    /// | `context:@home`, `project:"Launch website"`, `area:Health` | by name |
+    /// | `due:today`, `due<2024-05-01`, `due<+7d` | by due date, `<`, `<=`, `>`, `>=` |
+    /// | `sort:due`, `sort:-created` | sorts by `created`, `completed`, `defer`, `due`, `name` or `position`, descending with `-` |
+    ///
+    /// Dates are `YYYY-MM-DD`, `today`, `tomorrow` or a number of days from today like `+7d`,
+    /// days are in UTC. Parentheses and `NOT` nest at most 64 deep.
+    pub fn parse(text: &str, now: DateTime<Utc>) -> Result<Self, ParseActionQueryError> {
+        action_query_parser::parse(text, now)
+    }
+
+    /// Adds a predicate that matching actions have to satisfy as well.
+    pub fn filter(mut self, predicate: ActionPredicate) -> Self {
+        self.predicate = Some(match self.predicate {
+            Some(existing) => existing.and(predicate),
+            None => predicate,
+        });
+        self
+    }
+
+    /// Adds a sort key, which only decides between actions equal in all earlier keys.
+    ///
+    /// Actions equal in all keys are returned oldest first. Actions without a value for a key
+    /// come last.
+    pub fn sort_by(mut self, key: ActionSortKey, direction: SortDirection) -> Self {
+        self.sort.push((key, direction));
+        self
+    }
+
+    /// Returns at most `limit` actions.
+    pub fn limit(mut self, limit: u32) -> Self {
+        self.limit = Some(limit);
+        self
+    }
+
+    /// Skips the first `offset` matching actions.
+    pub fn offset(mut self, offset: u32) -> Self {
+        self.offset = offset;
+        self
+    }
+}
+
+/// A condition on [`Action`]s, used in an [`ActionQuery`].
+///
+/// Combine predicates with [`ActionPredicate::and`], [`ActionPredicate::or`] and `!`.
+#[derive(Debug, Clone, PartialEq)]
+pub enum ActionPredicate {
+    /// Actions that are not completed.
+    Open,
+    /// Actions that are completed.
+    Completed,
+    /// Actions on the someday/maybe list, directly or through their project.
+    Someday,
+    /// Actions delegated to someone else.
+    Delegated,
+    /// Actions not deferred past the given moment.
+    AvailableAt(DateTime<Utc>),
+    /// Actions due before the given moment.
+    DueBefore(DateTime<Utc>),
+    /// Actions due at or after the given moment.
+    DueFrom(DateTime<Utc>),
+    /// Actions whose name contains the text, ignoring the case of ASCII letters.
+    NameContains(String),
+    /// Actions belonging to the project.
+    Project(ProjectId),
+    /// Actions belonging to a project with the given name.
+    ProjectNamed(String),
+    /// Actions linked to the context.
+    Context(ContextId),
+    /// Actions linked to a context with the given name.
+    ContextNamed(String),
+    /// Actions in the area, directly or through their project.
+    Area(AreaId),
+    /// Actions in an area with the given name, directly or through their project.
+    AreaNamed(String),
+    /// Actions linked to the tag with the given path or to one of its descendants.
+    Tag(TagPath),
+    /// Actions matching all of the predicates.
+    And(Vec<ActionPredicate>),
+    /// Actions matching any of the predicates.
+    Or(Vec<ActionPredicate>),
+    /// Actions not matching the predicate.
+    Not(Box<ActionPredicate>),
+}
+
+impl ActionPredicate {
+    /// Returns a predicate matching actions that match both `self` and `other`.
+    pub fn and(self, other: ActionPredicate) -> Self {
+        let mut predicates = match self {
+            ActionPredicate::And(predicates) => predicates,
+            predicate => vec![predicate],
+        };
+        match other {
+            ActionPredicate::And(others) => predicates.extend(others),
+            other => predicates.push(other),
+        }
+        ActionPredicate::And(predicates)
+    }
+
+    /// Returns a predicate matching actions that match `self`, `other` or both.
+    pub fn or(self, other: ActionPredicate) -> Self {
+        let mut predicates = match self {
+            ActionPredicate::Or(predicates) => predicates,
+            predicate => vec![predicate],
+        };
+        match other {
+            ActionPredicate::Or(others) => predicates.extend(others),
+            other => predicates.push(other),
+        }
+        ActionPredicate::Or(predicates)
+    }
+}
+
+impl std::ops::Not for ActionPredicate {
+    type Output = ActionPredicate;
+
+    /// Returns a predicate matching actions that don't match `self`.
+    fn not(self) -> Self::Output {
+        match self {
+            ActionPredicate::Not(predicate) => *predicate,
+            predicate => ActionPredicate::Not(Box::new(predicate)),
+        }
+    }
+}
+
+/// What an [`ActionQuery`] sorts actions by.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ActionSortKey {
+    CreatedAt,
+    CompletedAt,
+    DeferUntil,
+    DueAt,
+    Name,
+    /// The position within the action's project.
+    Position,
+}
+
+/// The direction an [`ActionQuery`] sorts in.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum SortDirection {
+    #[default]
+    Ascending,
+    Descending,
 }
@@ -0,0 +1,535 @@
+//! Parser of the textual form of an [ActionQuery], see [ActionQuery::parse].
+//!
+//! The grammar, loosest binding first:
+//!
+//! ```text
+//! query = or
+//! or    = and { "OR" and }
+//! and   = unary { [ "AND" ] unary }
+//! unary = ( "NOT" | "-" ) unary | "(" or ")" | term
+//! ```
+//!
+//! `sort:` terms are taken out before parsing, so they may appear anywhere outside of
+//! parentheses.
+
+use chrono::{DateTime, Days, Duration, NaiveDate, Utc};
+use thiserror::Error;
+
+use crate::{
+    core::requests::{ActionPredicate, ActionQuery, ActionSortKey, SortDirection},
+    model::tag::tag_path::TagPath,
+};
+
+/// Error returned when the text of an [ActionQuery] can't be parsed.
+#[derive(Error, Debug, Clone, PartialEq, Eq)]
+#[error("{message} at position {position}")]
+pub struct ParseActionQueryError {
+    message: String,
+    position: usize,
+}
+
+impl ParseActionQueryError {
+    fn new(message: impl Into<String>, position: usize) -> Self {
+        Self {
+            message: message.into(),
+            position,
+        }
+    }
+
+    /// Returns what is wrong with the query.
+    pub fn message(&self) -> &str {
+        &self.message
+    }
+
+    /// Returns the offset of the offending character in the query, counted in characters.
+    pub fn position(&self) -> usize {
+        self.position
+    }
+}
+
+/// A word of the query, with quotes removed.
+#[derive(Debug, Clone, PartialEq)]
+struct Word {
+    text: String,
+    /// The byte offset in `text` where the first quoted part starts, if any.
+    quoted_from: Option<usize>,
+}
+
+impl Word {
+    /// Returns the part of the word before the first quote.
+    fn unquoted(&self) -> &str {
+        &self.text[..self.quoted_from.unwrap_or(self.text.len())]
+    }
+
+    /// Returns `true` if the word is the unquoted `keyword`.
+    fn is_keyword(&self, keyword: &str) -> bool {
+        self.quoted_from.is_none() && self.text == keyword
+    }
+
+    /// Splits a `key:value` or `key<value` term into its key, operator and value.
+    ///
+    /// Returns `None` for free text.
+    fn split(&self) -> Option<(&str, &str, &str)> {
+        let (index, delimiter) = self
+            .unquoted()
+            .char_indices()
+            .find(|(_, c)| matches!(c, ':' | '<' | '>'))
+            .filter(|(index, _)| *index > 0)?;
+        let op_len = match self.text[index + 1..].starts_with('=') {
+            true if delimiter != ':' => 2,
+            _ => 1,
+        };
+        Some((
+            &self.text[..index],
+            &self.text[index..index + op_len],
+            &self.text[index + op_len..],
+        ))
+    }
+
+    /// Returns the word without its leading `-`, if it is a negated term.
+    fn negated(&self) -> Option<Word> {
+        if self.unquoted().len() > 1 || self.quoted_from.is_some_and(|from| from > 0) {
+            self.text.strip_prefix('-').map(|text| Word {
+                text: String::from(text),
+                quoted_from: self.quoted_from.map(|from| from - 1),
+            })
+        } else {
+            None
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+enum Token {
+    Open,
+    Close,
+    Word(Word),
+}
+
+/// Splits the query text into tokens, each with the character offset it starts at.
+fn tokenize(text: &str) -> Result<Vec<(Token, usize)>, ParseActionQueryError> {
+    let mut tokens = Vec::new();
+    let mut chars = text.chars().enumerate().peekable();
+    while let Some(&(position, c)) = chars.peek() {
+        match c {
+            '(' | ')' => {
+                chars.next();
+                let token = if c == '(' { Token::Open } else { Token::Close };
+                tokens.push((token, position));
+            }
+            c if c.is_whitespace() => {
+                chars.next();
+            }
+            _ => {
+                let mut text = String::new();
+                let mut quoted_from = None;
+                while let Some(&(_, c)) = chars.peek() {
+                    if c.is_whitespace() || c == '(' || c == ')' {
+                        break;
+                    }
+                    chars.next();
+                    if c != '"' {
+                        text.push(c);
+                        continue;
+                    }
+                    quoted_from.get_or_insert(text.len());
+                    loop {
+                        match chars.next() {
+                            Some((_, '"')) => break,
+                            Some((_, c)) => text.push(c),
+                            None => {
+                                return Err(ParseActionQueryError::new(
+                                    "unterminated quote",
+                                    position,
+                                ));
+                            }
+                        }
+                    }
+                }
+                tokens.push((Token::Word(Word { text, quoted_from }), position));
+            }
+        }
+    }
+    Ok(tokens)
+}
+
+/// Parses the textual form of an [ActionQuery], see [ActionQuery::parse].
+pub(super) fn parse(text: &str, now: DateTime<Utc>) -> Result<ActionQuery, ParseActionQueryError> {
+    let mut query = ActionQuery::new();
+    let mut tokens = Vec::new();
+    let mut depth = 0usize;
+    for (token, position) in tokenize(text)? {
+        match &token {
+            Token::Open => depth += 1,
+            Token::Close => depth = depth.saturating_sub(1),
+            Token::Word(word) if depth == 0 => {
+                if let Some(("sort", ":", value)) = word.split() {
+                    // Dropping the term would bind the operator to the next term instead.
+                    if let Some((Token::Word(previous), _)) = tokens.last() {
+                        let operators = ["NOT", "-", "AND", "OR"];
+                        if operators
+                            .iter()
+                            .any(|operator| previous.is_keyword(operator))
+                        {
+                            return Err(ParseActionQueryError::new(
+                                format!("sort keys can't follow '{}'", previous.text),
+                                position,
+                            ));
+                        }
+                    }
+                    let (key, direction) = parse_sort(value, position)?;
+                    query = query.sort_by(key, direction);
+                    continue;
+                }
+            }
+            Token::Word(_) => {}
+        }
+        tokens.push((token, position));
+    }
+
+    let mut parser = Parser {
+        tokens,
+        next: 0,
+        end: text.chars().count(),
+        now,
+        depth: 0,
+    };
+    if parser.peek().is_some() {
+        query = query.filter(parser.parse_or()?);
+        if let Some((_, position)) = parser.peek() {
+            return Err(ParseActionQueryError::new("unmatched ')'", *position));
+        }
+    }
+    Ok(query)
+}
+
+/// Parses the value of a `sort:` term, like `due` or `-created`.
+fn parse_sort(
+    value: &str,
+    position: usize,
+) -> Result<(ActionSortKey, SortDirection), ParseActionQueryError> {
+    let (key, direction) = match value.strip_prefix('-') {
+        Some(key) => (key, SortDirection::Descending),
+        None => (value, SortDirection::Ascending),
+    };
+    let key = match key {
+        "created" => ActionSortKey::CreatedAt,
+        "completed" => ActionSortKey::CompletedAt,
+        "defer" => ActionSortKey::DeferUntil,
+        "due" => ActionSortKey::DueAt,
+        "name" => ActionSortKey::Name,
+        "position" => ActionSortKey::Position,
+        _ => {
+            return Err(ParseActionQueryError::new(
+                format!("unknown sort key '{key}'"),
+                position,
+            ));
+        }
+    };
+    Ok((key, direction))
+}
+
+/// A recursive descent parser over the tokens of a query.
+struct Parser {
+    tokens: Vec<(Token, usize)>,
+    next: usize,
+    /// The length of the query text, where errors at its end are reported.
+    end: usize,
+    now: DateTime<Utc>,
+    /// The number of groups and negations the parser is currently inside of.
+    depth: usize,
+}
+
+impl Parser {
+    /// How deeply groups and negations may nest, which keeps the recursion from overflowing the
+    /// stack.
+    const MAX_DEPTH: usize = 64;
+
+    fn peek(&self) -> Option<&(Token, usize)> {
+        self.tokens.get(self.next)
+    }
+
+    /// Returns `true` if the next token is the unquoted `keyword`.
+    fn peek_keyword(&self, keyword: &str) -> bool {
+        matches!(self.peek(), Some((Token::Word(word), _)) if word.is_keyword(keyword))
+    }
+
+    fn parse_or(&mut self) -> Result<ActionPredicate, ParseActionQueryError> {
+        let mut predicate = self.parse_and()?;
+        while self.peek_keyword("OR") {
+            self.next += 1;
+            predicate = predicate.or(self.parse_and()?);
+        }
+        Ok(predicate)
+    }
+
+    fn parse_and(&mut self) -> Result<ActionPredicate, ParseActionQueryError> {
+        let mut predicate = self.parse_unary()?;
+        loop {
+            match self.peek() {
+                None | Some((Token::Close, _)) => break,
+                Some(_) if self.peek_keyword("OR") => break,
+                Some(_) if self.peek_keyword("AND") => self.next += 1,
+                Some(_) => {}
+            }
+            predicate = predicate.and(self.parse_unary()?);
+        }
+        Ok(predicate)
+    }
+
+    fn parse_unary(&mut self) -> Result<ActionPredicate, ParseActionQueryError> {
+        let Some((token, position)) = self.peek().cloned() else {
+            return Err(ParseActionQueryError::new("expected a term", self.end));
+        };
+        self.next += 1;
+        match token {
+            Token::Open => {
+                let predicate = self.parse_nested(position, Self::parse_or)?;
+                match self.peek() {
+                    Some((Token::Close, _)) => {
+                        self.next += 1;
+                        Ok(predicate)
+                    }
+                    _ => Err(ParseActionQueryError::new("unmatched '('", position)),
+                }
+            }
+            Token::Close => Err(ParseActionQueryError::new("unexpected ')'", position)),
+            Token::Word(word) if word.is_keyword("NOT") => {
+                Ok(!self.parse_nested(position, Self::parse_unary)?)
+            }
+            Token::Word(word) if word.is_keyword("AND") || word.is_keyword("OR") => {
+                Err(ParseActionQueryError::new(
+                    format!("expected a term before '{}'", word.text),
+                    position,
+                ))
+            }
+            Token::Word(word) => match word.negated() {
+                Some(word) => Ok(!self.parse_term(&word, position)?),
+                None => self.parse_term(&word, position),
+            },
+        }
+    }
+
+    /// Runs `parse` one level deeper, for the group or negation starting at `position`.
+    fn parse_nested(
+        &mut self,
+        position: usize,
+        parse: fn(&mut Self) -> Result<ActionPredicate, ParseActionQueryError>,
+    ) -> Result<ActionPredicate, ParseActionQueryError> {
+        if self.depth == Self::MAX_DEPTH {
+            return Err(ParseActionQueryError::new(
+                "query nested too deeply",
+                position,
+            ));
+        }
+        self.depth += 1;
+        let predicate = parse(self);
+        self.depth -= 1;
+        predicate
+    }
+
+    /// Parses a single term, like `tag:work` or `report`.
+    fn parse_term(
+        &self,
+        word: &Word,
+        position: usize,
+    ) -> Result<ActionPredicate, ParseActionQueryError> {
+        let Some((key, op, value)) = word.split() else {
+            return Ok(ActionPredicate::NameContains(word.text.clone()));
+        };
+        let error = |message: String| Err(ParseActionQueryError::new(message, position));
+        if value.is_empty() {
+            return error(format!("missing value after '{key}{op}'"));
+        }
+        match (key, op) {
+            ("is", ":") => match value {
+                "open" => Ok(ActionPredicate::Open),
+                "completed" => Ok(ActionPredicate::Completed),
+                "someday" => Ok(ActionPredicate::Someday),
+                "delegated" => Ok(ActionPredicate::Delegated),
+                "available" => Ok(ActionPredicate::AvailableAt(self.now)),
+                _ => error(format!("unknown state '{value}'")),
+            },
+            ("tag", ":") => match TagPath::new(value) {
+                Ok(tag_path) => Ok(ActionPredicate::Tag(tag_path)),
+                Err(violation) => error(format!("tag path {violation}")),
+            },
+            ("context", ":") => Ok(ActionPredicate::ContextNamed(String::from(value))),
+            ("project", ":") => Ok(ActionPredicate::ProjectNamed(String::from(value))),
+            ("area", ":") => Ok(ActionPredicate::AreaNamed(String::from(value))),
+            ("due", op) => {
+                let Some(day) = self.parse_day(value) else {
+                    return error(format!("invalid date '{value}'"));
+                };
+                let next_day = day + Duration::days(1);
+                Ok(match op {
+                    "<" => ActionPredicate::DueBefore(day),
+                    "<=" => ActionPredicate::DueBefore(next_day),
+                    ">" => ActionPredicate::DueFrom(next_day),
+                    ">=" => ActionPredicate::DueFrom(day),
+                    _ => ActionPredicate::DueFrom(day).and(ActionPredicate::DueBefore(next_day)),
+                })
+            }
+            ("sort", _) => error(String::from("sort keys can't be grouped or negated")),
+            ("is" | "tag" | "context" | "project" | "area", _) => {
+                error(format!("'{key}' can't be compared with '{op}'"))
+            }
+            _ => error(format!("unknown key '{key}'")),
+        }
+    }
+
+    /// Parses `today`, `tomorrow`, a number of days from today like `+7d` or a `YYYY-MM-DD`
+    /// date into the start of that day in UTC.
+    fn parse_day(&self, value: &str) -> Option<DateTime<Utc>> {
+        let today = self.now.date_naive();
+        let day = match value {
+            "today" => today,
+            "tomorrow" => today.succ_opt()?,
+            _ => match value
+                .strip_prefix('+')
+                .and_then(|days| days.strip_suffix('d'))
+            {
+                Some(days) => today.checked_add_days(Days::new(days.parse().ok()?))?,
+                None => NaiveDate::parse_from_str(value, "%Y-%m-%d").ok()?,
+            },
+        };
+        Some(day.and_hms_opt(0, 0, 0)?.and_utc())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use chrono::TimeZone;
+
+    use super::*;
+
+    fn now() -> DateTime<Utc> {
+        Utc.with_ymd_and_hms(2024, 5, 1, 15, 30, 0).unwrap()
+    }
+
+    fn day(d: u32) -> DateTime<Utc> {
+        Utc.with_ymd_and_hms(2024, 5, d, 0, 0, 0).unwrap()
+    }
+
+    fn tag(path: &str) -> ActionPredicate {
+        ActionPredicate::Tag(TagPath::new(path).unwrap())
+    }
+
+    #[test]
+    fn test_terms_are_anded() {
+        let query = parse(r#"is:open tag:work "status report" -is:delegated"#, now()).unwrap();
+        assert_eq!(
+            query,
+            ActionQuery::new().filter(ActionPredicate::And(vec![
+                ActionPredicate::Open,
+                tag("work"),
+                ActionPredicate::NameContains(String::from("status report")),
+                !ActionPredicate::Delegated,
+            ]))
+        );
+    }
+
+    #[test]
+    fn test_or_binds_looser_than_and() {
+        let query = parse("a OR b AND NOT (c OR d)", now()).unwrap();
+        let name = |text: &str| ActionPredicate::NameContains(String::from(text));
+        assert_eq!(
+            query.predicate,
+            Some(name("a").or(name("b").and(!name("c").or(name("d")))))
+        );
+    }
+
+    #[test]
+    fn test_quoted_values_and_dates() {
+        let query = parse(
+            r#"project:"Launch website" due<=tomorrow due:2024-05-07 due<+7d"#,
+            now(),
+        )
+        .unwrap();
+        assert_eq!(
+            query.predicate,
+            Some(ActionPredicate::And(vec![
+                ActionPredicate::ProjectNamed(String::from("Launch website")),
+                ActionPredicate::DueBefore(day(3)),
+                ActionPredicate::DueFrom(day(7)),
+                ActionPredicate::DueBefore(day(8)),
+                ActionPredicate::DueBefore(day(8)),
+            ]))
+        );
+        assert_eq!(
+            parse(r#""OR" "is:open""#, now()).unwrap().predicate,
+            Some(ActionPredicate::And(vec![
+                ActionPredicate::NameContains(String::from("OR")),
+                ActionPredicate::NameContains(String::from("is:open")),
+            ]))
+        );
+    }
+
+    #[test]
+    fn test_sort_terms_are_collected() {
+        let query = parse("sort:due is:open sort:-created", now()).unwrap();
+        assert_eq!(
+            query,
+            ActionQuery::new()
+                .filter(ActionPredicate::Open)
+                .sort_by(ActionSortKey::DueAt, SortDirection::Ascending)
+                .sort_by(ActionSortKey::CreatedAt, SortDirection::Descending)
+        );
+        assert_eq!(parse("", now()).unwrap(), ActionQuery::new());
+    }
+
+    #[test]
+    fn test_errors_report_their_position() {
+        let error = |text: &str| parse(text, now()).unwrap_err();
+        assert_eq!(
+            error("is:open (tag:a"),
+            ParseActionQueryError::new("unmatched '('", 8)
+        );
+        assert_eq!(error("a)"), ParseActionQueryError::new("unmatched ')'", 1));
+        assert_eq!(
+            error("a OR"),
+            ParseActionQueryError::new("expected a term", 4)
+        );
+        assert_eq!(
+            error("is:open due<soon"),
+            ParseActionQueryError::new("invalid date 'soon'", 8)
+        );
+        assert_eq!(
+            error("colour:red"),
+            ParseActionQueryError::new("unknown key 'colour'", 0)
+        );
+        assert_eq!(
+            error("(sort:due)"),
+            ParseActionQueryError::new("sort keys can't be grouped or negated", 1)
+        );
+        assert_eq!(
+            error("\"open"),
+            ParseActionQueryError::new("unterminated quote", 0)
+        );
+        assert_eq!(
+            error("NOT sort:due foo"),
+            ParseActionQueryError::new("sort keys can't follow 'NOT'", 4)
+        );
+        assert_eq!(
+            error("- sort:due"),
+            ParseActionQueryError::new("sort keys can't follow '-'", 2)
+        );
+        assert_eq!(
+            error("foo OR sort:due bar"),
+            ParseActionQueryError::new("sort keys can't follow 'OR'", 7)
+        );
+        assert_eq!(
+            error("foo AND sort:due bar"),
+            ParseActionQueryError::new("sort keys can't follow 'AND'", 8)
+        );
+        assert_eq!(
+            error(&format!("{}a", "(".repeat(5000))),
+            ParseActionQueryError::new("query nested too deeply", 64)
+        );
+        assert_eq!(
+            error(&format!("{}a", "NOT ".repeat(5000))),
+            ParseActionQueryError::new("query nested too deeply", 256)
+        );
+        assert!(parse(&format!("{}a{}", "(".repeat(64), ")".repeat(64)), now()).is_ok());
+    }
+}
@@ -0,0 +1,110 @@
+//! This module contains the `Response` types.
+//!
+//! Most API calls simply return the affected domain model.
+//! Where a call can produce different kinds of results, a `Response` type describes them.
+
+use chrono::{DateTime, Utc};
+
+use crate::model::{
+    action::{Action, action_id::ActionId},
+    area::Area,
+    project::{Project, project_id::ProjectId},
+    reference_item::{ReferenceItem, reference_item_id::ReferenceItemId},
+};
+
+/// The result of processing an inbox item.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ProcessedInboxItem {
+    /// The item was turned into an [`Action`].
+    Action(Action),
+    /// The item was turned into a [`Project`].
+    Project(Project),
+    /// The item was turned into an [`Action`] on the someday/maybe list.
+    SomedayMaybe(Action),
+    /// The item was turned into a [`ReferenceItem`].
+    Reference(ReferenceItem),
+    /// The item was thrown away.
+    Trashed,
+}
+
+/// The result of completing an action.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct CompletedAction {
+    /// The completed [`Action`].
+    pub action: Action,
+    /// The open [`Action`] created as next occurrence, if the completed action repeats.
+    pub next_occurrence: Option<Action>,
+}
+
+/// Everything on the someday/maybe list.
+#[derive(Debug, Clone, PartialEq, Eq, Default)]
+pub struct SomedayMaybeList {
+    /// The open someday/maybe [`Action`]s, ordered by their creation date.
+    pub actions: Vec<Action>,
+    /// The someday/maybe [`Project`]s, ordered by their creation date.
+    pub projects: Vec<Project>,
+}
+
+/// An active project without an available action.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct StalledProject {
+    /// The stalled [`Project`].
+    pub project: Project,
+    /// When the project's last action was completed, if any was.
+    pub last_completed_at: Option<DateTime<Utc>>,
+}
+
+/// An area together with the numbers that tell whether it is neglected.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct AreaSummary {
+    /// The summarized [`Area`].
+    pub area: Area,
+    /// The number of open, committed actions in the area, including those of its projects.
+    pub open_actions: usize,
+    /// The number of stalled projects in the area, see `HeliaCore::stalled_projects`.
+    pub stalled_projects: usize,
+}
+
+/// The item a [`SearchResult`] refers to.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum SearchItem {
+    Action(ActionId),
+    Project(ProjectId),
+    ReferenceItem(ReferenceItemId),
+}
+
+/// An item found by `HeliaCore::search`.
+///
+/// Matches are enclosed in [`SearchResult::HIGHLIGHT_START`] and [`SearchResult::HIGHLIGHT_END`],
+/// the texts are not escaped otherwise.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SearchResult {
+    /// The found [`SearchItem`].
+    pub item: SearchItem,
+    /// The name of the action or project, or the text of the reference item, with its matches
+    /// highlighted.
+    pub title: String,
+    /// An excerpt of the action's notes around its matches, if the action has notes.
+    pub snippet: Option<String>,
+}
+
+impl SearchResult {
+    /// Marks the start of a match.
+    pub const HIGHLIGHT_START: &str = "<mark>";
+    /// Marks the end of a match.
+    pub const HIGHLIGHT_END: &str = "</mark>";
+}
+
+/// How many items of each kind were added by an import.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct ImportSummary {
+    pub areas: usize,
+    pub actions: usize,
+    pub projects: usize,
+    pub contexts: usize,
+    pub tags: usize,
+    pub perspectives: usize,
+    pub reviews: usize,
+    pub inbox_items: usize,
+    pub reference_items: usize,
+}
@@ -10,9 +10,47 @@
 //! It specifies the helia backend's core capabilities.
 
 // Re-export types and functions relevant to the API.
+pub use crate::core::clock::*;
+pub use crate::core::config::*;
 pub use crate::core::helia_error::*;
 pub use crate::core::requests::*;
+pub use crate::core::responses::*;
 pub use crate::core::*;
+pub use crate::model::action::{
+    Action,
+    acion_create_date::ActionCreateDate,
+    action_complete_date::ActionCompleteDate,
+    action_defer_date::ActionDeferDate,
+    action_delegation::ActionDelegation,
+    action_due_date::ActionDueDate,
+    action_id::ActionId,
+    action_name::ActionName,
+    action_notes::ActionNotes,
+    action_recurrence::{ActionRecurrence, Frequency, InvalidRecurrence, RecurrenceBase},
+};
+pub use crate::model::area::{Area, area_id::AreaId, area_name::AreaName};
+pub use crate::model::context::{Context, context_id::ContextId, context_name::ContextName};
+pub use crate::model::inbox_item::{
+    InboxItem, inbox_item_capture_date::InboxItemCaptureDate, inbox_item_id::InboxItemId,
+    inbox_item_text::InboxItemText,
+};
+pub use crate::model::perspective::{
+    Perspective, perspective_id::PerspectiveId, perspective_name::PerspectiveName,
+    perspective_query::PerspectiveQuery,
+};
+pub use crate::model::project::{
+    Project, project_create_date::ProjectCreateDate, project_id::ProjectId,
+    project_name::ProjectName, project_ordering::ProjectOrdering,
+    project_review_date::ProjectReviewDate, project_status::ProjectStatus,
+};
+pub use crate::model::reference_item::{
+    ReferenceItem, reference_item_create_date::ReferenceItemCreateDate,
+    reference_item_id::ReferenceItemId, reference_item_text::ReferenceItemText,
+};
+pub use crate::model::review::{Review, review_id::ReviewId, review_step::ReviewStep};
+pub use crate::model::tag::{Tag, tag_id::TagId, tag_path::TagPath};
+pub use crate::model::validation::{FieldError, Violation};
+pub use crate::storage::storage_error::{StorageError, StorageErrorKind};
 
 // Helia core API
 mod core;
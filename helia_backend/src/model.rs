@@ -4,5 +4,23 @@
 
 // Represents a GTD action.
 pub mod action;
+// Represents a GTD project.
+pub mod project;
+// Represents a GTD area of focus, like `Health` or `Finance`.
+pub mod area;
+// Represents a GTD context, like `@home` or `@phone`.
+pub mod context;
+// Represents a free-form, hierarchical tag, like `urgent` or `work/client-x`.
+pub mod tag;
+// Represents a saved perspective, a named query on actions.
+pub mod perspective;
+// Represents a captured, not yet processed GTD inbox item.
+pub mod inbox_item;
+// Represents GTD reference material.
+pub mod reference_item;
+// Represents a GTD weekly review.
+pub mod review;
+// Validation rules and errors for user input.
+pub mod validation;
 // Module containing common entities.
 mod common;
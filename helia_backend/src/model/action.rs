@@ -7,8 +7,22 @@
 
 use core::fmt;
 
-use crate::model::action::{
-    acion_create_date::ActionCreateDate, action_id::ActionId, action_name::ActionName,
+use chrono::{DateTime, Utc};
+
+use crate::model::{
+    action::{
+        acion_create_date::ActionCreateDate,
+        action_complete_date::ActionCompleteDate,
+        action_defer_date::ActionDeferDate,
+        action_delegation::ActionDelegation,
+        action_due_date::ActionDueDate,
+        action_id::ActionId,
+        action_name::ActionName,
+        action_notes::ActionNotes,
+        action_recurrence::{ActionRecurrence, RecurrenceBase},
+    },
+    area::area_id::AreaId,
+    project::project_id::ProjectId,
 };
 
 // The id of an action.
@@ -17,17 +31,39 @@ pub mod action_id;
 pub mod action_name;
 // The creation date of an action.
 pub mod acion_create_date;
+// The completion date of an action.
+pub mod action_complete_date;
+// The due date of an action.
+pub mod action_due_date;
+// The defer date of an action.
+pub mod action_defer_date;
+// The free-text notes of an action.
+pub mod action_notes;
+// The recurrence rule of an action.
+pub mod action_recurrence;
+// The delegation of an action.
+pub mod action_delegation;
 
 // A GTD Action.
-#[derive(Debug)]
+#[derive(Debug, Clone, PartialEq, Eq)]
 pub struct Action {
     action_id: ActionId,
     action_name: ActionName,
     action_create_date: ActionCreateDate,
+    action_complete_date: Option<ActionCompleteDate>,
+    action_due_date: Option<ActionDueDate>,
+    action_defer_date: Option<ActionDeferDate>,
+    action_notes: Option<ActionNotes>,
+    action_recurrence: Option<ActionRecurrence>,
+    action_delegation: Option<ActionDelegation>,
+    project_id: Option<ProjectId>,
+    position: Option<u32>,
+    area_id: Option<AreaId>,
+    someday: bool,
 }
 
 impl Action {
-    /// Returns a new [Action] instance.
+    /// Returns a new, open [Action] instance.
     pub(crate) fn new(
         action_id: ActionId,
         action_name: ActionName,
@@ -37,6 +73,16 @@ impl Action {
             action_id,
             action_name,
             action_create_date,
+            action_complete_date: None,
+            action_due_date: None,
+            action_defer_date: None,
+            action_notes: None,
+            action_recurrence: None,
+            action_delegation: None,
+            project_id: None,
+            position: None,
+            area_id: None,
+            someday: false,
         }
     }
 
@@ -54,6 +100,173 @@ impl Action {
     pub fn action_create_date(&self) -> &ActionCreateDate {
         &self.action_create_date
     }
+
+    /// Returns a reference to the action's [ActionCompleteDate], if the action is completed.
+    pub fn action_complete_date(&self) -> Option<&ActionCompleteDate> {
+        self.action_complete_date.as_ref()
+    }
+
+    /// Returns `true` if the action has been completed.
+    pub fn is_completed(&self) -> bool {
+        self.action_complete_date.is_some()
+    }
+
+    /// Returns a reference to the action's [ActionDueDate], if it has one.
+    pub fn action_due_date(&self) -> Option<&ActionDueDate> {
+        self.action_due_date.as_ref()
+    }
+
+    /// Returns a reference to the action's [ActionDeferDate], if it has one.
+    pub fn action_defer_date(&self) -> Option<&ActionDeferDate> {
+        self.action_defer_date.as_ref()
+    }
+
+    /// Returns a reference to the action's [ActionNotes], if it has any.
+    pub fn action_notes(&self) -> Option<&ActionNotes> {
+        self.action_notes.as_ref()
+    }
+
+    /// Returns a reference to the action's [ActionRecurrence], if it repeats.
+    pub fn action_recurrence(&self) -> Option<&ActionRecurrence> {
+        self.action_recurrence.as_ref()
+    }
+
+    /// Returns a reference to the action's [ActionDelegation], if it is delegated.
+    pub fn action_delegation(&self) -> Option<&ActionDelegation> {
+        self.action_delegation.as_ref()
+    }
+
+    /// Returns `true` if the action is delegated and waited for.
+    pub fn is_delegated(&self) -> bool {
+        self.action_delegation.is_some()
+    }
+
+    /// Returns the open action that follows this recurring action once it is completed at
+    /// `completed_at`, or `None` if the action doesn't repeat (anymore).
+    ///
+    /// Due and defer dates are shifted by the same amount, so their distance is kept.
+    /// Depending on the [RecurrenceBase], the next date is computed from the due date (or the
+    /// defer date, if there is no due date) or from `completed_at`. An action without either
+    /// date is always scheduled from `completed_at`.
+    pub(crate) fn next_occurrence(
+        &self,
+        action_id: ActionId,
+        action_create_date: ActionCreateDate,
+        completed_at: DateTime<Utc>,
+    ) -> Option<Action> {
+        let recurrence = self.action_recurrence()?;
+        let scheduled = self
+            .action_due_date()
+            .map(|date| *date.value())
+            .or_else(|| self.action_defer_date().map(|date| *date.value()))
+            .unwrap_or(completed_at);
+        let from = match recurrence.base() {
+            RecurrenceBase::Schedule => scheduled,
+            RecurrenceBase::Completion => completed_at
+                .date_naive()
+                .and_time(scheduled.time())
+                .and_utc(),
+        };
+        let shift = recurrence.next_after(from)? - scheduled;
+
+        let mut next = Action::new(action_id, self.action_name.clone(), action_create_date);
+        next.action_due_date = self
+            .action_due_date()
+            .map(|date| ActionDueDate::from_value(*date.value() + shift));
+        next.action_defer_date = self
+            .action_defer_date()
+            .map(|date| ActionDeferDate::from_value(*date.value() + shift));
+        next.action_notes = self.action_notes.clone();
+        next.action_recurrence = Some(recurrence.successor()?);
+        next.project_id = self.project_id.clone();
+        next.position = self.position;
+        next.area_id = self.area_id.clone();
+        next.someday = self.someday;
+        Some(next)
+    }
+
+    /// Returns a reference to the [ProjectId] of the project the action belongs to, if any.
+    pub fn project_id(&self) -> Option<&ProjectId> {
+        self.project_id.as_ref()
+    }
+
+    /// Returns the position of the action within its project, if it belongs to one.
+    ///
+    /// Actions with lower positions come first.
+    pub fn position(&self) -> Option<u32> {
+        self.position
+    }
+
+    /// Returns a reference to the [AreaId] of the area the action belongs to, if any.
+    ///
+    /// Only standalone actions belong to an area directly, the actions of a project belong to
+    /// the project's area.
+    pub fn area_id(&self) -> Option<&AreaId> {
+        self.area_id.as_ref()
+    }
+
+    /// Returns `true` if the action is on the someday/maybe list rather than committed to.
+    pub fn is_someday(&self) -> bool {
+        self.someday
+    }
+
+    /// Replaces the action's [ActionName].
+    pub(crate) fn set_action_name(&mut self, action_name: ActionName) {
+        self.action_name = action_name;
+    }
+
+    /// Marks the action as completed at the given date, or as open if `None` is given.
+    pub(crate) fn set_action_complete_date(
+        &mut self,
+        action_complete_date: Option<ActionCompleteDate>,
+    ) {
+        self.action_complete_date = action_complete_date;
+    }
+
+    /// Replaces the action's [ActionDueDate], or removes it if `None` is given.
+    pub(crate) fn set_action_due_date(&mut self, action_due_date: Option<ActionDueDate>) {
+        self.action_due_date = action_due_date;
+    }
+
+    /// Replaces the action's [ActionDeferDate], or removes it if `None` is given.
+    pub(crate) fn set_action_defer_date(&mut self, action_defer_date: Option<ActionDeferDate>) {
+        self.action_defer_date = action_defer_date;
+    }
+
+    /// Replaces the action's [ActionNotes], or removes them if `None` is given.
+    pub(crate) fn set_action_notes(&mut self, action_notes: Option<ActionNotes>) {
+        self.action_notes = action_notes;
+    }
+
+    /// Replaces the action's [ActionRecurrence], or stops it from repeating if `None` is given.
+    pub(crate) fn set_action_recurrence(&mut self, action_recurrence: Option<ActionRecurrence>) {
+        self.action_recurrence = action_recurrence;
+    }
+
+    /// Delegates the action, or reclaims it if `None` is given.
+    pub(crate) fn set_action_delegation(&mut self, action_delegation: Option<ActionDelegation>) {
+        self.action_delegation = action_delegation;
+    }
+
+    /// Moves the action into the given project, or out of any project if `None` is given.
+    pub(crate) fn set_project_id(&mut self, project_id: Option<ProjectId>) {
+        self.project_id = project_id;
+    }
+
+    /// Replaces the action's position within its project.
+    pub(crate) fn set_position(&mut self, position: Option<u32>) {
+        self.position = position;
+    }
+
+    /// Moves the action into the given area, or out of any area if `None` is given.
+    pub(crate) fn set_area_id(&mut self, area_id: Option<AreaId>) {
+        self.area_id = area_id;
+    }
+
+    /// Moves the action onto (`true`) or off (`false`) the someday/maybe list.
+    pub(crate) fn set_someday(&mut self, someday: bool) {
+        self.someday = someday;
+    }
 }
 
 // Nicer formatting for actions when printed to the console.
@@ -64,6 +277,36 @@ impl fmt::Display for Action {
         writeln!(f, "    {}", self.action_id())?;
         writeln!(f, "    {}", self.action_name())?;
         writeln!(f, "    {}", self.action_create_date)?;
+        if let Some(action_complete_date) = self.action_complete_date() {
+            writeln!(f, "    {action_complete_date}")?;
+        }
+        if let Some(action_due_date) = self.action_due_date() {
+            writeln!(f, "    {action_due_date}")?;
+        }
+        if let Some(action_defer_date) = self.action_defer_date() {
+            writeln!(f, "    {action_defer_date}")?;
+        }
+        if let Some(action_notes) = self.action_notes() {
+            writeln!(f, "    {action_notes}")?;
+        }
+        if let Some(action_recurrence) = self.action_recurrence() {
+            writeln!(f, "    {action_recurrence}")?;
+        }
+        if let Some(action_delegation) = self.action_delegation() {
+            writeln!(f, "    {action_delegation}")?;
+        }
+        if let Some(project_id) = self.project_id() {
+            writeln!(f, "    {project_id}")?;
+        }
+        if let Some(position) = self.position {
+            writeln!(f, "    Position {position}")?;
+        }
+        if let Some(area_id) = self.area_id() {
+            writeln!(f, "    {area_id}")?;
+        }
+        if self.someday {
+            writeln!(f, "    Someday")?;
+        }
         writeln!(f, "}}")
     }
 }
@@ -75,7 +318,7 @@ mod tests {
     #[test]
     fn test_action_getters() {
         let my_action_id = ActionId::new();
-        let my_action_name = ActionName::new("my_action");
+        let my_action_name = ActionName::new("my_action").unwrap();
         let my_action_create_date = ActionCreateDate::now();
         let my_action = Action::new(
             my_action_id.clone(),
@@ -84,7 +327,78 @@ mod tests {
         );
         assert_eq!(my_action.action_id, my_action_id);
         assert_eq!(my_action.action_name, my_action_name);
-        assert_eq!(my_action.action_create_date, my_action_create_date)
+        assert_eq!(my_action.action_create_date, my_action_create_date);
+        assert_eq!(my_action.action_complete_date(), None);
+    }
+
+    #[test]
+    fn test_completion() {
+        let mut my_action = test_utils::dummy_action();
+        assert!(!my_action.is_completed());
+
+        let my_action_complete_date = ActionCompleteDate::now();
+        my_action.set_action_complete_date(Some(my_action_complete_date.clone()));
+        assert!(my_action.is_completed());
+        assert_eq!(
+            my_action.action_complete_date(),
+            Some(&my_action_complete_date)
+        );
+
+        my_action.set_action_complete_date(None);
+        assert!(!my_action.is_completed());
+    }
+
+    #[test]
+    fn test_next_occurrence_shifts_dates() {
+        use chrono::{Duration, TimeZone};
+
+        use crate::model::action::action_recurrence::Frequency;
+
+        let due = Utc.with_ymd_and_hms(2024, 3, 4, 17, 0, 0).unwrap();
+        let completed_at = due + Duration::days(2);
+        let mut my_action = test_utils::dummy_action();
+        my_action.set_action_due_date(Some(ActionDueDate::from_value(due)));
+        my_action.set_action_defer_date(Some(ActionDeferDate::from_value(due - Duration::days(1))));
+
+        let weekly = ActionRecurrence::new(Frequency::Weekly).count(2);
+        my_action.set_action_recurrence(Some(weekly.clone()));
+        let next = my_action
+            .next_occurrence(ActionId::new(), ActionCreateDate::now(), completed_at)
+            .unwrap();
+        assert_eq!(next.action_name(), my_action.action_name());
+        assert_eq!(
+            next.action_due_date().unwrap().value(),
+            &(due + Duration::weeks(1))
+        );
+        assert_eq!(
+            next.action_defer_date().unwrap().value(),
+            &(due + Duration::days(6))
+        );
+        assert_eq!(next.action_recurrence().unwrap().remaining_count(), Some(1));
+        assert!(!next.is_completed());
+        // The last occurrence by count has no successor.
+        assert_eq!(
+            next.next_occurrence(ActionId::new(), ActionCreateDate::now(), completed_at),
+            None
+        );
+
+        my_action.set_action_recurrence(Some(weekly.based_on(RecurrenceBase::Completion)));
+        let next = my_action
+            .next_occurrence(ActionId::new(), ActionCreateDate::now(), completed_at)
+            .unwrap();
+        assert_eq!(
+            next.action_due_date().unwrap().value(),
+            &(completed_at + Duration::weeks(1))
+        );
+    }
+
+    #[test]
+    fn test_no_next_occurrence_without_recurrence() {
+        let my_action = test_utils::dummy_action();
+        assert_eq!(
+            my_action.next_occurrence(ActionId::new(), ActionCreateDate::now(), Utc::now()),
+            None
+        );
     }
 }
 
@@ -96,8 +410,18 @@ pub mod test_utils {
     pub fn dummy_action() -> Action {
         Action {
             action_id: ActionId::new(),
-            action_name: ActionName::new("Dummy Action"),
+            action_name: ActionName::new("Dummy Action").unwrap(),
             action_create_date: ActionCreateDate::now(),
+            action_complete_date: None,
+            action_due_date: None,
+            action_defer_date: None,
+            action_notes: None,
+            action_recurrence: None,
+            action_delegation: None,
+            project_id: None,
+            position: None,
+            area_id: None,
+            someday: false,
         }
     }
 }
@@ -18,6 +18,11 @@ impl ActionCreateDate {
         Self(Date::now())
     }
 
+    /// Creates an [ActionCreateDate] instance from an existing [DateTime].
+    pub fn from_value(value: DateTime<Utc>) -> Self {
+        Self(Date::from_value(value))
+    }
+
     /// Returns the [DateTime] inside this [ActionCreateDate] instance.
     pub fn value(&self) -> &DateTime<Utc> {
         self.0.value()
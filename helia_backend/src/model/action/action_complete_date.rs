@@ -0,0 +1,63 @@
+//! Completion dates of [`Action`s](super::Action).
+//!
+//! This module contains the [ActionCompleteDate] struct.
+//! It represents the date an action was completed in UTC.
+//! Completion dates contain both a date and a time value.
+
+use chrono::{DateTime, Utc};
+
+use crate::model::common::date::Date;
+
+/// The date and time an [Action](crate::model::action::Action) was completed.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct ActionCompleteDate(Date);
+
+impl ActionCompleteDate {
+    /// Creates a new [ActionCompleteDate] instance of the current time.
+    pub fn now() -> Self {
+        Self(Date::now())
+    }
+
+    /// Creates an [ActionCompleteDate] instance from an existing [DateTime].
+    pub fn from_value(value: DateTime<Utc>) -> Self {
+        Self(Date::from_value(value))
+    }
+
+    /// Returns the [DateTime] inside this [ActionCompleteDate] instance.
+    pub fn value(&self) -> &DateTime<Utc> {
+        self.0.value()
+    }
+}
+
+impl Default for ActionCompleteDate {
+    fn default() -> Self {
+        Self::now()
+    }
+}
+
+impl std::fmt::Display for ActionCompleteDate {
+    /// Nicer formatting for [ActionCompleteDate].
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "ActionCompleteDate({})", self.0)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn from_value_keeps_value() {
+        let value = Utc::now();
+        assert_eq!(ActionCompleteDate::from_value(value).value(), &value);
+    }
+
+    #[test]
+    fn test_display() {
+        let action_complete_date = ActionCompleteDate::now();
+        assert_eq!(
+            format!("{action_complete_date}"),
+            format!("ActionCompleteDate(Date({}))", action_complete_date.value())
+        );
+    }
+}
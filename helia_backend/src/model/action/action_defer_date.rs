@@ -0,0 +1,48 @@
+//! Defer dates of [`Action`s](super::Action).
+//!
+//! This module contains the [ActionDeferDate] struct.
+//! It represents the date and time until which an action is hidden from lists of available
+//! actions, in UTC. This is sometimes also called the *start date*.
+
+use chrono::{DateTime, Utc};
+
+use crate::model::common::date::Date;
+
+/// The date and time until which an [Action](crate::model::action::Action) is deferred.
+///
+/// A deferred action cannot be worked on yet, so it is not considered available before then.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct ActionDeferDate(Date);
+
+impl ActionDeferDate {
+    /// Creates an [ActionDeferDate] instance from an existing [DateTime].
+    pub fn from_value(value: DateTime<Utc>) -> Self {
+        Self(Date::from_value(value))
+    }
+
+    /// Returns the [DateTime] inside this [ActionDeferDate] instance.
+    pub fn value(&self) -> &DateTime<Utc> {
+        self.0.value()
+    }
+}
+
+impl std::fmt::Display for ActionDeferDate {
+    /// Nicer formatting for [ActionDeferDate].
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "ActionDeferDate({})", self.0)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_display() {
+        let action_defer_date = ActionDeferDate::from_value(Utc::now());
+        assert_eq!(
+            format!("{action_defer_date}"),
+            format!("ActionDeferDate(Date({}))", action_defer_date.value())
+        );
+    }
+}
@@ -0,0 +1,127 @@
+//! Delegation of [`Action`s](super::Action).
+//!
+//! This module contains the [ActionDelegation] struct.
+//! A delegated action sits on the waiting-for list until it is done or reclaimed.
+
+use chrono::{DateTime, Utc};
+
+use crate::model::validation::{self, Violation};
+
+/// Who an [Action](crate::model::action::Action) was delegated to, when, and when to follow up
+/// on it.
+///
+/// The delegate is trimmed, non-empty, at most [ActionDelegation::MAX_DELEGATE_LEN] characters
+/// long and contains no control characters.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ActionDelegation {
+    delegate: String,
+    delegated_at: DateTime<Utc>,
+    follow_up_at: Option<DateTime<Utc>>,
+}
+
+impl ActionDelegation {
+    /// The maximum number of characters of a delegate.
+    pub const MAX_DELEGATE_LEN: usize = 200;
+
+    /// Creates a new [ActionDelegation] to the trimmed `delegate`, made at `delegated_at`.
+    ///
+    /// Fails if the delegate breaks one of the rules described at [ActionDelegation].
+    pub fn new(
+        delegate: &str,
+        delegated_at: DateTime<Utc>,
+        follow_up_at: Option<DateTime<Utc>>,
+    ) -> Result<Self, Violation> {
+        let delegate = validation::single_line_text(delegate, Self::MAX_DELEGATE_LEN)?;
+        Ok(Self::from_stored(delegate, delegated_at, follow_up_at))
+    }
+
+    /// Creates an [ActionDelegation] instance without validating it.
+    ///
+    /// Only meant for delegations that were already stored.
+    pub(crate) fn from_stored(
+        delegate: &str,
+        delegated_at: DateTime<Utc>,
+        follow_up_at: Option<DateTime<Utc>>,
+    ) -> Self {
+        Self {
+            delegate: String::from(delegate),
+            delegated_at,
+            follow_up_at,
+        }
+    }
+
+    /// Returns who the action was delegated to.
+    pub fn delegate(&self) -> &str {
+        &self.delegate
+    }
+
+    /// Returns when the action was delegated.
+    pub fn delegated_at(&self) -> &DateTime<Utc> {
+        &self.delegated_at
+    }
+
+    /// Returns when to follow up with the delegate, if planned.
+    pub fn follow_up_at(&self) -> Option<&DateTime<Utc>> {
+        self.follow_up_at.as_ref()
+    }
+
+    /// Returns `true` if a follow-up is planned at or before `now`.
+    pub fn is_follow_up_due(&self, now: DateTime<Utc>) -> bool {
+        self.follow_up_at
+            .is_some_and(|follow_up_at| follow_up_at <= now)
+    }
+}
+
+impl std::fmt::Display for ActionDelegation {
+    /// Nicer formatting for [ActionDelegation].
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "ActionDelegation({} since {}",
+            self.delegate, self.delegated_at
+        )?;
+        if let Some(follow_up_at) = self.follow_up_at {
+            write!(f, ", follow up {follow_up_at}")?;
+        }
+        write!(f, ")")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use chrono::{Duration, TimeZone};
+
+    use super::*;
+
+    #[test]
+    fn test_delegate_is_trimmed_and_validated() {
+        let now = Utc::now();
+        let delegation = ActionDelegation::new("  Alice ", now, None).unwrap();
+        assert_eq!(delegation.delegate(), "Alice");
+        assert_eq!(delegation.delegated_at(), &now);
+        assert_eq!(ActionDelegation::new(" ", now, None), Err(Violation::Empty));
+    }
+
+    #[test]
+    fn test_is_follow_up_due() {
+        let now = Utc::now();
+        let without = ActionDelegation::new("Alice", now, None).unwrap();
+        assert!(!without.is_follow_up_due(now));
+
+        let delegation =
+            ActionDelegation::new("Alice", now, Some(now + Duration::days(2))).unwrap();
+        assert!(!delegation.is_follow_up_due(now));
+        assert!(delegation.is_follow_up_due(now + Duration::days(2)));
+    }
+
+    #[test]
+    fn test_display() {
+        let at = Utc.with_ymd_and_hms(2024, 3, 1, 9, 0, 0).unwrap();
+        let delegation = ActionDelegation::new("Alice", at, Some(at)).unwrap();
+        assert_eq!(
+            format!("{delegation}"),
+            "ActionDelegation(Alice since 2024-03-01 09:00:00 UTC, \
+             follow up 2024-03-01 09:00:00 UTC)"
+        );
+    }
+}
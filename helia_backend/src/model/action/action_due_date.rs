@@ -0,0 +1,45 @@
+//! Due dates of [`Action`s](super::Action).
+//!
+//! This module contains the [ActionDueDate] struct.
+//! It represents the date and time by which an action has to be done, in UTC.
+
+use chrono::{DateTime, Utc};
+
+use crate::model::common::date::Date;
+
+/// The date and time by which an [Action](crate::model::action::Action) has to be done.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct ActionDueDate(Date);
+
+impl ActionDueDate {
+    /// Creates an [ActionDueDate] instance from an existing [DateTime].
+    pub fn from_value(value: DateTime<Utc>) -> Self {
+        Self(Date::from_value(value))
+    }
+
+    /// Returns the [DateTime] inside this [ActionDueDate] instance.
+    pub fn value(&self) -> &DateTime<Utc> {
+        self.0.value()
+    }
+}
+
+impl std::fmt::Display for ActionDueDate {
+    /// Nicer formatting for [ActionDueDate].
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "ActionDueDate({})", self.0)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_display() {
+        let action_due_date = ActionDueDate::from_value(Utc::now());
+        assert_eq!(
+            format!("{action_due_date}"),
+            format!("ActionDueDate(Date({}))", action_due_date.value())
+        );
+    }
+}
@@ -17,6 +17,11 @@ impl ActionId {
         Self(Id::new())
     }
 
+    /// Creates an [ActionId] instance from an existing [uuid::Uuid].
+    pub fn from_uuid(uuid: Uuid) -> Self {
+        Self(Id::from_uuid(uuid))
+    }
+
     /// Returns the underlying [uuid::Uuid].
     pub fn uuid(&self) -> &Uuid {
         self.0.uuid()
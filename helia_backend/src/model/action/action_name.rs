@@ -2,13 +2,31 @@
 //!
 //! This module contains the [ActionName] struct.
 
+use crate::model::validation::{self, Violation};
+
 /// The name of an [Action](crate::model::action::Action).
+///
+/// Names are trimmed, non-empty, at most [ActionName::MAX_LEN] characters long and contain no
+/// control characters.
 #[derive(Debug, PartialEq, Eq, Clone)]
 pub struct ActionName(String);
 
 impl ActionName {
-    /// Creates a new [ActionName] instance.
-    pub fn new(action_name: &str) -> Self {
+    /// The maximum number of characters of an [ActionName].
+    pub const MAX_LEN: usize = 500;
+
+    /// Creates a new [ActionName] instance from the trimmed `action_name`.
+    ///
+    /// Fails if the name breaks one of the rules described at [ActionName].
+    pub fn new(action_name: &str) -> Result<Self, Violation> {
+        let action_name = validation::single_line_text(action_name, Self::MAX_LEN)?;
+        Ok(ActionName(String::from(action_name)))
+    }
+
+    /// Creates an [ActionName] instance without validating it.
+    ///
+    /// Only meant for names that were already stored.
+    pub(crate) fn from_stored(action_name: &str) -> Self {
         ActionName(String::from(action_name))
     }
 
@@ -31,13 +49,36 @@ mod tests {
 
     #[test]
     fn as_str_returns_string_of_name() {
-        let action_name = ActionName::new("MyAction");
+        let action_name = ActionName::new("MyAction").unwrap();
         assert_eq!(action_name.as_str(), "MyAction");
     }
 
     #[test]
     fn test_display() {
-        let action_name = ActionName::new("MyAction");
+        let action_name = ActionName::new("MyAction").unwrap();
         assert_eq!(format!("{action_name}"), "ActionName(MyAction)");
     }
+
+    #[test]
+    fn test_name_is_trimmed() {
+        let action_name = ActionName::new("  MyAction ").unwrap();
+        assert_eq!(action_name.as_str(), "MyAction");
+    }
+
+    #[test]
+    fn test_invalid_names_are_rejected() {
+        assert_eq!(ActionName::new("   "), Err(Violation::Empty));
+        assert_eq!(
+            ActionName::new("My\u{0}Action"),
+            Err(Violation::ControlCharacter)
+        );
+        assert_eq!(
+            ActionName::new(&"a".repeat(ActionName::MAX_LEN + 1)),
+            Err(Violation::TooLong {
+                max: ActionName::MAX_LEN,
+                actual: ActionName::MAX_LEN + 1
+            })
+        );
+        assert!(ActionName::new(&"a".repeat(ActionName::MAX_LEN)).is_ok());
+    }
 }
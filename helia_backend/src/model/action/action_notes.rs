@@ -0,0 +1,99 @@
+//! Notes of [`Action`s](super::Action).
+//!
+//! This module contains the [ActionNotes] struct.
+
+/// Free-text notes of an [Action](crate::model::action::Action).
+///
+/// Notes are usually written in Markdown and are kept exactly as given, including whitespace
+/// and line breaks.
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub struct ActionNotes(String);
+
+impl ActionNotes {
+    /// Creates a new [ActionNotes] instance.
+    pub fn new(action_notes: &str) -> Self {
+        ActionNotes(String::from(action_notes))
+    }
+
+    /// Returns the notes as a string.
+    pub fn as_str(&self) -> &str {
+        &self.0
+    }
+
+    /// Returns all `http` and `https` URLs found in the notes, in order of appearance.
+    ///
+    /// Both bare URLs and URLs inside Markdown links (`[text](url)`, `<url>`) are found.
+    /// Trailing punctuation like a sentence-ending period is not considered part of a URL.
+    pub fn urls(&self) -> Vec<&str> {
+        let mut urls = Vec::new();
+        let mut rest = self.0.as_str();
+        while let Some(start) = find_url_start(rest) {
+            let candidate = &rest[start..];
+            let end = candidate
+                .find(|c: char| c.is_whitespace() || "<>()[]\"'`".contains(c))
+                .unwrap_or(candidate.len());
+            let url = candidate[..end].trim_end_matches(['.', ',', ';', ':', '!', '?']);
+            if url.len() > url.find("://").map_or(0, |scheme_end| scheme_end + 3) {
+                urls.push(url);
+            }
+            rest = &candidate[end.max(1)..];
+        }
+        urls
+    }
+}
+
+/// Returns the byte index of the first `http://` or `https://` in `text`.
+fn find_url_start(text: &str) -> Option<usize> {
+    let http = text.find("http://");
+    let https = text.find("https://");
+    match (http, https) {
+        (Some(http), Some(https)) => Some(http.min(https)),
+        (http, https) => http.or(https),
+    }
+}
+
+impl std::fmt::Display for ActionNotes {
+    /// Nicer formatting for [ActionNotes].
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "ActionNotes({})", self.0)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn as_str_keeps_markdown_verbatim() {
+        let markdown = "# Call\n\n- [ ] ask about *invoice*\n  - phone: +49 123  \n";
+        let action_notes = ActionNotes::new(markdown);
+        assert_eq!(action_notes.as_str(), markdown);
+    }
+
+    #[test]
+    fn test_display() {
+        let action_notes = ActionNotes::new("Something");
+        assert_eq!(format!("{action_notes}"), "ActionNotes(Something)");
+    }
+
+    #[test]
+    fn test_urls() {
+        let action_notes = ActionNotes::new(
+            "See https://example.com/a?b=c. Docs: [guide](http://docs.example.org/guide), \
+             <https://example.net>\nand https:// alone.",
+        );
+        assert_eq!(
+            action_notes.urls(),
+            vec![
+                "https://example.com/a?b=c",
+                "http://docs.example.org/guide",
+                "https://example.net"
+            ]
+        );
+    }
+
+    #[test]
+    fn test_no_urls() {
+        assert!(ActionNotes::new("Call Bob at 555-1234").urls().is_empty());
+    }
+}
@@ -0,0 +1,599 @@
+//! Recurrence rules of [`Action`s](super::Action).
+//!
+//! This module contains the [ActionRecurrence] struct, modelled after the `RRULE` property of
+//! iCalendar (RFC 5545), and its textual representation.
+
+use std::str::FromStr;
+
+use chrono::{DateTime, Datelike, Days, Months, NaiveDate, NaiveDateTime, Utc, Weekday};
+use thiserror::Error;
+
+use crate::model::validation::Violation;
+
+/// How often an action recurs, before applying the interval.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Frequency {
+    Daily,
+    Weekly,
+    Monthly,
+    Yearly,
+}
+
+impl Frequency {
+    /// Returns the `FREQ` value of the frequency.
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Frequency::Daily => "DAILY",
+            Frequency::Weekly => "WEEKLY",
+            Frequency::Monthly => "MONTHLY",
+            Frequency::Yearly => "YEARLY",
+        }
+    }
+}
+
+/// What the next occurrence of a recurring action is computed from.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub enum RecurrenceBase {
+    /// The next occurrence follows the original schedule, no matter when the action was
+    /// completed. Paying rent on the first of every month is an example.
+    #[default]
+    Schedule,
+    /// The next occurrence is computed from the moment the action was completed.
+    /// Mowing the lawn a week after it was last mowed is an example.
+    Completion,
+}
+
+/// The recurrence rule of an [Action](crate::model::action::Action).
+///
+/// * `by_weekday` restricts daily and weekly rules to the given weekdays.
+/// * `by_month_day` selects the days of the month of monthly rules.
+///   Months lacking a selected day are skipped for that day.
+/// * Monthly and yearly rules without `by_month_day` keep the day of the month, falling back to
+///   the last day of shorter months.
+/// * `count` is the number of occurrences left, including the current one.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ActionRecurrence {
+    frequency: Frequency,
+    interval: u32,
+    by_weekday: Vec<Weekday>,
+    by_month_day: Vec<u32>,
+    until: Option<DateTime<Utc>>,
+    count: Option<u32>,
+    base: RecurrenceBase,
+}
+
+impl ActionRecurrence {
+    /// The largest number of periods between two occurrences.
+    pub const MAX_INTERVAL: u32 = 1000;
+
+    /// Creates a new [ActionRecurrence] recurring every period of the given [Frequency].
+    pub fn new(frequency: Frequency) -> Self {
+        Self {
+            frequency,
+            interval: 1,
+            by_weekday: Vec::new(),
+            by_month_day: Vec::new(),
+            until: None,
+            count: None,
+            base: RecurrenceBase::default(),
+        }
+    }
+
+    /// Sets how many periods lie between two occurrences, e.g. `2` for every other week.
+    pub fn with_interval(mut self, interval: u32) -> Self {
+        self.interval = interval;
+        self
+    }
+
+    /// Restricts a daily or weekly rule to the given weekdays.
+    pub fn with_weekdays(mut self, weekdays: &[Weekday]) -> Self {
+        self.by_weekday = weekdays.to_vec();
+        self.by_weekday.sort_by_key(Weekday::num_days_from_monday);
+        self.by_weekday.dedup();
+        self
+    }
+
+    /// Sets the days of the month (`1` to `31`) a monthly rule recurs on.
+    pub fn with_month_days(mut self, month_days: &[u32]) -> Self {
+        self.by_month_day = month_days.to_vec();
+        self.by_month_day.sort_unstable();
+        self.by_month_day.dedup();
+        self
+    }
+
+    /// Ends the recurrence after the given moment.
+    pub fn until(mut self, until: DateTime<Utc>) -> Self {
+        self.until = Some(until);
+        self
+    }
+
+    /// Ends the recurrence after the given number of occurrences.
+    pub fn count(mut self, count: u32) -> Self {
+        self.count = Some(count);
+        self
+    }
+
+    /// Sets what the next occurrence is computed from.
+    pub fn based_on(mut self, base: RecurrenceBase) -> Self {
+        self.base = base;
+        self
+    }
+
+    /// Returns the [Frequency] of the rule.
+    pub fn frequency(&self) -> Frequency {
+        self.frequency
+    }
+
+    /// Returns the number of periods between two occurrences.
+    pub fn interval(&self) -> u32 {
+        self.interval
+    }
+
+    /// Returns the weekdays a daily or weekly rule is restricted to.
+    pub fn weekdays(&self) -> &[Weekday] {
+        &self.by_weekday
+    }
+
+    /// Returns the days of the month a monthly rule recurs on.
+    pub fn month_days(&self) -> &[u32] {
+        &self.by_month_day
+    }
+
+    /// Returns the moment after which the recurrence ends, if any.
+    pub fn until_date(&self) -> Option<&DateTime<Utc>> {
+        self.until.as_ref()
+    }
+
+    /// Returns the number of occurrences left, including the current one, if limited.
+    pub fn remaining_count(&self) -> Option<u32> {
+        self.count
+    }
+
+    /// Returns what the next occurrence is computed from.
+    pub fn base(&self) -> RecurrenceBase {
+        self.base
+    }
+
+    /// Returns the rule of the occurrence following this one, or `None` if this occurrence is
+    /// the last one by count.
+    pub(crate) fn successor(&self) -> Option<Self> {
+        match self.count {
+            Some(count) if count <= 1 => None,
+            count => Some(Self {
+                count: count.map(|count| count - 1),
+                ..self.clone()
+            }),
+        }
+    }
+
+    /// Checks that the parts of the rule fit together.
+    pub fn validate(&self) -> Result<(), InvalidRecurrence> {
+        if self.interval == 0 {
+            return Err(InvalidRecurrence("the interval must be at least 1"));
+        }
+        if self.interval > Self::MAX_INTERVAL {
+            return Err(InvalidRecurrence("the interval must be at most 1000"));
+        }
+        if !self.by_weekday.is_empty()
+            && !matches!(self.frequency, Frequency::Daily | Frequency::Weekly)
+        {
+            return Err(InvalidRecurrence(
+                "weekdays can only restrict daily or weekly rules",
+            ));
+        }
+        if !self.by_month_day.is_empty() && self.frequency != Frequency::Monthly {
+            return Err(InvalidRecurrence(
+                "days of the month can only be used by monthly rules",
+            ));
+        }
+        if self.by_month_day.iter().any(|day| !(1..=31).contains(day)) {
+            return Err(InvalidRecurrence(
+                "days of the month must lie between 1 and 31",
+            ));
+        }
+        if self.count == Some(0) {
+            return Err(InvalidRecurrence("the count must be at least 1"));
+        }
+        if self.count.is_some() && self.until.is_some() {
+            return Err(InvalidRecurrence(
+                "a rule can't end both by count and by date",
+            ));
+        }
+        Ok(())
+    }
+
+    /// Returns the first occurrence after `after`, keeping its time of day.
+    ///
+    /// Returns `None` if the rule ends before that, either by its `until` date or because no
+    /// further date matches the rule or lies within the supported range of dates.
+    /// The `count` is not considered here, see [ActionRecurrence::remaining_count].
+    pub fn next_after(&self, after: DateTime<Utc>) -> Option<DateTime<Utc>> {
+        let date = after.date_naive();
+        let next_date = match self.frequency {
+            Frequency::Daily => self.next_daily(date),
+            Frequency::Weekly => self.next_weekly(date),
+            Frequency::Monthly => self.next_monthly(date),
+            Frequency::Yearly => {
+                date.checked_add_months(Months::new(self.interval.checked_mul(12)?))
+            }
+        }?;
+        let next = NaiveDateTime::new(next_date, after.time()).and_utc();
+        match self.until {
+            Some(until) if next > until => None,
+            _ => Some(next),
+        }
+    }
+
+    /// Returns the rule in its textual `RRULE` form, e.g. `FREQ=WEEKLY;INTERVAL=2;BYDAY=MO`.
+    ///
+    /// The [RecurrenceBase] is not part of it.
+    pub fn to_rrule(&self) -> String {
+        let mut parts = vec![format!("FREQ={}", self.frequency.as_str())];
+        if self.interval != 1 {
+            parts.push(format!("INTERVAL={}", self.interval));
+        }
+        if !self.by_weekday.is_empty() {
+            let days: Vec<_> = self
+                .by_weekday
+                .iter()
+                .map(|day| weekday_code(*day))
+                .collect();
+            parts.push(format!("BYDAY={}", days.join(",")));
+        }
+        if !self.by_month_day.is_empty() {
+            let days: Vec<_> = self.by_month_day.iter().map(u32::to_string).collect();
+            parts.push(format!("BYMONTHDAY={}", days.join(",")));
+        }
+        if let Some(until) = self.until {
+            parts.push(format!("UNTIL={}", until.format("%Y%m%dT%H%M%SZ")));
+        }
+        if let Some(count) = self.count {
+            parts.push(format!("COUNT={count}"));
+        }
+        parts.join(";")
+    }
+
+    /// Parses a rule from its textual `RRULE` form, see [ActionRecurrence::to_rrule].
+    pub fn from_rrule(rrule: &str, base: RecurrenceBase) -> Result<Self, InvalidRecurrence> {
+        let mut frequency = None;
+        let mut recurrence = ActionRecurrence::new(Frequency::Daily).based_on(base);
+        for part in rrule.split(';').filter(|part| !part.is_empty()) {
+            let (key, value) = part
+                .split_once('=')
+                .ok_or(InvalidRecurrence("every part must look like KEY=VALUE"))?;
+            match key {
+                "FREQ" => {
+                    frequency = Some(match value {
+                        "DAILY" => Frequency::Daily,
+                        "WEEKLY" => Frequency::Weekly,
+                        "MONTHLY" => Frequency::Monthly,
+                        "YEARLY" => Frequency::Yearly,
+                        _ => return Err(InvalidRecurrence("unknown FREQ")),
+                    })
+                }
+                "INTERVAL" => {
+                    recurrence.interval = value
+                        .parse()
+                        .map_err(|_| InvalidRecurrence("INTERVAL must be a number"))?
+                }
+                "BYDAY" => {
+                    let days = value
+                        .split(',')
+                        .map(parse_weekday)
+                        .collect::<Option<Vec<_>>>()
+                        .ok_or(InvalidRecurrence("unknown weekday in BYDAY"))?;
+                    recurrence = recurrence.with_weekdays(&days);
+                }
+                "BYMONTHDAY" => {
+                    let days = value
+                        .split(',')
+                        .map(str::parse)
+                        .collect::<Result<Vec<_>, _>>()
+                        .map_err(|_| InvalidRecurrence("BYMONTHDAY must list numbers"))?;
+                    recurrence = recurrence.with_month_days(&days);
+                }
+                "UNTIL" => {
+                    let until = NaiveDateTime::parse_from_str(value, "%Y%m%dT%H%M%SZ")
+                        .map_err(|_| InvalidRecurrence("UNTIL must look like 20240131T120000Z"))?;
+                    recurrence.until = Some(until.and_utc());
+                }
+                "COUNT" => {
+                    recurrence.count = Some(
+                        value
+                            .parse()
+                            .map_err(|_| InvalidRecurrence("COUNT must be a number"))?,
+                    )
+                }
+                _ => return Err(InvalidRecurrence("unsupported rule part")),
+            }
+        }
+        recurrence.frequency = frequency.ok_or(InvalidRecurrence("FREQ is missing"))?;
+        recurrence.validate()?;
+        Ok(recurrence)
+    }
+
+    /// Steps `interval` days at a time until a day on one of the selected weekdays is found.
+    fn next_daily(&self, date: NaiveDate) -> Option<NaiveDate> {
+        // Weekdays repeat after seven steps, so if none matched by then, none ever will.
+        (1..=7u32)
+            .map(|step| {
+                let days = step.checked_mul(self.interval)?;
+                date.checked_add_days(Days::new(u64::from(days)))
+            })
+            .find(|candidate| {
+                candidate.is_none_or(|candidate| {
+                    self.by_weekday.is_empty() || self.by_weekday.contains(&candidate.weekday())
+                })
+            })?
+    }
+
+    /// Finds the next selected weekday in the current week, or the first one `interval` weeks
+    /// later.
+    fn next_weekly(&self, date: NaiveDate) -> Option<NaiveDate> {
+        let Some(first_weekday) = self.by_weekday.first() else {
+            let days = self.interval.checked_mul(7)?;
+            return date.checked_add_days(Days::new(u64::from(days)));
+        };
+        let weekday = date.weekday().num_days_from_monday();
+        let week_start = date.checked_sub_days(Days::new(u64::from(weekday)))?;
+        let offset = match self
+            .by_weekday
+            .iter()
+            .find(|day| day.num_days_from_monday() > weekday)
+        {
+            Some(later_day) => later_day.num_days_from_monday(),
+            None => self
+                .interval
+                .checked_mul(7)?
+                .checked_add(first_weekday.num_days_from_monday())?,
+        };
+        week_start.checked_add_days(Days::new(u64::from(offset)))
+    }
+
+    /// Finds the next selected day in the current month, or the first existing one in the
+    /// months `interval`, `2 * interval`, ... later.
+    fn next_monthly(&self, date: NaiveDate) -> Option<NaiveDate> {
+        if self.by_month_day.is_empty() {
+            return date.checked_add_months(Months::new(self.interval));
+        }
+        let later_this_month = self
+            .by_month_day
+            .iter()
+            .filter(|day| **day > date.day())
+            .find_map(|day| date.with_day(*day));
+        if later_this_month.is_some() {
+            return later_this_month;
+        }
+        let month_start = date.with_day(1)?;
+        // Every selected day exists in at least 7 of 12 months, so 4 years suffice to find one.
+        (1..=48u32).find_map(|step| {
+            let months = step.checked_mul(self.interval)?;
+            let month = month_start.checked_add_months(Months::new(months))?;
+            self.by_month_day
+                .iter()
+                .find_map(|day| month.with_day(*day))
+        })
+    }
+}
+
+impl std::fmt::Display for ActionRecurrence {
+    /// Nicer formatting for [ActionRecurrence].
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "ActionRecurrence({})", self.to_rrule())
+    }
+}
+
+/// Error returned for recurrence rules that are malformed or don't fit together.
+#[derive(Error, Debug, Clone, PartialEq, Eq)]
+#[error("Invalid recurrence rule: {0}.")]
+pub struct InvalidRecurrence(pub &'static str);
+
+impl From<InvalidRecurrence> for Violation {
+    fn from(err: InvalidRecurrence) -> Self {
+        Violation::Invalid(err.0)
+    }
+}
+
+impl FromStr for ActionRecurrence {
+    type Err = InvalidRecurrence;
+
+    /// Parses a rule following the original schedule, see [ActionRecurrence::from_rrule].
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        ActionRecurrence::from_rrule(s, RecurrenceBase::Schedule)
+    }
+}
+
+/// Returns the two-letter iCalendar code of a weekday.
+fn weekday_code(weekday: Weekday) -> &'static str {
+    match weekday {
+        Weekday::Mon => "MO",
+        Weekday::Tue => "TU",
+        Weekday::Wed => "WE",
+        Weekday::Thu => "TH",
+        Weekday::Fri => "FR",
+        Weekday::Sat => "SA",
+        Weekday::Sun => "SU",
+    }
+}
+
+/// Parses a two-letter iCalendar weekday code.
+fn parse_weekday(code: &str) -> Option<Weekday> {
+    match code {
+        "MO" => Some(Weekday::Mon),
+        "TU" => Some(Weekday::Tue),
+        "WE" => Some(Weekday::Wed),
+        "TH" => Some(Weekday::Thu),
+        "FR" => Some(Weekday::Fri),
+        "SA" => Some(Weekday::Sat),
+        "SU" => Some(Weekday::Sun),
+        _ => None,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use chrono::TimeZone;
+
+    use super::*;
+
+    fn at(year: i32, month: u32, day: u32) -> DateTime<Utc> {
+        Utc.with_ymd_and_hms(year, month, day, 9, 30, 0).unwrap()
+    }
+
+    #[test]
+    fn test_daily() {
+        let rule = ActionRecurrence::new(Frequency::Daily).with_interval(3);
+        assert_eq!(rule.next_after(at(2024, 2, 27)), Some(at(2024, 3, 1)));
+    }
+
+    #[test]
+    fn test_daily_on_weekdays() {
+        let rule = ActionRecurrence::new(Frequency::Daily).with_weekdays(&[
+            Weekday::Mon,
+            Weekday::Tue,
+            Weekday::Wed,
+            Weekday::Thu,
+            Weekday::Fri,
+        ]);
+        // Friday -> Monday
+        assert_eq!(rule.next_after(at(2024, 3, 1)), Some(at(2024, 3, 4)));
+
+        let never = ActionRecurrence::new(Frequency::Daily)
+            .with_interval(7)
+            .with_weekdays(&[Weekday::Tue]);
+        // Every 7 days from a Monday only ever hits Mondays.
+        assert_eq!(never.next_after(at(2024, 3, 4)), None);
+    }
+
+    #[test]
+    fn test_weekly() {
+        let rule = ActionRecurrence::new(Frequency::Weekly).with_interval(2);
+        assert_eq!(rule.next_after(at(2024, 3, 4)), Some(at(2024, 3, 18)));
+    }
+
+    #[test]
+    fn test_weekly_on_weekdays() {
+        let rule = ActionRecurrence::new(Frequency::Weekly)
+            .with_interval(2)
+            .with_weekdays(&[Weekday::Thu, Weekday::Mon]);
+        // Monday -> Thursday of the same week
+        assert_eq!(rule.next_after(at(2024, 3, 4)), Some(at(2024, 3, 7)));
+        // Thursday -> Monday two weeks later
+        assert_eq!(rule.next_after(at(2024, 3, 7)), Some(at(2024, 3, 18)));
+    }
+
+    #[test]
+    fn test_monthly_clamps_to_month_end() {
+        let rule = ActionRecurrence::new(Frequency::Monthly);
+        assert_eq!(rule.next_after(at(2024, 1, 31)), Some(at(2024, 2, 29)));
+    }
+
+    #[test]
+    fn test_monthly_on_month_days() {
+        let rule = ActionRecurrence::new(Frequency::Monthly).with_month_days(&[15, 31]);
+        assert_eq!(rule.next_after(at(2024, 3, 10)), Some(at(2024, 3, 15)));
+        assert_eq!(rule.next_after(at(2024, 3, 15)), Some(at(2024, 3, 31)));
+        // April has no 31st.
+        assert_eq!(rule.next_after(at(2024, 4, 15)), Some(at(2024, 5, 15)));
+    }
+
+    #[test]
+    fn test_yearly() {
+        let rule = ActionRecurrence::new(Frequency::Yearly);
+        assert_eq!(rule.next_after(at(2024, 2, 29)), Some(at(2025, 2, 28)));
+    }
+
+    #[test]
+    fn test_huge_intervals_end_the_recurrence() {
+        let after = at(2024, 3, 4);
+        let rule = |frequency| ActionRecurrence::new(frequency).with_interval(u32::MAX);
+        assert_eq!(rule(Frequency::Daily).next_after(after), None);
+        assert_eq!(rule(Frequency::Weekly).next_after(after), None);
+        assert_eq!(
+            rule(Frequency::Weekly)
+                .with_weekdays(&[Weekday::Mon])
+                .next_after(after),
+            None
+        );
+        assert_eq!(rule(Frequency::Monthly).next_after(after), None);
+        assert_eq!(
+            rule(Frequency::Monthly)
+                .with_month_days(&[1])
+                .next_after(after),
+            None
+        );
+        assert_eq!(rule(Frequency::Yearly).next_after(after), None);
+    }
+
+    #[test]
+    fn test_until() {
+        let rule = ActionRecurrence::new(Frequency::Weekly).until(at(2024, 3, 10));
+        assert_eq!(rule.next_after(at(2024, 3, 1)), Some(at(2024, 3, 8)));
+        assert_eq!(rule.next_after(at(2024, 3, 8)), None);
+    }
+
+    #[test]
+    fn test_successor_counts_down() {
+        let rule = ActionRecurrence::new(Frequency::Daily).count(2);
+        let successor = rule.successor().unwrap();
+        assert_eq!(successor.remaining_count(), Some(1));
+        assert_eq!(successor.successor(), None);
+        assert!(
+            ActionRecurrence::new(Frequency::Daily)
+                .successor()
+                .is_some()
+        );
+    }
+
+    #[test]
+    fn test_rrule_round_trip() {
+        let rule = ActionRecurrence::new(Frequency::Weekly)
+            .with_interval(2)
+            .with_weekdays(&[Weekday::Fri, Weekday::Mon])
+            .until(at(2024, 12, 31))
+            .based_on(RecurrenceBase::Completion);
+        let rrule = rule.to_rrule();
+        assert_eq!(
+            rrule,
+            "FREQ=WEEKLY;INTERVAL=2;BYDAY=MO,FR;UNTIL=20241231T093000Z"
+        );
+        assert_eq!(
+            ActionRecurrence::from_rrule(&rrule, RecurrenceBase::Completion),
+            Ok(rule)
+        );
+    }
+
+    #[test]
+    fn test_invalid_rules() {
+        assert!("INTERVAL=2".parse::<ActionRecurrence>().is_err());
+        assert!("FREQ=HOURLY".parse::<ActionRecurrence>().is_err());
+        assert!("FREQ=DAILY;INTERVAL=0".parse::<ActionRecurrence>().is_err());
+        assert!(
+            "FREQ=DAILY;INTERVAL=1001"
+                .parse::<ActionRecurrence>()
+                .is_err()
+        );
+        assert!(
+            "FREQ=YEARLY;INTERVAL=1000"
+                .parse::<ActionRecurrence>()
+                .is_ok()
+        );
+        assert!("FREQ=YEARLY;BYDAY=MO".parse::<ActionRecurrence>().is_err());
+        assert!(
+            "FREQ=MONTHLY;BYMONTHDAY=32"
+                .parse::<ActionRecurrence>()
+                .is_err()
+        );
+        assert!(
+            "FREQ=DAILY;COUNT=2;UNTIL=20240101T000000Z"
+                .parse::<ActionRecurrence>()
+                .is_err()
+        );
+        assert_eq!(
+            "FREQ=MONTHLY;BYMONTHDAY=1,15;COUNT=3".parse::<ActionRecurrence>(),
+            Ok(ActionRecurrence::new(Frequency::Monthly)
+                .with_month_days(&[15, 1])
+                .count(3))
+        );
+    }
+}
@@ -0,0 +1,83 @@
+//! A GTD area of focus.
+//!
+//! This model contains the [Area] type.
+//! Areas of focus, or areas of responsibility, are the spheres of life and work to be maintained,
+//! like `Health`, `Finance` or `Team`. They sit one horizon above projects: each
+//! [Project](crate::model::project::Project) and each standalone
+//! [Action](crate::model::action::Action) can belong to one area.
+
+use core::fmt;
+
+use crate::model::area::{area_id::AreaId, area_name::AreaName};
+
+// The id of an area.
+pub mod area_id;
+// The name of an area.
+pub mod area_name;
+
+/// A GTD area of focus.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Area {
+    area_id: AreaId,
+    area_name: AreaName,
+}
+
+impl Area {
+    /// Returns a new [Area] instance.
+    pub(crate) fn new(area_id: AreaId, area_name: AreaName) -> Self {
+        Area { area_id, area_name }
+    }
+
+    /// Returns a reference to the area's [AreaId].
+    pub fn area_id(&self) -> &AreaId {
+        &self.area_id
+    }
+
+    /// Returns a reference to the area's [AreaName].
+    pub fn area_name(&self) -> &AreaName {
+        &self.area_name
+    }
+
+    /// Replaces the area's [AreaName].
+    pub(crate) fn set_area_name(&mut self, area_name: AreaName) {
+        self.area_name = area_name;
+    }
+}
+
+// Nicer formatting for areas when printed to the console.
+impl fmt::Display for Area {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        writeln!(f)?;
+        writeln!(f, "Area {{")?;
+        writeln!(f, "    {}", self.area_id)?;
+        writeln!(f, "    {}", self.area_name)?;
+        writeln!(f, "}}")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_area_getters() {
+        let my_area_id = AreaId::new();
+        let my_area_name = AreaName::new("Health").unwrap();
+        let my_area = Area::new(my_area_id.clone(), my_area_name.clone());
+        assert_eq!(my_area.area_id(), &my_area_id);
+        assert_eq!(my_area.area_name(), &my_area_name);
+    }
+}
+
+#[cfg(test)]
+pub mod test_utils {
+
+    use super::*;
+
+    pub fn dummy_area() -> Area {
+        Area {
+            area_id: AreaId::new(),
+            area_name: AreaName::from_stored("Dummy Area"),
+        }
+    }
+}
@@ -0,0 +1,55 @@
+//! Id's of [`Area`s](super::Area).
+//!
+//! This module contains the [AreaId] struct.
+//! It contains a *uuid*, a globally unique identifier.
+
+use uuid::Uuid;
+
+use crate::model::common::id::Id;
+
+/// The id of an [Area](crate::model::area::Area).
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub struct AreaId(Id);
+
+impl AreaId {
+    /// Creates a new [AreaId] instance.
+    pub fn new() -> Self {
+        Self(Id::new())
+    }
+
+    /// Creates an [AreaId] instance from an existing [uuid::Uuid].
+    pub fn from_uuid(uuid: Uuid) -> Self {
+        Self(Id::from_uuid(uuid))
+    }
+
+    /// Returns the underlying [uuid::Uuid].
+    pub fn uuid(&self) -> &Uuid {
+        self.0.uuid()
+    }
+}
+
+impl Default for AreaId {
+    /// Default constructor for [AreaId].
+    fn default() -> Self {
+        AreaId::new()
+    }
+}
+
+impl std::fmt::Display for AreaId {
+    /// Nicer formatting for [AreaId].
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "AreaId({})", self.0)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn ids_should_be_different() {
+        let id_1 = AreaId::new();
+        let id_2 = AreaId::new();
+        assert_ne!(id_1, id_2);
+    }
+}
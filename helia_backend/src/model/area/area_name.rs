@@ -0,0 +1,62 @@
+//! Names of [`Area`s](super::Area).
+//!
+//! This module contains the [AreaName] struct.
+
+use crate::model::validation::{self, Violation};
+
+/// The name of an [Area](crate::model::area::Area), like `Health` or `Finance`.
+///
+/// Names are trimmed, non-empty, at most [AreaName::MAX_LEN] characters long and contain no
+/// control characters.
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub struct AreaName(String);
+
+impl AreaName {
+    /// The maximum number of characters of an [AreaName].
+    pub const MAX_LEN: usize = 100;
+
+    /// Creates a new [AreaName] instance from the trimmed `area_name`.
+    ///
+    /// Fails if the name breaks one of the rules described at [AreaName].
+    pub fn new(area_name: &str) -> Result<Self, Violation> {
+        let area_name = validation::single_line_text(area_name, Self::MAX_LEN)?;
+        Ok(AreaName(String::from(area_name)))
+    }
+
+    /// Creates an [AreaName] instance without validating it.
+    ///
+    /// Only meant for names that were already stored.
+    pub(crate) fn from_stored(area_name: &str) -> Self {
+        AreaName(String::from(area_name))
+    }
+
+    /// Returns the name of the area as a string.
+    pub fn as_str(&self) -> &str {
+        &self.0
+    }
+}
+
+impl std::fmt::Display for AreaName {
+    /// Nicer formatting for [AreaName].
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "AreaName({})", self.0)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_name_is_trimmed() {
+        let area_name = AreaName::new("  Health ").unwrap();
+        assert_eq!(area_name.as_str(), "Health");
+        assert_eq!(format!("{area_name}"), "AreaName(Health)");
+    }
+
+    #[test]
+    fn test_invalid_names_are_rejected() {
+        assert_eq!(AreaName::new(""), Err(Violation::Empty));
+        assert!(AreaName::new(&"a".repeat(AreaName::MAX_LEN + 1)).is_err());
+    }
+}
@@ -19,6 +19,11 @@ impl Date {
         Self(Utc::now())
     }
 
+    /// Creates a [Date] instance from an existing [DateTime].
+    pub fn from_value(value: DateTime<Utc>) -> Self {
+        Self(value)
+    }
+
     /// Returns the [DateTime] inside this [Date] instance.
     pub fn value(&self) -> &DateTime<Utc> {
         &self.0
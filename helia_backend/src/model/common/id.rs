@@ -27,6 +27,11 @@ impl Id {
         }
     }
 
+    /// Creates an [Id] instance from an existing [uuid::Uuid].
+    pub fn from_uuid(uuid: Uuid) -> Self {
+        Self { uuid }
+    }
+
     /// Returns the underlying [uuid::Uuid].
     pub fn uuid(&self) -> &Uuid {
         &self.uuid
@@ -0,0 +1,85 @@
+//! A GTD context.
+//!
+//! This model contains the [Context] type.
+//! A context describes where you are or what tool you have at hand, like `@home`, `@computer` or
+//! `@phone`. Each [Action](crate::model::action::Action) can be linked to any number of
+//! contexts, so next actions can be filtered by what can actually be done right now.
+
+use core::fmt;
+
+use crate::model::context::{context_id::ContextId, context_name::ContextName};
+
+// The id of a context.
+pub mod context_id;
+// The name of a context.
+pub mod context_name;
+
+/// A GTD context.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Context {
+    context_id: ContextId,
+    context_name: ContextName,
+}
+
+impl Context {
+    /// Returns a new [Context] instance.
+    pub(crate) fn new(context_id: ContextId, context_name: ContextName) -> Self {
+        Context {
+            context_id,
+            context_name,
+        }
+    }
+
+    /// Returns a reference to the context's [ContextId].
+    pub fn context_id(&self) -> &ContextId {
+        &self.context_id
+    }
+
+    /// Returns a reference to the context's [ContextName].
+    pub fn context_name(&self) -> &ContextName {
+        &self.context_name
+    }
+
+    /// Replaces the context's [ContextName].
+    pub(crate) fn set_context_name(&mut self, context_name: ContextName) {
+        self.context_name = context_name;
+    }
+}
+
+// Nicer formatting for contexts when printed to the console.
+impl fmt::Display for Context {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        writeln!(f)?;
+        writeln!(f, "Context {{")?;
+        writeln!(f, "    {}", self.context_id)?;
+        writeln!(f, "    {}", self.context_name)?;
+        writeln!(f, "}}")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_context_getters() {
+        let my_context_id = ContextId::new();
+        let my_context_name = ContextName::new("@phone");
+        let my_context = Context::new(my_context_id.clone(), my_context_name.clone());
+        assert_eq!(my_context.context_id(), &my_context_id);
+        assert_eq!(my_context.context_name(), &my_context_name);
+    }
+}
+
+#[cfg(test)]
+pub mod test_utils {
+
+    use super::*;
+
+    pub fn dummy_context() -> Context {
+        Context {
+            context_id: ContextId::new(),
+            context_name: ContextName::new("@dummy"),
+        }
+    }
+}
@@ -0,0 +1,55 @@
+//! Id's of [`Context`s](super::Context).
+//!
+//! This module contains the [ContextId] struct.
+//! It contains a *uuid*, a globally unique identifier.
+
+use uuid::Uuid;
+
+use crate::model::common::id::Id;
+
+/// The id of a [Context](crate::model::context::Context).
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub struct ContextId(Id);
+
+impl ContextId {
+    /// Creates a new [ContextId] instance.
+    pub fn new() -> Self {
+        Self(Id::new())
+    }
+
+    /// Creates a [ContextId] instance from an existing [uuid::Uuid].
+    pub fn from_uuid(uuid: Uuid) -> Self {
+        Self(Id::from_uuid(uuid))
+    }
+
+    /// Returns the underlying [uuid::Uuid].
+    pub fn uuid(&self) -> &Uuid {
+        self.0.uuid()
+    }
+}
+
+impl Default for ContextId {
+    /// Default constructor for [ContextId].
+    fn default() -> Self {
+        ContextId::new()
+    }
+}
+
+impl std::fmt::Display for ContextId {
+    /// Nicer formatting for [ContextId].
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "ContextId({})", self.0)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn ids_should_be_different() {
+        let id_1 = ContextId::new();
+        let id_2 = ContextId::new();
+        assert_ne!(id_1, id_2);
+    }
+}
@@ -0,0 +1,45 @@
+//! Names of [`Context`s](super::Context).
+//!
+//! This module contains the [ContextName] struct.
+
+/// The name of a [Context](crate::model::context::Context).
+///
+/// By GTD convention, context names start with an `@`, like `@home` or `@phone`.
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub struct ContextName(String);
+
+impl ContextName {
+    /// Creates a new [ContextName] instance.
+    pub fn new(context_name: &str) -> Self {
+        ContextName(String::from(context_name))
+    }
+
+    /// Returns the name as a string.
+    pub fn as_str(&self) -> &str {
+        &self.0
+    }
+}
+
+impl std::fmt::Display for ContextName {
+    /// Nicer formatting for [ContextName].
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "ContextName({})", self.0)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn as_str_returns_string() {
+        let context_name = ContextName::new("@home");
+        assert_eq!(context_name.as_str(), "@home");
+    }
+
+    #[test]
+    fn test_display() {
+        let context_name = ContextName::new("@home");
+        assert_eq!(format!("{context_name}"), "ContextName(@home)");
+    }
+}
@@ -0,0 +1,109 @@
+//! A GTD inbox item.
+//!
+//! This model contains the [InboxItem] type.
+//! In GTD, everything that has your attention is first captured into an inbox, without deciding
+//! what it means yet. Later, each inbox item is processed: it is turned into an
+//! [Action](crate::model::action::Action), a [Project](crate::model::project::Project),
+//! a someday/maybe entry, a [ReferenceItem](crate::model::reference_item::ReferenceItem), or it
+//! is thrown away.
+
+use core::fmt;
+
+use crate::model::inbox_item::{
+    inbox_item_capture_date::InboxItemCaptureDate, inbox_item_id::InboxItemId,
+    inbox_item_text::InboxItemText,
+};
+
+// The id of an inbox item.
+pub mod inbox_item_id;
+// The captured text of an inbox item.
+pub mod inbox_item_text;
+// The capture date of an inbox item.
+pub mod inbox_item_capture_date;
+
+/// A GTD inbox item.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct InboxItem {
+    inbox_item_id: InboxItemId,
+    inbox_item_text: InboxItemText,
+    inbox_item_capture_date: InboxItemCaptureDate,
+}
+
+impl InboxItem {
+    /// Returns a new [InboxItem] instance.
+    pub(crate) fn new(
+        inbox_item_id: InboxItemId,
+        inbox_item_text: InboxItemText,
+        inbox_item_capture_date: InboxItemCaptureDate,
+    ) -> Self {
+        InboxItem {
+            inbox_item_id,
+            inbox_item_text,
+            inbox_item_capture_date,
+        }
+    }
+
+    /// Returns a reference to the inbox item's [InboxItemId].
+    pub fn inbox_item_id(&self) -> &InboxItemId {
+        &self.inbox_item_id
+    }
+
+    /// Returns a reference to the inbox item's [InboxItemText].
+    pub fn inbox_item_text(&self) -> &InboxItemText {
+        &self.inbox_item_text
+    }
+
+    /// Returns a reference to the inbox item's [InboxItemCaptureDate].
+    pub fn inbox_item_capture_date(&self) -> &InboxItemCaptureDate {
+        &self.inbox_item_capture_date
+    }
+}
+
+// Nicer formatting for inbox items when printed to the console.
+impl fmt::Display for InboxItem {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        writeln!(f)?;
+        writeln!(f, "InboxItem {{")?;
+        writeln!(f, "    {}", self.inbox_item_id)?;
+        writeln!(f, "    {}", self.inbox_item_text)?;
+        writeln!(f, "    {}", self.inbox_item_capture_date)?;
+        writeln!(f, "}}")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_inbox_item_getters() {
+        let my_inbox_item_id = InboxItemId::new();
+        let my_inbox_item_text = InboxItemText::new("call dentist?").unwrap();
+        let my_inbox_item_capture_date = InboxItemCaptureDate::now();
+        let my_inbox_item = InboxItem::new(
+            my_inbox_item_id.clone(),
+            my_inbox_item_text.clone(),
+            my_inbox_item_capture_date.clone(),
+        );
+        assert_eq!(my_inbox_item.inbox_item_id(), &my_inbox_item_id);
+        assert_eq!(my_inbox_item.inbox_item_text(), &my_inbox_item_text);
+        assert_eq!(
+            my_inbox_item.inbox_item_capture_date(),
+            &my_inbox_item_capture_date
+        );
+    }
+}
+
+#[cfg(test)]
+pub mod test_utils {
+
+    use super::*;
+
+    pub fn dummy_inbox_item() -> InboxItem {
+        InboxItem {
+            inbox_item_id: InboxItemId::new(),
+            inbox_item_text: InboxItemText::new("Dummy Inbox Item").unwrap(),
+            inbox_item_capture_date: InboxItemCaptureDate::now(),
+        }
+    }
+}
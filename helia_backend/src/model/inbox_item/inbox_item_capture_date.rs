@@ -0,0 +1,59 @@
+//! Capture dates of [`InboxItem`s](super::InboxItem).
+//!
+//! This module contains the [InboxItemCaptureDate] struct.
+//! It represents the date an inbox item was captured in UTC.
+
+use chrono::{DateTime, Utc};
+
+use crate::model::common::date::Date;
+
+/// The date and time an [InboxItem](crate::model::inbox_item::InboxItem) was captured.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct InboxItemCaptureDate(Date);
+
+impl InboxItemCaptureDate {
+    /// Creates a new [InboxItemCaptureDate] instance of the current time.
+    pub fn now() -> Self {
+        Self(Date::now())
+    }
+
+    /// Creates an [InboxItemCaptureDate] instance from an existing [DateTime].
+    pub fn from_value(value: DateTime<Utc>) -> Self {
+        Self(Date::from_value(value))
+    }
+
+    /// Returns the [DateTime] inside this [InboxItemCaptureDate] instance.
+    pub fn value(&self) -> &DateTime<Utc> {
+        self.0.value()
+    }
+}
+
+impl Default for InboxItemCaptureDate {
+    fn default() -> Self {
+        Self::now()
+    }
+}
+
+impl std::fmt::Display for InboxItemCaptureDate {
+    /// Nicer formatting for [InboxItemCaptureDate].
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "InboxItemCaptureDate({})", self.0)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_display() {
+        let inbox_item_capture_date = InboxItemCaptureDate::now();
+        assert_eq!(
+            format!("{inbox_item_capture_date}"),
+            format!(
+                "InboxItemCaptureDate(Date({}))",
+                inbox_item_capture_date.value()
+            )
+        );
+    }
+}
@@ -0,0 +1,55 @@
+//! Id's of [`InboxItem`s](super::InboxItem).
+//!
+//! This module contains the [InboxItemId] struct.
+//! It contains a *uuid*, a globally unique identifier.
+
+use uuid::Uuid;
+
+use crate::model::common::id::Id;
+
+/// The id of an [InboxItem](crate::model::inbox_item::InboxItem).
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub struct InboxItemId(Id);
+
+impl InboxItemId {
+    /// Creates a new [InboxItemId] instance.
+    pub fn new() -> Self {
+        Self(Id::new())
+    }
+
+    /// Creates an [InboxItemId] instance from an existing [uuid::Uuid].
+    pub fn from_uuid(uuid: Uuid) -> Self {
+        Self(Id::from_uuid(uuid))
+    }
+
+    /// Returns the underlying [uuid::Uuid].
+    pub fn uuid(&self) -> &Uuid {
+        self.0.uuid()
+    }
+}
+
+impl Default for InboxItemId {
+    /// Default constructor for [InboxItemId].
+    fn default() -> Self {
+        InboxItemId::new()
+    }
+}
+
+impl std::fmt::Display for InboxItemId {
+    /// Nicer formatting for [InboxItemId].
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "InboxItemId({})", self.0)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn ids_should_be_different() {
+        let id_1 = InboxItemId::new();
+        let id_2 = InboxItemId::new();
+        assert_ne!(id_1, id_2);
+    }
+}
@@ -0,0 +1,68 @@
+//! Texts of [`InboxItem`s](super::InboxItem).
+//!
+//! This module contains the [InboxItemText] struct.
+
+use crate::model::validation::{self, Violation};
+
+/// The text of an [InboxItem](crate::model::inbox_item::InboxItem).
+///
+/// This is the raw, unclarified text that was captured. Texts are trimmed, non-empty, at most
+/// [InboxItemText::MAX_LEN] characters long and contain no control characters.
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub struct InboxItemText(String);
+
+impl InboxItemText {
+    /// The maximum number of characters of an [InboxItemText].
+    pub const MAX_LEN: usize = 500;
+
+    /// Creates a new [InboxItemText] instance from the trimmed `inbox_item_text`.
+    ///
+    /// Fails if the text breaks one of the rules described at [InboxItemText].
+    pub fn new(inbox_item_text: &str) -> Result<Self, Violation> {
+        let inbox_item_text = validation::single_line_text(inbox_item_text, Self::MAX_LEN)?;
+        Ok(InboxItemText(String::from(inbox_item_text)))
+    }
+
+    /// Creates an [InboxItemText] instance without validating it.
+    ///
+    /// Only meant for texts that were already stored.
+    pub(crate) fn from_stored(inbox_item_text: &str) -> Self {
+        InboxItemText(String::from(inbox_item_text))
+    }
+
+    /// Returns the text as a string.
+    pub fn as_str(&self) -> &str {
+        &self.0
+    }
+}
+
+impl std::fmt::Display for InboxItemText {
+    /// Nicer formatting for [InboxItemText].
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "InboxItemText({})", self.0)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn as_str_returns_string() {
+        let inbox_item_text = InboxItemText::new(" Something ").unwrap();
+        assert_eq!(inbox_item_text.as_str(), "Something");
+    }
+
+    #[test]
+    fn test_invalid_texts_are_rejected() {
+        assert_eq!(InboxItemText::new(" \t "), Err(Violation::Empty));
+        assert!(InboxItemText::new("Call\nmom").is_err());
+        assert!(InboxItemText::new(&"a".repeat(InboxItemText::MAX_LEN + 1)).is_err());
+    }
+
+    #[test]
+    fn test_display() {
+        let inbox_item_text = InboxItemText::new("Something").unwrap();
+        assert_eq!(format!("{inbox_item_text}"), "InboxItemText(Something)");
+    }
+}
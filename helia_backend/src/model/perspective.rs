@@ -0,0 +1,114 @@
+//! A saved perspective.
+//!
+//! This model contains the [Perspective] type.
+//! Perspectives are named, saved views on the [`Action`s](crate::model::action::Action), like
+//! `Office, high energy, due this week`. They keep the query selecting their actions, so every
+//! frontend evaluating a perspective shows the same actions.
+
+use core::fmt;
+
+use crate::model::perspective::{
+    perspective_id::PerspectiveId, perspective_name::PerspectiveName,
+    perspective_query::PerspectiveQuery,
+};
+
+// The id of a perspective.
+pub mod perspective_id;
+// The name of a perspective.
+pub mod perspective_name;
+// The query of a perspective.
+pub mod perspective_query;
+
+/// A named, saved query on actions.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Perspective {
+    perspective_id: PerspectiveId,
+    perspective_name: PerspectiveName,
+    perspective_query: PerspectiveQuery,
+}
+
+impl Perspective {
+    /// Returns a new [Perspective] instance.
+    pub(crate) fn new(
+        perspective_id: PerspectiveId,
+        perspective_name: PerspectiveName,
+        perspective_query: PerspectiveQuery,
+    ) -> Self {
+        Perspective {
+            perspective_id,
+            perspective_name,
+            perspective_query,
+        }
+    }
+
+    /// Returns a reference to the perspective's [PerspectiveId].
+    pub fn perspective_id(&self) -> &PerspectiveId {
+        &self.perspective_id
+    }
+
+    /// Returns a reference to the perspective's [PerspectiveName].
+    pub fn perspective_name(&self) -> &PerspectiveName {
+        &self.perspective_name
+    }
+
+    /// Returns a reference to the perspective's [PerspectiveQuery].
+    pub fn perspective_query(&self) -> &PerspectiveQuery {
+        &self.perspective_query
+    }
+
+    /// Replaces the perspective's [PerspectiveName].
+    pub(crate) fn set_perspective_name(&mut self, perspective_name: PerspectiveName) {
+        self.perspective_name = perspective_name;
+    }
+
+    /// Replaces the perspective's [PerspectiveQuery].
+    pub(crate) fn set_perspective_query(&mut self, perspective_query: PerspectiveQuery) {
+        self.perspective_query = perspective_query;
+    }
+}
+
+// Nicer formatting for perspectives when printed to the console.
+impl fmt::Display for Perspective {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        writeln!(f)?;
+        writeln!(f, "Perspective {{")?;
+        writeln!(f, "    {}", self.perspective_id)?;
+        writeln!(f, "    {}", self.perspective_name)?;
+        writeln!(f, "    {}", self.perspective_query)?;
+        writeln!(f, "}}")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_perspective_getters() {
+        let my_perspective_id = PerspectiveId::new();
+        let my_perspective_name = PerspectiveName::new("Office").unwrap();
+        let my_perspective_query = PerspectiveQuery::new("context:@office").unwrap();
+        let my_perspective = Perspective::new(
+            my_perspective_id.clone(),
+            my_perspective_name.clone(),
+            my_perspective_query.clone(),
+        );
+        assert_eq!(my_perspective.perspective_id(), &my_perspective_id);
+        assert_eq!(my_perspective.perspective_name(), &my_perspective_name);
+        assert_eq!(my_perspective.perspective_query(), &my_perspective_query);
+    }
+}
+
+#[cfg(test)]
+pub mod test_utils {
+
+    use super::*;
+
+    pub fn dummy_perspective() -> Perspective {
+        Perspective {
+            perspective_id: PerspectiveId::new(),
+            perspective_name: PerspectiveName::from_stored("Dummy Perspective"),
+            perspective_query: PerspectiveQuery::from_stored("is:open"),
+        }
+    }
+}
@@ -0,0 +1,55 @@
+//! Id's of [`Perspective`s](super::Perspective).
+//!
+//! This module contains the [PerspectiveId] struct.
+//! It contains a *uuid*, a globally unique identifier.
+
+use uuid::Uuid;
+
+use crate::model::common::id::Id;
+
+/// The id of a [Perspective](crate::model::perspective::Perspective).
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub struct PerspectiveId(Id);
+
+impl PerspectiveId {
+    /// Creates a new [PerspectiveId] instance.
+    pub fn new() -> Self {
+        Self(Id::new())
+    }
+
+    /// Creates a [PerspectiveId] instance from an existing [uuid::Uuid].
+    pub fn from_uuid(uuid: Uuid) -> Self {
+        Self(Id::from_uuid(uuid))
+    }
+
+    /// Returns the underlying [uuid::Uuid].
+    pub fn uuid(&self) -> &Uuid {
+        self.0.uuid()
+    }
+}
+
+impl Default for PerspectiveId {
+    /// Default constructor for [PerspectiveId].
+    fn default() -> Self {
+        PerspectiveId::new()
+    }
+}
+
+impl std::fmt::Display for PerspectiveId {
+    /// Nicer formatting for [PerspectiveId].
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "PerspectiveId({})", self.0)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn ids_should_be_different() {
+        let id_1 = PerspectiveId::new();
+        let id_2 = PerspectiveId::new();
+        assert_ne!(id_1, id_2);
+    }
+}
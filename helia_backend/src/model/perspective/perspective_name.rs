@@ -0,0 +1,63 @@
+//! Names of [`Perspective`s](super::Perspective).
+//!
+//! This module contains the [PerspectiveName] struct.
+
+use crate::model::validation::{self, Violation};
+
+/// The name of a [Perspective](crate::model::perspective::Perspective), like `Office` or
+/// `Due this week`.
+///
+/// Names are trimmed, non-empty, at most [PerspectiveName::MAX_LEN] characters long and contain
+/// no control characters.
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub struct PerspectiveName(String);
+
+impl PerspectiveName {
+    /// The maximum number of characters of a [PerspectiveName].
+    pub const MAX_LEN: usize = 100;
+
+    /// Creates a new [PerspectiveName] instance from the trimmed `perspective_name`.
+    ///
+    /// Fails if the name breaks one of the rules described at [PerspectiveName].
+    pub fn new(perspective_name: &str) -> Result<Self, Violation> {
+        let perspective_name = validation::single_line_text(perspective_name, Self::MAX_LEN)?;
+        Ok(PerspectiveName(String::from(perspective_name)))
+    }
+
+    /// Creates a [PerspectiveName] instance without validating it.
+    ///
+    /// Only meant for names that were already stored.
+    pub(crate) fn from_stored(perspective_name: &str) -> Self {
+        PerspectiveName(String::from(perspective_name))
+    }
+
+    /// Returns the name of the perspective as a string.
+    pub fn as_str(&self) -> &str {
+        &self.0
+    }
+}
+
+impl std::fmt::Display for PerspectiveName {
+    /// Nicer formatting for [PerspectiveName].
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "PerspectiveName({})", self.0)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_name_is_trimmed() {
+        let perspective_name = PerspectiveName::new("  Office ").unwrap();
+        assert_eq!(perspective_name.as_str(), "Office");
+        assert_eq!(format!("{perspective_name}"), "PerspectiveName(Office)");
+    }
+
+    #[test]
+    fn test_invalid_names_are_rejected() {
+        assert_eq!(PerspectiveName::new(""), Err(Violation::Empty));
+        assert!(PerspectiveName::new(&"a".repeat(PerspectiveName::MAX_LEN + 1)).is_err());
+    }
+}
@@ -0,0 +1,67 @@
+//! Queries of [`Perspective`s](super::Perspective).
+//!
+//! This module contains the [PerspectiveQuery] struct.
+
+use crate::model::validation::{self, Violation};
+
+/// The query of a [Perspective](crate::model::perspective::Perspective), in the textual form
+/// of an [ActionQuery](crate::core::requests::ActionQuery), like `context:@office due<+7d`.
+///
+/// The text is kept rather than the parsed query, so relative dates like `today` are resolved
+/// whenever the perspective is evaluated.
+/// Queries are trimmed, non-empty, at most [PerspectiveQuery::MAX_LEN] characters long and
+/// contain no control characters. Their syntax is checked when parsing them.
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub struct PerspectiveQuery(String);
+
+impl PerspectiveQuery {
+    /// The maximum number of characters of a [PerspectiveQuery].
+    pub const MAX_LEN: usize = 1000;
+
+    /// Creates a new [PerspectiveQuery] instance from the trimmed `perspective_query`.
+    ///
+    /// Fails if the query breaks one of the rules described at [PerspectiveQuery].
+    pub fn new(perspective_query: &str) -> Result<Self, Violation> {
+        let perspective_query = validation::single_line_text(perspective_query, Self::MAX_LEN)?;
+        Ok(PerspectiveQuery(String::from(perspective_query)))
+    }
+
+    /// Creates a [PerspectiveQuery] instance without validating it.
+    ///
+    /// Only meant for queries that were already stored.
+    pub(crate) fn from_stored(perspective_query: &str) -> Self {
+        PerspectiveQuery(String::from(perspective_query))
+    }
+
+    /// Returns the query as a string.
+    pub fn as_str(&self) -> &str {
+        &self.0
+    }
+}
+
+impl std::fmt::Display for PerspectiveQuery {
+    /// Nicer formatting for [PerspectiveQuery].
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "PerspectiveQuery({})", self.0)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_query_is_trimmed() {
+        let perspective_query = PerspectiveQuery::new(" is:open tag:work ").unwrap();
+        assert_eq!(perspective_query.as_str(), "is:open tag:work");
+    }
+
+    #[test]
+    fn test_invalid_queries_are_rejected() {
+        assert_eq!(PerspectiveQuery::new("  "), Err(Violation::Empty));
+        assert_eq!(
+            PerspectiveQuery::new("is:open\ntag:work"),
+            Err(Violation::ControlCharacter)
+        );
+    }
+}
@@ -0,0 +1,201 @@
+//! A GTD project.
+//!
+//! This model contains the [Project] type.
+//! A project in GTD is any desired outcome that requires more than one action to achieve.
+//! [`Action`s](crate::model::action::Action) can belong to a project.
+
+use core::fmt;
+
+use crate::model::{
+    area::area_id::AreaId,
+    project::{
+        project_create_date::ProjectCreateDate, project_id::ProjectId, project_name::ProjectName,
+        project_ordering::ProjectOrdering, project_review_date::ProjectReviewDate,
+        project_status::ProjectStatus,
+    },
+};
+
+// The id of a project.
+pub mod project_id;
+// The name of a project.
+pub mod project_name;
+// The creation date of a project.
+pub mod project_create_date;
+// The status of a project.
+pub mod project_status;
+// The date a project was last reviewed.
+pub mod project_review_date;
+// The ordering of a project's actions.
+pub mod project_ordering;
+
+/// A GTD Project.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Project {
+    project_id: ProjectId,
+    project_name: ProjectName,
+    project_create_date: ProjectCreateDate,
+    project_status: ProjectStatus,
+    project_ordering: ProjectOrdering,
+    project_review_date: Option<ProjectReviewDate>,
+    area_id: Option<AreaId>,
+    someday: bool,
+}
+
+impl Project {
+    /// Returns a new [Project] instance.
+    pub(crate) fn new(
+        project_id: ProjectId,
+        project_name: ProjectName,
+        project_create_date: ProjectCreateDate,
+        project_status: ProjectStatus,
+    ) -> Self {
+        Project {
+            project_id,
+            project_name,
+            project_create_date,
+            project_status,
+            project_ordering: ProjectOrdering::default(),
+            project_review_date: None,
+            area_id: None,
+            someday: false,
+        }
+    }
+
+    /// Returns a reference to the project's [ProjectId].
+    pub fn project_id(&self) -> &ProjectId {
+        &self.project_id
+    }
+
+    /// Returns a reference to the project's [ProjectName].
+    pub fn project_name(&self) -> &ProjectName {
+        &self.project_name
+    }
+
+    /// Returns a reference to the project's [ProjectCreateDate].
+    pub fn project_create_date(&self) -> &ProjectCreateDate {
+        &self.project_create_date
+    }
+
+    /// Returns the project's [ProjectStatus].
+    pub fn project_status(&self) -> ProjectStatus {
+        self.project_status
+    }
+
+    /// Returns the project's [ProjectOrdering].
+    pub fn project_ordering(&self) -> ProjectOrdering {
+        self.project_ordering
+    }
+
+    /// Returns a reference to the project's [ProjectReviewDate], if it was ever reviewed.
+    pub fn project_review_date(&self) -> Option<&ProjectReviewDate> {
+        self.project_review_date.as_ref()
+    }
+
+    /// Returns a reference to the [AreaId] of the area the project belongs to, if any.
+    pub fn area_id(&self) -> Option<&AreaId> {
+        self.area_id.as_ref()
+    }
+
+    /// Returns `true` if the project is on the someday/maybe list rather than committed to.
+    pub fn is_someday(&self) -> bool {
+        self.someday
+    }
+
+    /// Replaces the project's [ProjectName].
+    pub(crate) fn set_project_name(&mut self, project_name: ProjectName) {
+        self.project_name = project_name;
+    }
+
+    /// Replaces the project's [ProjectStatus].
+    pub(crate) fn set_project_status(&mut self, project_status: ProjectStatus) {
+        self.project_status = project_status;
+    }
+
+    /// Replaces the project's [ProjectOrdering].
+    pub(crate) fn set_project_ordering(&mut self, project_ordering: ProjectOrdering) {
+        self.project_ordering = project_ordering;
+    }
+
+    /// Records when the project was last reviewed.
+    pub(crate) fn set_project_review_date(
+        &mut self,
+        project_review_date: Option<ProjectReviewDate>,
+    ) {
+        self.project_review_date = project_review_date;
+    }
+
+    /// Moves the project into the given area, or out of any area if `None` is given.
+    pub(crate) fn set_area_id(&mut self, area_id: Option<AreaId>) {
+        self.area_id = area_id;
+    }
+
+    /// Moves the project onto (`true`) or off (`false`) the someday/maybe list.
+    pub(crate) fn set_someday(&mut self, someday: bool) {
+        self.someday = someday;
+    }
+}
+
+// Nicer formatting for projects when printed to the console.
+impl fmt::Display for Project {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        writeln!(f)?;
+        writeln!(f, "Project {{")?;
+        writeln!(f, "    {}", self.project_id)?;
+        writeln!(f, "    {}", self.project_name)?;
+        writeln!(f, "    {}", self.project_create_date)?;
+        writeln!(f, "    {}", self.project_status)?;
+        writeln!(f, "    {}", self.project_ordering)?;
+        if let Some(project_review_date) = &self.project_review_date {
+            writeln!(f, "    {project_review_date}")?;
+        }
+        if let Some(area_id) = &self.area_id {
+            writeln!(f, "    {area_id}")?;
+        }
+        if self.someday {
+            writeln!(f, "    Someday")?;
+        }
+        writeln!(f, "}}")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_project_getters() {
+        let my_project_id = ProjectId::new();
+        let my_project_name = ProjectName::new("my_project");
+        let my_project_create_date = ProjectCreateDate::now();
+        let my_project = Project::new(
+            my_project_id.clone(),
+            my_project_name.clone(),
+            my_project_create_date.clone(),
+            ProjectStatus::OnHold,
+        );
+        assert_eq!(my_project.project_id(), &my_project_id);
+        assert_eq!(my_project.project_name(), &my_project_name);
+        assert_eq!(my_project.project_create_date(), &my_project_create_date);
+        assert_eq!(my_project.project_status(), ProjectStatus::OnHold);
+        assert_eq!(my_project.project_ordering(), ProjectOrdering::Parallel);
+    }
+}
+
+#[cfg(test)]
+pub mod test_utils {
+
+    use super::*;
+
+    pub fn dummy_project() -> Project {
+        Project {
+            project_id: ProjectId::new(),
+            project_name: ProjectName::new("Dummy Project"),
+            project_create_date: ProjectCreateDate::now(),
+            project_status: ProjectStatus::Active,
+            project_ordering: ProjectOrdering::Parallel,
+            project_review_date: None,
+            area_id: None,
+            someday: false,
+        }
+    }
+}
@@ -0,0 +1,56 @@
+//! Creation dates of [`Project`s](super::Project).
+//!
+//! This module contains the [ProjectCreateDate] struct.
+//! It represents the creation date of a project in UTC.
+
+use chrono::{DateTime, Utc};
+
+use crate::model::common::date::Date;
+
+/// The date and time a [Project](crate::model::project::Project) was created.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct ProjectCreateDate(Date);
+
+impl ProjectCreateDate {
+    /// Creates a new [ProjectCreateDate] instance of the current time.
+    pub fn now() -> Self {
+        Self(Date::now())
+    }
+
+    /// Creates a [ProjectCreateDate] instance from an existing [DateTime].
+    pub fn from_value(value: DateTime<Utc>) -> Self {
+        Self(Date::from_value(value))
+    }
+
+    /// Returns the [DateTime] inside this [ProjectCreateDate] instance.
+    pub fn value(&self) -> &DateTime<Utc> {
+        self.0.value()
+    }
+}
+
+impl Default for ProjectCreateDate {
+    fn default() -> Self {
+        Self::now()
+    }
+}
+
+impl std::fmt::Display for ProjectCreateDate {
+    /// Nicer formatting for [ProjectCreateDate].
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "ProjectCreateDate({})", self.0)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_display() {
+        let project_create_date = ProjectCreateDate::now();
+        assert_eq!(
+            format!("{project_create_date}"),
+            format!("ProjectCreateDate(Date({}))", project_create_date.value())
+        );
+    }
+}
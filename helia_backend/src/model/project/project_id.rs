@@ -0,0 +1,55 @@
+//! Id's of [`Project`s](super::Project).
+//!
+//! This module contains the [ProjectId] struct.
+//! It contains a *uuid*, a globally unique identifier.
+
+use uuid::Uuid;
+
+use crate::model::common::id::Id;
+
+/// The id of a [Project](crate::model::project::Project).
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub struct ProjectId(Id);
+
+impl ProjectId {
+    /// Creates a new [ProjectId] instance.
+    pub fn new() -> Self {
+        Self(Id::new())
+    }
+
+    /// Creates a [ProjectId] instance from an existing [uuid::Uuid].
+    pub fn from_uuid(uuid: Uuid) -> Self {
+        Self(Id::from_uuid(uuid))
+    }
+
+    /// Returns the underlying [uuid::Uuid].
+    pub fn uuid(&self) -> &Uuid {
+        self.0.uuid()
+    }
+}
+
+impl Default for ProjectId {
+    /// Default constructor for [ProjectId].
+    fn default() -> Self {
+        ProjectId::new()
+    }
+}
+
+impl std::fmt::Display for ProjectId {
+    /// Nicer formatting for [ProjectId].
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "ProjectId({})", self.0)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn ids_should_be_different() {
+        let id_1 = ProjectId::new();
+        let id_2 = ProjectId::new();
+        assert_ne!(id_1, id_2);
+    }
+}
@@ -0,0 +1,45 @@
+//! Names of [`Project`s](super::Project).
+//!
+//! This module contains the [ProjectName] struct.
+
+/// The name of a [Project](crate::model::project::Project).
+///
+/// In GTD, a project's name describes its desired outcome.
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub struct ProjectName(String);
+
+impl ProjectName {
+    /// Creates a new [ProjectName] instance.
+    pub fn new(project_name: &str) -> Self {
+        ProjectName(String::from(project_name))
+    }
+
+    /// Returns the name of the project as a string.
+    pub fn as_str(&self) -> &str {
+        &self.0
+    }
+}
+
+impl std::fmt::Display for ProjectName {
+    /// Nicer formatting for [ProjectName].
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "ProjectName({})", self.0)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn as_str_returns_string_of_name() {
+        let project_name = ProjectName::new("MyProject");
+        assert_eq!(project_name.as_str(), "MyProject");
+    }
+
+    #[test]
+    fn test_display() {
+        let project_name = ProjectName::new("MyProject");
+        assert_eq!(format!("{project_name}"), "ProjectName(MyProject)");
+    }
+}
@@ -0,0 +1,70 @@
+//! Orderings of [`Project`s](super::Project).
+//!
+//! This module contains the [ProjectOrdering] enum.
+
+use std::str::FromStr;
+
+use thiserror::Error;
+
+/// How the actions of a [Project](crate::model::project::Project) depend on each other.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub enum ProjectOrdering {
+    /// The actions can be done in any order, all open ones are available.
+    #[default]
+    Parallel,
+    /// The actions have to be done one after the other, in the order of their positions.
+    ///
+    /// Only the first open action is available.
+    Sequential,
+}
+
+impl ProjectOrdering {
+    /// Returns the textual representation of the ordering, as used for persistence.
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            ProjectOrdering::Parallel => "parallel",
+            ProjectOrdering::Sequential => "sequential",
+        }
+    }
+}
+
+/// Error returned when parsing an unknown [ProjectOrdering].
+#[derive(Error, Debug, PartialEq, Eq)]
+#[error("Unknown project ordering '{0}'.")]
+pub struct ParseProjectOrderingError(String);
+
+impl FromStr for ProjectOrdering {
+    type Err = ParseProjectOrderingError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "parallel" => Ok(ProjectOrdering::Parallel),
+            "sequential" => Ok(ProjectOrdering::Sequential),
+            _ => Err(ParseProjectOrderingError(String::from(s))),
+        }
+    }
+}
+
+impl std::fmt::Display for ProjectOrdering {
+    /// Nicer formatting for [ProjectOrdering].
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "ProjectOrdering({})", self.as_str())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn as_str_round_trips() {
+        for ordering in [ProjectOrdering::Parallel, ProjectOrdering::Sequential] {
+            assert_eq!(ordering.as_str().parse::<ProjectOrdering>(), Ok(ordering));
+        }
+    }
+
+    #[test]
+    fn parsing_unknown_ordering_fails() {
+        assert!("random".parse::<ProjectOrdering>().is_err());
+    }
+}
@@ -0,0 +1,56 @@
+//! Review dates of [`Project`s](super::Project).
+//!
+//! This module contains the [ProjectReviewDate] struct.
+//! It represents the date a project was last reviewed in UTC.
+
+use chrono::{DateTime, Utc};
+
+use crate::model::common::date::Date;
+
+/// The date and time a [Project](crate::model::project::Project) was last reviewed.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct ProjectReviewDate(Date);
+
+impl ProjectReviewDate {
+    /// Creates a new [ProjectReviewDate] instance of the current time.
+    pub fn now() -> Self {
+        Self(Date::now())
+    }
+
+    /// Creates a [ProjectReviewDate] instance from an existing [DateTime].
+    pub fn from_value(value: DateTime<Utc>) -> Self {
+        Self(Date::from_value(value))
+    }
+
+    /// Returns the [DateTime] inside this [ProjectReviewDate] instance.
+    pub fn value(&self) -> &DateTime<Utc> {
+        self.0.value()
+    }
+}
+
+impl Default for ProjectReviewDate {
+    fn default() -> Self {
+        Self::now()
+    }
+}
+
+impl std::fmt::Display for ProjectReviewDate {
+    /// Nicer formatting for [ProjectReviewDate].
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "ProjectReviewDate({})", self.0)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_display() {
+        let project_review_date = ProjectReviewDate::now();
+        assert_eq!(
+            format!("{project_review_date}"),
+            format!("ProjectReviewDate(Date({}))", project_review_date.value())
+        );
+    }
+}
@@ -0,0 +1,81 @@
+//! Statuses of [`Project`s](super::Project).
+//!
+//! This module contains the [ProjectStatus] enum.
+
+use std::str::FromStr;
+
+use thiserror::Error;
+
+/// The status of a [Project](crate::model::project::Project).
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub enum ProjectStatus {
+    /// The project is being worked on.
+    #[default]
+    Active,
+    /// The project is paused for now.
+    OnHold,
+    /// The project's outcome has been achieved.
+    Completed,
+    /// The project has been abandoned.
+    Dropped,
+}
+
+impl ProjectStatus {
+    /// Returns the textual representation of the status, as used for persistence.
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            ProjectStatus::Active => "active",
+            ProjectStatus::OnHold => "on_hold",
+            ProjectStatus::Completed => "completed",
+            ProjectStatus::Dropped => "dropped",
+        }
+    }
+}
+
+/// Error returned when parsing an unknown [ProjectStatus].
+#[derive(Error, Debug, PartialEq, Eq)]
+#[error("Unknown project status '{0}'.")]
+pub struct ParseProjectStatusError(String);
+
+impl FromStr for ProjectStatus {
+    type Err = ParseProjectStatusError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "active" => Ok(ProjectStatus::Active),
+            "on_hold" => Ok(ProjectStatus::OnHold),
+            "completed" => Ok(ProjectStatus::Completed),
+            "dropped" => Ok(ProjectStatus::Dropped),
+            _ => Err(ParseProjectStatusError(String::from(s))),
+        }
+    }
+}
+
+impl std::fmt::Display for ProjectStatus {
+    /// Nicer formatting for [ProjectStatus].
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "ProjectStatus({})", self.as_str())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn as_str_round_trips() {
+        for status in [
+            ProjectStatus::Active,
+            ProjectStatus::OnHold,
+            ProjectStatus::Completed,
+            ProjectStatus::Dropped,
+        ] {
+            assert_eq!(status.as_str().parse::<ProjectStatus>(), Ok(status));
+        }
+    }
+
+    #[test]
+    fn parsing_unknown_status_fails() {
+        assert!("finished".parse::<ProjectStatus>().is_err());
+    }
+}
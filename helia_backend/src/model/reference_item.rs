@@ -0,0 +1,94 @@
+//! A GTD reference item.
+//!
+//! This model contains the [ReferenceItem] type.
+//! Reference material is information that is worth keeping, but does not require any action.
+
+use core::fmt;
+
+use crate::model::reference_item::{
+    reference_item_create_date::ReferenceItemCreateDate, reference_item_id::ReferenceItemId,
+    reference_item_text::ReferenceItemText,
+};
+
+// The id of a reference item.
+pub mod reference_item_id;
+// The text of a reference item.
+pub mod reference_item_text;
+// The creation date of a reference item.
+pub mod reference_item_create_date;
+
+/// A GTD reference item.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ReferenceItem {
+    reference_item_id: ReferenceItemId,
+    reference_item_text: ReferenceItemText,
+    reference_item_create_date: ReferenceItemCreateDate,
+}
+
+impl ReferenceItem {
+    /// Returns a new [ReferenceItem] instance.
+    pub(crate) fn new(
+        reference_item_id: ReferenceItemId,
+        reference_item_text: ReferenceItemText,
+        reference_item_create_date: ReferenceItemCreateDate,
+    ) -> Self {
+        ReferenceItem {
+            reference_item_id,
+            reference_item_text,
+            reference_item_create_date,
+        }
+    }
+
+    /// Returns a reference to the reference item's [ReferenceItemId].
+    pub fn reference_item_id(&self) -> &ReferenceItemId {
+        &self.reference_item_id
+    }
+
+    /// Returns a reference to the reference item's [ReferenceItemText].
+    pub fn reference_item_text(&self) -> &ReferenceItemText {
+        &self.reference_item_text
+    }
+
+    /// Returns a reference to the reference item's [ReferenceItemCreateDate].
+    pub fn reference_item_create_date(&self) -> &ReferenceItemCreateDate {
+        &self.reference_item_create_date
+    }
+}
+
+// Nicer formatting for reference items when printed to the console.
+impl fmt::Display for ReferenceItem {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        writeln!(f)?;
+        writeln!(f, "ReferenceItem {{")?;
+        writeln!(f, "    {}", self.reference_item_id)?;
+        writeln!(f, "    {}", self.reference_item_text)?;
+        writeln!(f, "    {}", self.reference_item_create_date)?;
+        writeln!(f, "}}")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_reference_item_getters() {
+        let my_reference_item_id = ReferenceItemId::new();
+        let my_reference_item_text = ReferenceItemText::new("Wifi password: hunter2");
+        let my_reference_item_create_date = ReferenceItemCreateDate::now();
+        let my_reference_item = ReferenceItem::new(
+            my_reference_item_id.clone(),
+            my_reference_item_text.clone(),
+            my_reference_item_create_date.clone(),
+        );
+        assert_eq!(my_reference_item.reference_item_id(), &my_reference_item_id);
+        assert_eq!(
+            my_reference_item.reference_item_text(),
+            &my_reference_item_text
+        );
+        assert_eq!(
+            my_reference_item.reference_item_create_date(),
+            &my_reference_item_create_date
+        );
+    }
+}
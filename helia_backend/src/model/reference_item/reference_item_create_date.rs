@@ -0,0 +1,59 @@
+//! Creation dates of [`ReferenceItem`s](super::ReferenceItem).
+//!
+//! This module contains the [ReferenceItemCreateDate] struct.
+//! It represents the date a reference item was created in UTC.
+
+use chrono::{DateTime, Utc};
+
+use crate::model::common::date::Date;
+
+/// The date and time a [ReferenceItem](crate::model::reference_item::ReferenceItem) was created.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct ReferenceItemCreateDate(Date);
+
+impl ReferenceItemCreateDate {
+    /// Creates a new [ReferenceItemCreateDate] instance of the current time.
+    pub fn now() -> Self {
+        Self(Date::now())
+    }
+
+    /// Creates a [ReferenceItemCreateDate] instance from an existing [DateTime].
+    pub fn from_value(value: DateTime<Utc>) -> Self {
+        Self(Date::from_value(value))
+    }
+
+    /// Returns the [DateTime] inside this [ReferenceItemCreateDate] instance.
+    pub fn value(&self) -> &DateTime<Utc> {
+        self.0.value()
+    }
+}
+
+impl Default for ReferenceItemCreateDate {
+    fn default() -> Self {
+        Self::now()
+    }
+}
+
+impl std::fmt::Display for ReferenceItemCreateDate {
+    /// Nicer formatting for [ReferenceItemCreateDate].
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "ReferenceItemCreateDate({})", self.0)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_display() {
+        let reference_item_create_date = ReferenceItemCreateDate::now();
+        assert_eq!(
+            format!("{reference_item_create_date}"),
+            format!(
+                "ReferenceItemCreateDate(Date({}))",
+                reference_item_create_date.value()
+            )
+        );
+    }
+}
@@ -0,0 +1,55 @@
+//! Id's of [`ReferenceItem`s](super::ReferenceItem).
+//!
+//! This module contains the [ReferenceItemId] struct.
+//! It contains a *uuid*, a globally unique identifier.
+
+use uuid::Uuid;
+
+use crate::model::common::id::Id;
+
+/// The id of a [ReferenceItem](crate::model::reference_item::ReferenceItem).
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub struct ReferenceItemId(Id);
+
+impl ReferenceItemId {
+    /// Creates a new [ReferenceItemId] instance.
+    pub fn new() -> Self {
+        Self(Id::new())
+    }
+
+    /// Creates a [ReferenceItemId] instance from an existing [uuid::Uuid].
+    pub fn from_uuid(uuid: Uuid) -> Self {
+        Self(Id::from_uuid(uuid))
+    }
+
+    /// Returns the underlying [uuid::Uuid].
+    pub fn uuid(&self) -> &Uuid {
+        self.0.uuid()
+    }
+}
+
+impl Default for ReferenceItemId {
+    /// Default constructor for [ReferenceItemId].
+    fn default() -> Self {
+        ReferenceItemId::new()
+    }
+}
+
+impl std::fmt::Display for ReferenceItemId {
+    /// Nicer formatting for [ReferenceItemId].
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "ReferenceItemId({})", self.0)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn ids_should_be_different() {
+        let id_1 = ReferenceItemId::new();
+        let id_2 = ReferenceItemId::new();
+        assert_ne!(id_1, id_2);
+    }
+}
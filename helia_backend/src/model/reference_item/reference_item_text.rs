@@ -0,0 +1,46 @@
+//! Texts of [`ReferenceItem`s](super::ReferenceItem).
+//!
+//! This module contains the [ReferenceItemText] struct.
+
+/// The text of a [ReferenceItem](crate::model::reference_item::ReferenceItem).
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub struct ReferenceItemText(String);
+
+impl ReferenceItemText {
+    /// Creates a new [ReferenceItemText] instance.
+    pub fn new(reference_item_text: &str) -> Self {
+        ReferenceItemText(String::from(reference_item_text))
+    }
+
+    /// Returns the text as a string.
+    pub fn as_str(&self) -> &str {
+        &self.0
+    }
+}
+
+impl std::fmt::Display for ReferenceItemText {
+    /// Nicer formatting for [ReferenceItemText].
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "ReferenceItemText({})", self.0)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn as_str_returns_string() {
+        let reference_item_text = ReferenceItemText::new("Something");
+        assert_eq!(reference_item_text.as_str(), "Something");
+    }
+
+    #[test]
+    fn test_display() {
+        let reference_item_text = ReferenceItemText::new("Something");
+        assert_eq!(
+            format!("{reference_item_text}"),
+            "ReferenceItemText(Something)"
+        );
+    }
+}
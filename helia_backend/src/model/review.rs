@@ -0,0 +1,127 @@
+//! A GTD weekly review.
+//!
+//! This model contains the [Review] type.
+//! The weekly review walks through the lists of the system to get it current and complete again.
+
+use core::fmt;
+
+use chrono::{DateTime, Utc};
+
+use crate::model::review::{review_id::ReviewId, review_step::ReviewStep};
+
+// The id of a review.
+pub mod review_id;
+// The steps of a review.
+pub mod review_step;
+
+/// A weekly review, started at some moment and completed once all of its [ReviewStep]s are.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Review {
+    review_id: ReviewId,
+    started_at: DateTime<Utc>,
+    completed_steps: Vec<(ReviewStep, DateTime<Utc>)>,
+}
+
+impl Review {
+    /// Returns a new [Review] instance without completed steps.
+    pub(crate) fn new(review_id: ReviewId, started_at: DateTime<Utc>) -> Self {
+        Review {
+            review_id,
+            started_at,
+            completed_steps: Vec::new(),
+        }
+    }
+
+    /// Returns a reference to the review's [ReviewId].
+    pub fn review_id(&self) -> &ReviewId {
+        &self.review_id
+    }
+
+    /// Returns when the review was started.
+    pub fn started_at(&self) -> &DateTime<Utc> {
+        &self.started_at
+    }
+
+    /// Returns the completed steps and when they were completed, in the order of completion.
+    pub fn completed_steps(&self) -> &[(ReviewStep, DateTime<Utc>)] {
+        &self.completed_steps
+    }
+
+    /// Returns when the given step was completed, if it was.
+    pub fn step_completed_at(&self, step: ReviewStep) -> Option<&DateTime<Utc>> {
+        self.completed_steps
+            .iter()
+            .find(|(completed_step, _)| *completed_step == step)
+            .map(|(_, completed_at)| completed_at)
+    }
+
+    /// Returns the step to do next, or `None` if the review is completed.
+    pub fn next_step(&self) -> Option<ReviewStep> {
+        ReviewStep::ALL
+            .into_iter()
+            .find(|step| self.step_completed_at(*step).is_none())
+    }
+
+    /// Returns when the review was completed, which is when its last step was.
+    pub fn completed_at(&self) -> Option<&DateTime<Utc>> {
+        match self.next_step() {
+            Some(_) => None,
+            None => self
+                .completed_steps
+                .iter()
+                .map(|(_, completed_at)| completed_at)
+                .max(),
+        }
+    }
+
+    /// Returns `true` if all steps of the review are completed.
+    pub fn is_completed(&self) -> bool {
+        self.next_step().is_none()
+    }
+
+    /// Records that `step` was completed at `completed_at`.
+    pub(crate) fn complete_step(&mut self, step: ReviewStep, completed_at: DateTime<Utc>) {
+        self.completed_steps.push((step, completed_at));
+    }
+}
+
+// Nicer formatting for reviews when printed to the console.
+impl fmt::Display for Review {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        writeln!(f)?;
+        writeln!(f, "Review {{")?;
+        writeln!(f, "    {}", self.review_id)?;
+        writeln!(f, "    Started {}", self.started_at)?;
+        for (step, completed_at) in &self.completed_steps {
+            writeln!(f, "    {step} completed {completed_at}")?;
+        }
+        writeln!(f, "}}")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use chrono::Duration;
+
+    use super::*;
+
+    #[test]
+    fn test_steps_are_walked_in_order() {
+        let start = Utc::now();
+        let mut review = Review::new(ReviewId::new(), start);
+        assert_eq!(review.next_step(), Some(ReviewStep::EmptyInbox));
+        assert_eq!(review.completed_at(), None);
+
+        for (minutes, step) in ReviewStep::ALL.into_iter().enumerate() {
+            assert!(!review.is_completed());
+            review.complete_step(step, start + Duration::minutes(minutes as i64));
+        }
+        assert!(review.is_completed());
+        assert_eq!(review.next_step(), None);
+        assert_eq!(
+            review.step_completed_at(ReviewStep::WaitingFor),
+            Some(&(start + Duration::minutes(2)))
+        );
+        assert_eq!(review.completed_at(), Some(&(start + Duration::minutes(4))));
+    }
+}
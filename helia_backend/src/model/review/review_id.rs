@@ -0,0 +1,55 @@
+//! Id's of [`Review`s](super::Review).
+//!
+//! This module contains the [ReviewId] struct.
+//! It contains a *uuid*, a globally unique identifier.
+
+use uuid::Uuid;
+
+use crate::model::common::id::Id;
+
+/// The id of a [Review](crate::model::review::Review).
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub struct ReviewId(Id);
+
+impl ReviewId {
+    /// Creates a new [ReviewId] instance.
+    pub fn new() -> Self {
+        Self(Id::new())
+    }
+
+    /// Creates a [ReviewId] instance from an existing [uuid::Uuid].
+    pub fn from_uuid(uuid: Uuid) -> Self {
+        Self(Id::from_uuid(uuid))
+    }
+
+    /// Returns the underlying [uuid::Uuid].
+    pub fn uuid(&self) -> &Uuid {
+        self.0.uuid()
+    }
+}
+
+impl Default for ReviewId {
+    /// Default constructor for [ReviewId].
+    fn default() -> Self {
+        ReviewId::new()
+    }
+}
+
+impl std::fmt::Display for ReviewId {
+    /// Nicer formatting for [ReviewId].
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "ReviewId({})", self.0)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn ids_should_be_different() {
+        let id_1 = ReviewId::new();
+        let id_2 = ReviewId::new();
+        assert_ne!(id_1, id_2);
+    }
+}
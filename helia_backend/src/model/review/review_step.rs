@@ -0,0 +1,87 @@
+//! Steps of a [`Review`](super::Review).
+//!
+//! This module contains the [ReviewStep] enum.
+
+use std::str::FromStr;
+
+use thiserror::Error;
+
+/// A step of the weekly [Review](crate::model::review::Review).
+///
+/// Steps are walked through in the order of [ReviewStep::ALL].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum ReviewStep {
+    /// Process every inbox item, see `HeliaCore::list_inbox`.
+    EmptyInbox,
+    /// Give every project without a next action one, or put it on hold, see
+    /// `HeliaCore::stalled_projects`.
+    StalledProjects,
+    /// Follow up on delegated actions, see `HeliaCore::list_waiting_for`.
+    WaitingFor,
+    /// Promote what has become relevant, see `HeliaCore::list_someday_maybe`.
+    SomedayMaybe,
+    /// Look at what is due soon, see `HeliaCore::list_actions_due_within`.
+    UpcomingDue,
+}
+
+impl ReviewStep {
+    /// All steps, in the order they are walked through.
+    pub const ALL: [ReviewStep; 5] = [
+        ReviewStep::EmptyInbox,
+        ReviewStep::StalledProjects,
+        ReviewStep::WaitingFor,
+        ReviewStep::SomedayMaybe,
+        ReviewStep::UpcomingDue,
+    ];
+
+    /// Returns the textual representation of the step, as used for persistence.
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            ReviewStep::EmptyInbox => "empty_inbox",
+            ReviewStep::StalledProjects => "stalled_projects",
+            ReviewStep::WaitingFor => "waiting_for",
+            ReviewStep::SomedayMaybe => "someday_maybe",
+            ReviewStep::UpcomingDue => "upcoming_due",
+        }
+    }
+}
+
+/// Error returned when parsing an unknown [ReviewStep].
+#[derive(Error, Debug, PartialEq, Eq)]
+#[error("Unknown review step '{0}'.")]
+pub struct ParseReviewStepError(String);
+
+impl FromStr for ReviewStep {
+    type Err = ParseReviewStepError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        ReviewStep::ALL
+            .into_iter()
+            .find(|step| step.as_str() == s)
+            .ok_or_else(|| ParseReviewStepError(String::from(s)))
+    }
+}
+
+impl std::fmt::Display for ReviewStep {
+    /// Nicer formatting for [ReviewStep].
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "ReviewStep({})", self.as_str())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn as_str_round_trips() {
+        for step in ReviewStep::ALL {
+            assert_eq!(step.as_str().parse::<ReviewStep>(), Ok(step));
+        }
+    }
+
+    #[test]
+    fn parsing_unknown_step_fails() {
+        assert!("clean_desk".parse::<ReviewStep>().is_err());
+    }
+}
@@ -0,0 +1,77 @@
+//! A free-form tag.
+//!
+//! This model contains the [Tag] type.
+//! Tags label [`Action`s](crate::model::action::Action) and
+//! [`Project`s](crate::model::project::Project) beyond what contexts cover, like `urgent` or
+//! `client-x`. Their [TagPath]s form a hierarchy, so `errand/hardware` is a kind of `errand`.
+
+use core::fmt;
+
+use crate::model::tag::{tag_id::TagId, tag_path::TagPath};
+
+// The id of a tag.
+pub mod tag_id;
+// The path of a tag.
+pub mod tag_path;
+
+/// A free-form, hierarchical tag.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Tag {
+    tag_id: TagId,
+    tag_path: TagPath,
+}
+
+impl Tag {
+    /// Returns a new [Tag] instance.
+    pub(crate) fn new(tag_id: TagId, tag_path: TagPath) -> Self {
+        Tag { tag_id, tag_path }
+    }
+
+    /// Returns a reference to the tag's [TagId].
+    pub fn tag_id(&self) -> &TagId {
+        &self.tag_id
+    }
+
+    /// Returns a reference to the tag's [TagPath].
+    pub fn tag_path(&self) -> &TagPath {
+        &self.tag_path
+    }
+}
+
+// Nicer formatting for tags when printed to the console.
+impl fmt::Display for Tag {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        writeln!(f)?;
+        writeln!(f, "Tag {{")?;
+        writeln!(f, "    {}", self.tag_id)?;
+        writeln!(f, "    {}", self.tag_path)?;
+        writeln!(f, "}}")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_tag_getters() {
+        let my_tag_id = TagId::new();
+        let my_tag_path = TagPath::new("work/client-x").unwrap();
+        let my_tag = Tag::new(my_tag_id.clone(), my_tag_path.clone());
+        assert_eq!(my_tag.tag_id(), &my_tag_id);
+        assert_eq!(my_tag.tag_path(), &my_tag_path);
+    }
+}
+
+#[cfg(test)]
+pub mod test_utils {
+
+    use super::*;
+
+    pub fn dummy_tag() -> Tag {
+        Tag {
+            tag_id: TagId::new(),
+            tag_path: TagPath::from_stored("dummy/tag"),
+        }
+    }
+}
@@ -0,0 +1,55 @@
+//! Id's of [`Tag`s](super::Tag).
+//!
+//! This module contains the [TagId] struct.
+//! It contains a *uuid*, a globally unique identifier.
+
+use uuid::Uuid;
+
+use crate::model::common::id::Id;
+
+/// The id of a [Tag](crate::model::tag::Tag).
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub struct TagId(Id);
+
+impl TagId {
+    /// Creates a new [TagId] instance.
+    pub fn new() -> Self {
+        Self(Id::new())
+    }
+
+    /// Creates a [TagId] instance from an existing [uuid::Uuid].
+    pub fn from_uuid(uuid: Uuid) -> Self {
+        Self(Id::from_uuid(uuid))
+    }
+
+    /// Returns the underlying [uuid::Uuid].
+    pub fn uuid(&self) -> &Uuid {
+        self.0.uuid()
+    }
+}
+
+impl Default for TagId {
+    /// Default constructor for [TagId].
+    fn default() -> Self {
+        TagId::new()
+    }
+}
+
+impl std::fmt::Display for TagId {
+    /// Nicer formatting for [TagId].
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "TagId({})", self.0)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn ids_should_be_different() {
+        let id_1 = TagId::new();
+        let id_2 = TagId::new();
+        assert_ne!(id_1, id_2);
+    }
+}
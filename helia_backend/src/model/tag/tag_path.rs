@@ -0,0 +1,118 @@
+//! Paths of [`Tag`s](super::Tag).
+//!
+//! This module contains the [TagPath] struct.
+
+use crate::model::validation::{self, Violation};
+
+/// The path of a [Tag](crate::model::tag::Tag), like `urgent` or `work/client-x`.
+///
+/// Paths are made of segments separated by [TagPath::SEPARATOR]; every segment but the last
+/// names an ancestor tag. Segments are trimmed and non-empty, and the whole path is at most
+/// [TagPath::MAX_LEN] characters long and contains no control characters.
+#[derive(Debug, PartialEq, Eq, Clone, PartialOrd, Ord, Hash)]
+pub struct TagPath(String);
+
+impl TagPath {
+    /// The maximum number of characters of a [TagPath].
+    pub const MAX_LEN: usize = 200;
+
+    /// The separator between the segments of a [TagPath].
+    pub const SEPARATOR: char = '/';
+
+    /// Creates a new [TagPath] instance from `tag_path`, trimming each of its segments.
+    ///
+    /// Fails if the path breaks one of the rules described at [TagPath].
+    pub fn new(tag_path: &str) -> Result<Self, Violation> {
+        let segments = tag_path
+            .split(Self::SEPARATOR)
+            .map(str::trim)
+            .collect::<Vec<_>>();
+        if segments.iter().all(|segment| segment.is_empty()) {
+            return Err(Violation::Empty);
+        }
+        if segments.iter().any(|segment| segment.is_empty()) {
+            return Err(Violation::Invalid("contains an empty segment"));
+        }
+        let tag_path = segments.join("/");
+        validation::single_line_text(&tag_path, Self::MAX_LEN)?;
+        Ok(TagPath(tag_path))
+    }
+
+    /// Creates a [TagPath] instance without validating it.
+    ///
+    /// Only meant for paths that were already stored.
+    pub(crate) fn from_stored(tag_path: &str) -> Self {
+        TagPath(String::from(tag_path))
+    }
+
+    /// Returns the path as a string.
+    pub fn as_str(&self) -> &str {
+        &self.0
+    }
+
+    /// Returns the segments of the path, from the root down.
+    pub fn segments(&self) -> impl Iterator<Item = &str> {
+        self.0.split(Self::SEPARATOR)
+    }
+
+    /// Returns the last segment of the path, the tag's own name.
+    pub fn name(&self) -> &str {
+        self.segments().last().unwrap_or_default()
+    }
+
+    /// Returns the path of the parent tag, or `None` for a root tag.
+    pub fn parent(&self) -> Option<TagPath> {
+        self.0
+            .rsplit_once(Self::SEPARATOR)
+            .map(|(parent, _)| TagPath(String::from(parent)))
+    }
+
+    /// Returns `true` if `other` is this path or one of its descendants.
+    pub fn contains(&self, other: &TagPath) -> bool {
+        other
+            .0
+            .strip_prefix(&self.0)
+            .is_some_and(|rest| rest.is_empty() || rest.starts_with(Self::SEPARATOR))
+    }
+}
+
+impl std::fmt::Display for TagPath {
+    /// Nicer formatting for [TagPath].
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "TagPath({})", self.0)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_segments_are_trimmed() {
+        let tag_path = TagPath::new(" work / client-x ").unwrap();
+        assert_eq!(tag_path.as_str(), "work/client-x");
+        assert_eq!(tag_path.name(), "client-x");
+        assert_eq!(tag_path.parent(), Some(TagPath::new("work").unwrap()));
+        assert_eq!(TagPath::new("urgent").unwrap().parent(), None);
+    }
+
+    #[test]
+    fn test_invalid_paths_are_rejected() {
+        assert_eq!(TagPath::new(" / "), Err(Violation::Empty));
+        assert_eq!(
+            TagPath::new("work//client-x"),
+            Err(Violation::Invalid("contains an empty segment"))
+        );
+        assert_eq!(TagPath::new("work\t/x"), Ok(TagPath::from_stored("work/x")));
+        assert_eq!(TagPath::new("wo\u{0}rk"), Err(Violation::ControlCharacter));
+    }
+
+    #[test]
+    fn test_contains_descendants_only() {
+        let work = TagPath::new("work").unwrap();
+        assert!(work.contains(&work));
+        assert!(work.contains(&TagPath::new("work/client-x").unwrap()));
+        assert!(!work.contains(&TagPath::new("workshop").unwrap()));
+        assert!(!work.contains(&TagPath::new("home/work").unwrap()));
+    }
+}
@@ -0,0 +1,88 @@
+//! Validation of user input for the domain types.
+//!
+//! Fallible constructors like [ActionName::new](crate::model::action::action_name::ActionName::new)
+//! report a [Violation]. The core attaches the name of the offending input field, so frontends
+//! can show a [FieldError] right next to that input.
+
+use thiserror::Error;
+
+/// A rule broken by a value.
+#[derive(Error, Debug, Clone, PartialEq, Eq)]
+pub enum Violation {
+    #[error("must not be empty")]
+    Empty,
+
+    #[error("must be at most {max} characters long, but is {actual}")]
+    TooLong { max: usize, actual: usize },
+
+    #[error("must not contain control characters")]
+    ControlCharacter,
+
+    #[error("is invalid: {0}")]
+    Invalid(&'static str),
+}
+
+/// A [Violation] of the input field called `field`.
+#[derive(Error, Debug, Clone, PartialEq, Eq)]
+#[error("{field} {violation}")]
+pub struct FieldError {
+    /// The name of the offending field, as named in the request.
+    pub field: &'static str,
+    pub violation: Violation,
+}
+
+impl FieldError {
+    /// Creates a new [FieldError] instance.
+    pub fn new(field: &'static str, violation: Violation) -> Self {
+        Self { field, violation }
+    }
+}
+
+/// Trims `value` and checks that it is a non-empty, single-line text of at most `max_len`
+/// characters.
+pub(crate) fn single_line_text(value: &str, max_len: usize) -> Result<&str, Violation> {
+    let value = value.trim();
+    if value.is_empty() {
+        return Err(Violation::Empty);
+    }
+    let len = value.chars().count();
+    if len > max_len {
+        return Err(Violation::TooLong {
+            max: max_len,
+            actual: len,
+        });
+    }
+    if value.chars().any(char::is_control) {
+        return Err(Violation::ControlCharacter);
+    }
+    Ok(value)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_single_line_text_is_trimmed() {
+        assert_eq!(single_line_text("  Call mom \t", 10), Ok("Call mom"));
+    }
+
+    #[test]
+    fn test_single_line_text_violations() {
+        assert_eq!(single_line_text(" \n ", 10), Err(Violation::Empty));
+        assert_eq!(
+            single_line_text("ääääää", 5),
+            Err(Violation::TooLong { max: 5, actual: 6 })
+        );
+        assert_eq!(
+            single_line_text("Call\nmom", 10),
+            Err(Violation::ControlCharacter)
+        );
+    }
+
+    #[test]
+    fn test_field_error_display() {
+        let err = FieldError::new("name", Violation::Empty);
+        assert_eq!(err.to_string(), "name must not be empty");
+    }
+}
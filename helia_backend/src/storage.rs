@@ -5,8 +5,23 @@
 //! The storage module itself has no knowledge of GTD specific workflows, and thus only offers
 //! basic **CRUD** operations.
 
+use std::path::Path;
+
+use chrono::{DateTime, Utc};
+
 use crate::{
-    model::action::Action,
+    core::{requests::ActionQuery, responses::SearchItem},
+    model::{
+        action::{Action, action_id::ActionId},
+        area::{Area, area_id::AreaId},
+        context::{Context, context_id::ContextId},
+        inbox_item::{InboxItem, inbox_item_id::InboxItemId},
+        perspective::{Perspective, perspective_id::PerspectiveId},
+        project::{Project, project_id::ProjectId},
+        reference_item::ReferenceItem,
+        review::Review,
+        tag::{Tag, tag_id::TagId, tag_path::TagPath},
+    },
     storage::{
         migration::MigrationSource, sqlite_storage::SqliteStorage, storage_error::StorageError,
     },
@@ -35,10 +50,459 @@ pub trait Storage {
 
     /// Tries to insert an action.
     fn insert_action(&self, action: &Action) -> Result<(), StorageError>;
+
+    /// Tries to fetch the action with the given id.
+    ///
+    /// Returns [`StorageError::NotFound`] if no such action exists.
+    fn get_action(&self, action_id: &ActionId) -> Result<Action, StorageError>;
+
+    /// Fetches the actions matching the [ActionFilter], ordered by their creation date.
+    fn list_actions(&self, filter: &ActionFilter) -> Result<Vec<Action>, StorageError>;
+
+    /// Fetches the page of actions matching the [ActionQuery], sorted as it asks for.
+    fn query_actions(&self, query: &ActionQuery) -> Result<Vec<Action>, StorageError>;
+
+    /// Tries to overwrite a stored action with the given one.
+    ///
+    /// The action is matched by its id.
+    /// Returns [`StorageError::NotFound`] if no such action exists.
+    fn update_action(&self, action: &Action) -> Result<(), StorageError>;
+
+    /// Overwrites the completed occurrence of a recurring action and inserts the next one, in one
+    /// transaction.
+    ///
+    /// The next occurrence is linked to the same contexts and tags as the completed one.
+    /// Returns [`StorageError::NotFound`] if the completed action doesn't exist.
+    /// If any step fails, nothing is changed.
+    fn complete_recurring_action(
+        &self,
+        completed: &Action,
+        next_occurrence: &Action,
+    ) -> Result<(), StorageError>;
+
+    /// Returns the position after the last action of the given project, or 0 if it has none.
+    fn next_action_position(&self, project_id: &ProjectId) -> Result<u32, StorageError>;
+
+    /// Numbers the given actions of a project by their index in `action_ids`, in one transaction.
+    ///
+    /// Returns [`StorageError::NotFound`] if any of the actions doesn't belong to the project.
+    /// If any step fails, nothing is changed.
+    fn set_action_positions(
+        &self,
+        project_id: &ProjectId,
+        action_ids: &[ActionId],
+    ) -> Result<(), StorageError>;
+
+    /// Tries to delete the action with the given id.
+    ///
+    /// Returns [`StorageError::NotFound`] if no such action exists.
+    fn delete_action(&self, action_id: &ActionId) -> Result<(), StorageError>;
+
+    /// Tries to insert a project.
+    fn insert_project(&self, project: &Project) -> Result<(), StorageError>;
+
+    /// Tries to fetch the project with the given id.
+    ///
+    /// Returns [`StorageError::NotFound`] if no such project exists.
+    fn get_project(&self, project_id: &ProjectId) -> Result<Project, StorageError>;
+
+    /// Fetches the projects matching the [ProjectFilter], ordered by their creation date.
+    fn list_projects(&self, filter: &ProjectFilter) -> Result<Vec<Project>, StorageError>;
+
+    /// Fetches the active, committed projects without a next action, together with the
+    /// completion date of their last completed action, in one query.
+    ///
+    /// A next action is open, committed, not delegated, not deferred past `now` and not blocked
+    /// by an earlier open action of its sequential project. Projects whose last action was completed longest ago come first, projects without
+    /// any completed action before all others.
+    fn list_stalled_projects(
+        &self,
+        now: DateTime<Utc>,
+    ) -> Result<Vec<StalledProjectRow>, StorageError>;
+
+    /// Tries to overwrite a stored project with the given one.
+    ///
+    /// The project is matched by its id.
+    /// Returns [`StorageError::NotFound`] if no such project exists.
+    fn update_project(&self, project: &Project) -> Result<(), StorageError>;
+
+    /// Tries to delete the project with the given id.
+    ///
+    /// Actions belonging to the project are kept, but no longer belong to any project.
+    /// Returns [`StorageError::NotFound`] if no such project exists.
+    fn delete_project(&self, project_id: &ProjectId) -> Result<(), StorageError>;
+
+    /// Tries to insert an inbox item.
+    fn insert_inbox_item(&self, inbox_item: &InboxItem) -> Result<(), StorageError>;
+
+    /// Tries to fetch the inbox item with the given id.
+    ///
+    /// Returns [`StorageError::NotFound`] if no such inbox item exists.
+    fn get_inbox_item(&self, inbox_item_id: &InboxItemId) -> Result<InboxItem, StorageError>;
+
+    /// Fetches all inbox items, ordered by their capture date.
+    fn list_inbox_items(&self) -> Result<Vec<InboxItem>, StorageError>;
+
+    /// Removes an inbox item and inserts what it was turned into, in one transaction.
+    ///
+    /// Returns [`StorageError::NotFound`] if no such inbox item exists.
+    /// If any step fails, nothing is changed.
+    fn process_inbox_item(
+        &self,
+        inbox_item_id: &InboxItemId,
+        target: &InboxItemTarget,
+    ) -> Result<(), StorageError>;
+
+    /// Tries to insert a reference item.
+    fn insert_reference_item(&self, reference_item: &ReferenceItem) -> Result<(), StorageError>;
+
+    /// Fetches all reference items, ordered by their creation date.
+    fn list_reference_items(&self) -> Result<Vec<ReferenceItem>, StorageError>;
+
+    /// Tries to insert a context.
+    fn insert_context(&self, context: &Context) -> Result<(), StorageError>;
+
+    /// Tries to fetch the context with the given id.
+    ///
+    /// Returns [`StorageError::NotFound`] if no such context exists.
+    fn get_context(&self, context_id: &ContextId) -> Result<Context, StorageError>;
+
+    /// Fetches all contexts, ordered by their name.
+    fn list_contexts(&self) -> Result<Vec<Context>, StorageError>;
+
+    /// Tries to overwrite a stored context with the given one.
+    ///
+    /// The context is matched by its id.
+    /// Returns [`StorageError::NotFound`] if no such context exists.
+    fn update_context(&self, context: &Context) -> Result<(), StorageError>;
+
+    /// Tries to delete the context with the given id.
+    ///
+    /// Fails if the context is still linked to an action.
+    /// Returns [`StorageError::NotFound`] if no such context exists.
+    fn delete_context(&self, context_id: &ContextId) -> Result<(), StorageError>;
+
+    /// Tries to insert an area.
+    fn insert_area(&self, area: &Area) -> Result<(), StorageError>;
+
+    /// Tries to fetch the area with the given id.
+    ///
+    /// Returns [`StorageError::NotFound`] if no such area exists.
+    fn get_area(&self, area_id: &AreaId) -> Result<Area, StorageError>;
+
+    /// Fetches all areas, ordered by their name.
+    fn list_areas(&self) -> Result<Vec<Area>, StorageError>;
+
+    /// Fetches all areas, ordered by their name, together with their number of open, committed
+    /// actions and of stalled projects, in one query.
+    ///
+    /// Actions of a project count for the project's area. Stalled projects are those listed by
+    /// [Storage::list_stalled_projects].
+    fn list_area_summaries(&self, now: DateTime<Utc>) -> Result<Vec<AreaSummaryRow>, StorageError>;
+
+    /// Tries to overwrite a stored area with the given one.
+    ///
+    /// The area is matched by its id.
+    /// Returns [`StorageError::NotFound`] if no such area exists.
+    fn update_area(&self, area: &Area) -> Result<(), StorageError>;
+
+    /// Tries to delete the area with the given id.
+    ///
+    /// Its projects and actions are kept without an area.
+    /// Returns [`StorageError::NotFound`] if no such area exists.
+    fn delete_area(&self, area_id: &AreaId) -> Result<(), StorageError>;
+
+    /// Tries to insert a tag.
+    fn insert_tag(&self, tag: &Tag) -> Result<(), StorageError>;
+
+    /// Tries to fetch the tag with the given id.
+    ///
+    /// Returns [`StorageError::NotFound`] if no such tag exists.
+    fn get_tag(&self, tag_id: &TagId) -> Result<Tag, StorageError>;
+
+    /// Tries to fetch the tag with the given path.
+    ///
+    /// Returns [`StorageError::NotFound`] if no such tag exists.
+    fn get_tag_by_path(&self, tag_path: &TagPath) -> Result<Tag, StorageError>;
+
+    /// Fetches all tags, ordered by their path.
+    fn list_tags(&self) -> Result<Vec<Tag>, StorageError>;
+
+    /// Moves the tag at `from` and all its descendants below `to`, in one transaction.
+    ///
+    /// `from/x` becomes `to/x`. A moved tag whose new path is already taken is merged into the
+    /// tag at that path: its actions and projects are linked to that tag, and the moved tag is
+    /// deleted. Only tags outside the moved ones are merged into, so a tag can move onto the old
+    /// path of another moved tag. `to` must not lie within `from`, and the new paths must be
+    /// valid [TagPath]s.
+    fn move_tags(&self, from: &TagPath, to: &TagPath) -> Result<(), StorageError>;
+
+    /// Tries to delete the tag with the given id, together with its links.
+    ///
+    /// Descendants of the tag are kept.
+    /// Returns [`StorageError::NotFound`] if no such tag exists.
+    fn delete_tag(&self, tag_id: &TagId) -> Result<(), StorageError>;
+
+    /// Tries to insert a perspective.
+    fn insert_perspective(&self, perspective: &Perspective) -> Result<(), StorageError>;
+
+    /// Tries to fetch the perspective with the given id.
+    ///
+    /// Returns [`StorageError::NotFound`] if no such perspective exists.
+    fn get_perspective(&self, perspective_id: &PerspectiveId) -> Result<Perspective, StorageError>;
+
+    /// Fetches all perspectives, ordered by their name.
+    fn list_perspectives(&self) -> Result<Vec<Perspective>, StorageError>;
+
+    /// Tries to overwrite a stored perspective with the given one.
+    ///
+    /// The perspective is matched by its id.
+    /// Returns [`StorageError::NotFound`] if no such perspective exists.
+    fn update_perspective(&self, perspective: &Perspective) -> Result<(), StorageError>;
+
+    /// Tries to delete the perspective with the given id.
+    ///
+    /// Returns [`StorageError::NotFound`] if no such perspective exists.
+    fn delete_perspective(&self, perspective_id: &PerspectiveId) -> Result<(), StorageError>;
+
+    /// Links an action to a context.
+    ///
+    /// Linking an action to a context it is already linked to does nothing.
+    fn insert_action_context(
+        &self,
+        action_id: &ActionId,
+        context_id: &ContextId,
+    ) -> Result<(), StorageError>;
+
+    /// Unlinks an action from a context.
+    ///
+    /// Returns [`StorageError::NotFound`] if the action is not linked to the context.
+    fn delete_action_context(
+        &self,
+        action_id: &ActionId,
+        context_id: &ContextId,
+    ) -> Result<(), StorageError>;
+
+    /// Fetches the contexts an action is linked to, ordered by their name.
+    fn list_action_contexts(&self, action_id: &ActionId) -> Result<Vec<Context>, StorageError>;
+
+    /// Links an action to a tag.
+    ///
+    /// Linking an action to a tag it is already linked to does nothing.
+    fn insert_action_tag(&self, action_id: &ActionId, tag_id: &TagId) -> Result<(), StorageError>;
+
+    /// Unlinks an action from a tag.
+    ///
+    /// Returns [`StorageError::NotFound`] if the action is not linked to the tag.
+    fn delete_action_tag(&self, action_id: &ActionId, tag_id: &TagId) -> Result<(), StorageError>;
+
+    /// Fetches the tags an action is linked to, ordered by their path.
+    fn list_action_tags(&self, action_id: &ActionId) -> Result<Vec<Tag>, StorageError>;
+
+    /// Links a project to a tag.
+    ///
+    /// Linking a project to a tag it is already linked to does nothing.
+    fn insert_project_tag(
+        &self,
+        project_id: &ProjectId,
+        tag_id: &TagId,
+    ) -> Result<(), StorageError>;
+
+    /// Unlinks a project from a tag.
+    ///
+    /// Returns [`StorageError::NotFound`] if the project is not linked to the tag.
+    fn delete_project_tag(
+        &self,
+        project_id: &ProjectId,
+        tag_id: &TagId,
+    ) -> Result<(), StorageError>;
+
+    /// Fetches the tags a project is linked to, ordered by their path.
+    fn list_project_tags(&self, project_id: &ProjectId) -> Result<Vec<Tag>, StorageError>;
+
+    /// Tries to insert a review together with its completed steps.
+    ///
+    /// Fails if another review is still in progress.
+    fn insert_review(&self, review: &Review) -> Result<(), StorageError>;
+
+    /// Fetches the review that is still in progress.
+    ///
+    /// Returns [`StorageError::NotFound`] if no review is in progress.
+    fn get_review_in_progress(&self) -> Result<Review, StorageError>;
+
+    /// Fetches all reviews together with their completed steps, ordered by their start.
+    fn list_reviews(&self) -> Result<Vec<Review>, StorageError>;
+
+    /// Fetches the review that was completed last.
+    ///
+    /// Returns [`StorageError::NotFound`] if no review was ever completed.
+    fn get_last_completed_review(&self) -> Result<Review, StorageError>;
+
+    /// Overwrites a stored review and its completed steps with the given one, in one
+    /// transaction.
+    ///
+    /// Returns [`StorageError::NotFound`] if no such review exists.
+    fn update_review(&self, review: &Review) -> Result<(), StorageError>;
+
+    /// Searches the names and notes of actions, the names of projects and the texts of reference
+    /// items for the words in `text`, best matches first.
+    ///
+    /// Every word of `text` has to match the start of a word of an item. FTS5 operators in
+    /// `text` are taken literally. Text without words matches nothing.
+    fn search(&self, text: &str) -> Result<Vec<SearchRow>, StorageError>;
+
+    /// Discards the changes logged outside of a history step, so the next step only covers the
+    /// changes made from now on.
+    ///
+    /// Every change to the data is logged as the statement reverting it, see
+    /// [Storage::finish_history_step].
+    fn start_history_step(&self) -> Result<(), StorageError>;
+
+    /// Groups the changes logged since [Storage::start_history_step] into a new step of the
+    /// history, named after the `operation` that made them, in one transaction.
+    ///
+    /// Nothing happens if no change was logged. Otherwise undone steps can no longer be redone
+    /// and are dropped, as are the oldest steps beyond `max_steps` and those beyond `max_entries`
+    /// logged changes in total. The new step is kept even if it alone exceeds `max_entries`.
+    fn finish_history_step(
+        &self,
+        operation: &str,
+        max_steps: usize,
+        max_entries: usize,
+    ) -> Result<(), StorageError>;
+
+    /// Reverts the changes of the last step of the history that is not undone, in one
+    /// transaction.
+    ///
+    /// Returns the operation of the step, or `None` if there is no step to undo.
+    fn undo_history_step(&self) -> Result<Option<String>, StorageError>;
+
+    /// Reapplies the changes of the step of the history that was undone last, in one
+    /// transaction.
+    ///
+    /// Returns the operation of the step, or `None` if there is no step to redo.
+    fn redo_history_step(&self) -> Result<Option<String>, StorageError>;
+
+    /// Reads the whole content of the database in one transaction.
+    fn export_data(&self) -> Result<DataSet, StorageError>;
+
+    /// Inserts everything in the [DataSet] in one transaction.
+    ///
+    /// Existing data is kept. If any item can't be inserted, e.g. because its id is already
+    /// taken, nothing is changed.
+    fn import_data(&self, data: &DataSet) -> Result<(), StorageError>;
+}
+
+/// The complete content of a database, as read by [Storage::export_data].
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct DataSet {
+    pub areas: Vec<Area>,
+    pub actions: Vec<Action>,
+    pub projects: Vec<Project>,
+    pub contexts: Vec<Context>,
+    /// Links between actions and contexts.
+    pub action_contexts: Vec<(ActionId, ContextId)>,
+    pub tags: Vec<Tag>,
+    /// Links between actions and tags.
+    pub action_tags: Vec<(ActionId, TagId)>,
+    /// Links between projects and tags.
+    pub project_tags: Vec<(ProjectId, TagId)>,
+    pub perspectives: Vec<Perspective>,
+    /// Reviews with their completed steps, including the one in progress.
+    pub reviews: Vec<Review>,
+    pub inbox_items: Vec<InboxItem>,
+    pub reference_items: Vec<ReferenceItem>,
+}
+
+/// Criteria for selecting actions via [Storage::list_actions].
+///
+/// Every criterion that is `None` matches all actions.
+#[derive(Debug, Clone, Default)]
+pub struct ActionFilter {
+    /// The order in which matching actions are returned.
+    pub order: ActionOrder,
+    /// Selects either completed (`true`) or open (`false`) actions.
+    pub completed: Option<bool>,
+    /// Selects actions belonging to the given project.
+    pub project_id: Option<ProjectId>,
+    /// Selects actions linked to the given context.
+    pub context_id: Option<ContextId>,
+    /// Selects actions linked to the tag with the given path or to one of its descendants.
+    pub tag: Option<TagPath>,
+    /// Selects actions that are not deferred past the given moment.
+    pub available_at: Option<DateTime<Utc>>,
+    /// Selects actions due at or after the given moment.
+    pub due_from: Option<DateTime<Utc>>,
+    /// Selects actions due before the given moment.
+    pub due_before: Option<DateTime<Utc>>,
+    /// Selects either delegated (`true`) or not delegated (`false`) actions.
+    pub delegated: Option<bool>,
+    /// Selects either someday/maybe (`true`) or committed (`false`) actions.
+    ///
+    /// Actions belonging to a someday/maybe project count as someday/maybe as well.
+    pub someday: Option<bool>,
+    /// Selects only actions not preceded by an open, committed action of their sequential
+    /// project, if `true`.
+    pub unblocked: bool,
+}
+
+/// Criteria selecting projects in [Storage::list_projects].
+///
+/// Criteria that are `None` match every project.
+#[derive(Debug, Clone, Default)]
+pub struct ProjectFilter {
+    /// Selects either someday/maybe (`true`) or committed (`false`) projects.
+    pub someday: Option<bool>,
+    /// Selects projects linked to the tag with the given path or to one of its descendants.
+    pub tag: Option<TagPath>,
+}
+
+/// A project returned by [Storage::list_stalled_projects], with its last completion date.
+pub type StalledProjectRow = (Project, Option<DateTime<Utc>>);
+
+/// An item found by [Storage::search], with its highlighted title and notes snippet, see
+/// [SearchResult](crate::core::responses::SearchResult).
+pub type SearchRow = (SearchItem, String, Option<String>);
+
+/// An area returned by [Storage::list_area_summaries], with its number of open actions and of
+/// stalled projects.
+pub type AreaSummaryRow = (Area, usize, usize);
+
+/// The order of actions returned by [Storage::list_actions].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum ActionOrder {
+    /// Oldest actions first.
+    #[default]
+    CreateDate,
+    /// Actions due first come first.
+    ///
+    /// Actions without a due date come before all others.
+    DueDate,
+    /// Actions to follow up on first come first.
+    ///
+    /// Actions without a follow-up date come after all others.
+    FollowUp,
+    /// Actions are ordered by their position within their project.
+    ///
+    /// Actions without a position come after all others.
+    Position,
+}
+
+/// What an inbox item is replaced with by [Storage::process_inbox_item].
+#[derive(Debug, Clone)]
+pub enum InboxItemTarget {
+    /// The inbox item becomes an action.
+    Action(Box<Action>),
+    /// The inbox item becomes a project.
+    Project(Project),
+    /// The inbox item becomes a reference item.
+    Reference(ReferenceItem),
+    /// The inbox item is discarded.
+    Trash,
 }
 
 /// Factory method returning a new production ready [Storage] instance.
-pub fn new_production_storage(db_path: &str) -> Result<impl Storage, StorageError> {
+pub fn new_production_storage(db_path: &Path) -> Result<impl Storage + use<>, StorageError> {
     SqliteStorage::new_persistence(db_path)
 }
 
@@ -50,7 +514,25 @@ pub fn new_in_memory_storage() -> Result<impl Storage, StorageError> {
 #[cfg(test)]
 mod tests {
     use super::*;
-    use crate::model::action::test_utils::dummy_action;
+    use crate::model::{
+        action::{
+            action_complete_date::ActionCompleteDate,
+            action_defer_date::ActionDeferDate,
+            action_due_date::ActionDueDate,
+            action_name::ActionName,
+            action_notes::ActionNotes,
+            action_recurrence::{ActionRecurrence, Frequency, RecurrenceBase},
+            test_utils::dummy_action,
+        },
+        area::area_name::AreaName,
+        context::test_utils::dummy_context,
+        inbox_item::test_utils::dummy_inbox_item,
+        project::{
+            project_name::ProjectName, project_status::ProjectStatus, test_utils::dummy_project,
+        },
+        tag::{Tag, tag_id::TagId, tag_path::TagPath},
+    };
+    use chrono::Duration;
 
     #[test]
     fn test_insert_action_is_ok() {
@@ -62,6 +544,519 @@ mod tests {
         assert!(storage.insert_action(&action).is_ok());
     }
 
+    #[test]
+    fn test_get_inserted_action_returns_equal_action() {
+        crate::test_utils::init_test_logging();
+
+        let mut storage = new_in_memory_storage().unwrap();
+        let _ = storage.run_migrations(&migration::builtin_migrations());
+        let action = dummy_action();
+        storage.insert_action(&action).unwrap();
+        assert_eq!(storage.get_action(action.action_id()).unwrap(), action);
+    }
+
+    #[test]
+    fn test_get_unknown_action_is_not_found() {
+        crate::test_utils::init_test_logging();
+
+        let mut storage = new_in_memory_storage().unwrap();
+        let _ = storage.run_migrations(&migration::builtin_migrations());
+        let result = storage.get_action(&ActionId::new());
+        assert!(matches!(result, Err(StorageError::NotFound)));
+    }
+
+    #[test]
+    fn test_list_actions_returns_all_actions() {
+        crate::test_utils::init_test_logging();
+
+        let mut storage = new_in_memory_storage().unwrap();
+        let _ = storage.run_migrations(&migration::builtin_migrations());
+        let action_1 = dummy_action();
+        let action_2 = dummy_action();
+        storage.insert_action(&action_1).unwrap();
+        storage.insert_action(&action_2).unwrap();
+        assert_eq!(
+            storage.list_actions(&ActionFilter::default()).unwrap(),
+            vec![action_1, action_2]
+        );
+    }
+
+    #[test]
+    fn test_list_actions_filters_by_completion() {
+        crate::test_utils::init_test_logging();
+
+        let mut storage = new_in_memory_storage().unwrap();
+        let _ = storage.run_migrations(&migration::builtin_migrations());
+        let open_action = dummy_action();
+        let mut completed_action = dummy_action();
+        completed_action.set_action_complete_date(Some(ActionCompleteDate::now()));
+        storage.insert_action(&open_action).unwrap();
+        storage.insert_action(&completed_action).unwrap();
+        let open_filter = ActionFilter {
+            completed: Some(false),
+            ..Default::default()
+        };
+        let completed_filter = ActionFilter {
+            completed: Some(true),
+            ..Default::default()
+        };
+        assert_eq!(
+            storage.list_actions(&open_filter).unwrap(),
+            vec![open_action]
+        );
+        assert_eq!(
+            storage.list_actions(&completed_filter).unwrap(),
+            vec![completed_action]
+        );
+    }
+
+    #[test]
+    fn test_list_actions_filters_by_project() {
+        crate::test_utils::init_test_logging();
+
+        let mut storage = new_in_memory_storage().unwrap();
+        let _ = storage.run_migrations(&migration::builtin_migrations());
+        let project = dummy_project();
+        storage.insert_project(&project).unwrap();
+        let mut project_action = dummy_action();
+        project_action.set_project_id(Some(project.project_id().clone()));
+        storage.insert_action(&project_action).unwrap();
+        storage.insert_action(&dummy_action()).unwrap();
+
+        let filter = ActionFilter {
+            project_id: Some(project.project_id().clone()),
+            ..Default::default()
+        };
+        assert_eq!(storage.list_actions(&filter).unwrap(), vec![project_action]);
+    }
+
+    #[test]
+    fn test_insert_action_with_unknown_project_fails() {
+        crate::test_utils::init_test_logging();
+
+        let mut storage = new_in_memory_storage().unwrap();
+        let _ = storage.run_migrations(&migration::builtin_migrations());
+        let mut action = dummy_action();
+        action.set_project_id(Some(ProjectId::new()));
+        assert!(storage.insert_action(&action).is_err());
+    }
+
+    #[test]
+    fn test_project_crud() {
+        crate::test_utils::init_test_logging();
+
+        let mut storage = new_in_memory_storage().unwrap();
+        let _ = storage.run_migrations(&migration::builtin_migrations());
+        let mut project = dummy_project();
+        storage.insert_project(&project).unwrap();
+        assert_eq!(storage.get_project(project.project_id()).unwrap(), project);
+
+        project.set_project_status(ProjectStatus::OnHold);
+        storage.update_project(&project).unwrap();
+        assert_eq!(
+            storage.list_projects(&ProjectFilter::default()).unwrap(),
+            vec![project.clone()]
+        );
+
+        storage.delete_project(project.project_id()).unwrap();
+        let result = storage.get_project(project.project_id());
+        assert!(matches!(result, Err(StorageError::NotFound)));
+    }
+
+    #[test]
+    fn test_delete_project_detaches_actions() {
+        crate::test_utils::init_test_logging();
+
+        let mut storage = new_in_memory_storage().unwrap();
+        let _ = storage.run_migrations(&migration::builtin_migrations());
+        let project = dummy_project();
+        storage.insert_project(&project).unwrap();
+        let mut action = dummy_action();
+        action.set_project_id(Some(project.project_id().clone()));
+        storage.insert_action(&action).unwrap();
+
+        storage.delete_project(project.project_id()).unwrap();
+        let action = storage.get_action(action.action_id()).unwrap();
+        assert_eq!(action.project_id(), None);
+    }
+
+    #[test]
+    fn test_update_action_persists_changes() {
+        crate::test_utils::init_test_logging();
+
+        let mut storage = new_in_memory_storage().unwrap();
+        let _ = storage.run_migrations(&migration::builtin_migrations());
+        let mut action = dummy_action();
+        storage.insert_action(&action).unwrap();
+        action.set_action_name(ActionName::new("Renamed Action").unwrap());
+        action.set_action_notes(Some(ActionNotes::new("- [ ] first\n- [ ] second\n")));
+        storage.update_action(&action).unwrap();
+        assert_eq!(storage.get_action(action.action_id()).unwrap(), action);
+    }
+
+    #[test]
+    fn test_action_recurrence_is_persisted() {
+        crate::test_utils::init_test_logging();
+
+        let mut storage = new_in_memory_storage().unwrap();
+        let _ = storage.run_migrations(&migration::builtin_migrations());
+        let mut action = dummy_action();
+        action.set_action_recurrence(Some(
+            ActionRecurrence::new(Frequency::Monthly)
+                .with_month_days(&[1, 15])
+                .count(3)
+                .based_on(RecurrenceBase::Completion),
+        ));
+        storage.insert_action(&action).unwrap();
+        assert_eq!(storage.get_action(action.action_id()).unwrap(), action);
+
+        action.set_action_recurrence(None);
+        storage.update_action(&action).unwrap();
+        assert_eq!(storage.get_action(action.action_id()).unwrap(), action);
+    }
+
+    #[test]
+    fn test_complete_recurring_action_copies_contexts() {
+        crate::test_utils::init_test_logging();
+
+        let mut storage = new_in_memory_storage().unwrap();
+        let _ = storage.run_migrations(&migration::builtin_migrations());
+        let context = dummy_context();
+        storage.insert_context(&context).unwrap();
+        let mut action = dummy_action();
+        storage.insert_action(&action).unwrap();
+        storage
+            .insert_action_context(action.action_id(), context.context_id())
+            .unwrap();
+
+        action.set_action_complete_date(Some(ActionCompleteDate::now()));
+        let next = dummy_action();
+        storage.complete_recurring_action(&action, &next).unwrap();
+        assert_eq!(storage.get_action(action.action_id()).unwrap(), action);
+        assert_eq!(storage.get_action(next.action_id()).unwrap(), next);
+        assert_eq!(
+            storage.list_action_contexts(next.action_id()).unwrap(),
+            vec![context]
+        );
+    }
+
+    #[test]
+    fn test_complete_recurring_action_is_atomic() {
+        crate::test_utils::init_test_logging();
+
+        let mut storage = new_in_memory_storage().unwrap();
+        let _ = storage.run_migrations(&migration::builtin_migrations());
+        let action = dummy_action();
+        storage.insert_action(&action).unwrap();
+
+        // The next occurrence clashes with the completed one, so its insert fails.
+        let mut completed = action.clone();
+        completed.set_action_complete_date(Some(ActionCompleteDate::now()));
+        assert!(
+            storage
+                .complete_recurring_action(&completed, &action)
+                .is_err()
+        );
+        assert_eq!(storage.get_action(action.action_id()).unwrap(), action);
+
+        let result = storage.complete_recurring_action(&dummy_action(), &dummy_action());
+        assert!(matches!(result, Err(StorageError::NotFound)));
+    }
+
+    #[test]
+    fn test_review_lifecycle() {
+        use crate::model::review::{review_id::ReviewId, review_step::ReviewStep};
+
+        crate::test_utils::init_test_logging();
+
+        let mut storage = new_in_memory_storage().unwrap();
+        let _ = storage.run_migrations(&migration::builtin_migrations());
+        assert!(matches!(
+            storage.get_review_in_progress(),
+            Err(StorageError::NotFound)
+        ));
+
+        let start = chrono::Utc::now();
+        let mut review = Review::new(ReviewId::new(), start);
+        storage.insert_review(&review).unwrap();
+        assert_eq!(storage.get_review_in_progress().unwrap(), review);
+        // Only one review can be in progress.
+        assert!(
+            storage
+                .insert_review(&Review::new(ReviewId::new(), start))
+                .is_err()
+        );
+
+        for (minutes, step) in ReviewStep::ALL.into_iter().enumerate() {
+            review.complete_step(step, start + Duration::minutes(minutes as i64));
+            storage.update_review(&review).unwrap();
+        }
+        assert!(matches!(
+            storage.get_review_in_progress(),
+            Err(StorageError::NotFound)
+        ));
+        assert_eq!(storage.get_last_completed_review().unwrap(), review);
+    }
+
+    #[test]
+    fn test_update_unknown_action_is_not_found() {
+        crate::test_utils::init_test_logging();
+
+        let mut storage = new_in_memory_storage().unwrap();
+        let _ = storage.run_migrations(&migration::builtin_migrations());
+        let result = storage.update_action(&dummy_action());
+        assert!(matches!(result, Err(StorageError::NotFound)));
+    }
+
+    #[test]
+    fn test_delete_action_removes_action() {
+        crate::test_utils::init_test_logging();
+
+        let mut storage = new_in_memory_storage().unwrap();
+        let _ = storage.run_migrations(&migration::builtin_migrations());
+        let action = dummy_action();
+        storage.insert_action(&action).unwrap();
+        storage.delete_action(action.action_id()).unwrap();
+        let result = storage.get_action(action.action_id());
+        assert!(matches!(result, Err(StorageError::NotFound)));
+    }
+
+    #[test]
+    fn test_delete_unknown_action_is_not_found() {
+        crate::test_utils::init_test_logging();
+
+        let mut storage = new_in_memory_storage().unwrap();
+        let _ = storage.run_migrations(&migration::builtin_migrations());
+        let result = storage.delete_action(&ActionId::new());
+        assert!(matches!(result, Err(StorageError::NotFound)));
+    }
+
+    #[test]
+    fn test_process_inbox_item_replaces_item() {
+        crate::test_utils::init_test_logging();
+
+        let mut storage = new_in_memory_storage().unwrap();
+        let _ = storage.run_migrations(&migration::builtin_migrations());
+        let inbox_item = dummy_inbox_item();
+        storage.insert_inbox_item(&inbox_item).unwrap();
+        assert_eq!(
+            storage.list_inbox_items().unwrap(),
+            vec![inbox_item.clone()]
+        );
+
+        let action = dummy_action();
+        storage
+            .process_inbox_item(
+                inbox_item.inbox_item_id(),
+                &InboxItemTarget::Action(Box::new(action.clone())),
+            )
+            .unwrap();
+        assert!(storage.list_inbox_items().unwrap().is_empty());
+        assert_eq!(storage.get_action(action.action_id()).unwrap(), action);
+    }
+
+    #[test]
+    fn test_failed_processing_keeps_inbox_item() {
+        crate::test_utils::init_test_logging();
+
+        let mut storage = new_in_memory_storage().unwrap();
+        let _ = storage.run_migrations(&migration::builtin_migrations());
+        let inbox_item = dummy_inbox_item();
+        storage.insert_inbox_item(&inbox_item).unwrap();
+
+        // The action refers to a project that does not exist, so inserting it fails.
+        let mut action = dummy_action();
+        action.set_project_id(Some(ProjectId::new()));
+        let result = storage.process_inbox_item(
+            inbox_item.inbox_item_id(),
+            &InboxItemTarget::Action(Box::new(action)),
+        );
+        assert!(result.is_err());
+        assert_eq!(storage.list_inbox_items().unwrap(), vec![inbox_item]);
+    }
+
+    #[test]
+    fn test_process_unknown_inbox_item_is_not_found() {
+        crate::test_utils::init_test_logging();
+
+        let mut storage = new_in_memory_storage().unwrap();
+        let _ = storage.run_migrations(&migration::builtin_migrations());
+        let result = storage.process_inbox_item(&InboxItemId::new(), &InboxItemTarget::Trash);
+        assert!(matches!(result, Err(StorageError::NotFound)));
+    }
+
+    #[test]
+    fn test_list_actions_filters_by_context() {
+        crate::test_utils::init_test_logging();
+
+        let mut storage = new_in_memory_storage().unwrap();
+        let _ = storage.run_migrations(&migration::builtin_migrations());
+        let context = dummy_context();
+        storage.insert_context(&context).unwrap();
+        let action = dummy_action();
+        storage.insert_action(&action).unwrap();
+        storage.insert_action(&dummy_action()).unwrap();
+        storage
+            .insert_action_context(action.action_id(), context.context_id())
+            .unwrap();
+        // Linking twice is fine.
+        storage
+            .insert_action_context(action.action_id(), context.context_id())
+            .unwrap();
+
+        let filter = ActionFilter {
+            context_id: Some(context.context_id().clone()),
+            ..Default::default()
+        };
+        assert_eq!(storage.list_actions(&filter).unwrap(), vec![action.clone()]);
+        assert_eq!(
+            storage.list_action_contexts(action.action_id()).unwrap(),
+            vec![context]
+        );
+    }
+
+    #[test]
+    fn test_move_tags_merges_colliding_tags() {
+        crate::test_utils::init_test_logging();
+
+        let mut storage = new_in_memory_storage().unwrap();
+        let _ = storage.run_migrations(&migration::builtin_migrations());
+        let insert_tag = |path: &str| {
+            let tag = Tag::new(TagId::new(), TagPath::new(path).unwrap());
+            storage.insert_tag(&tag).unwrap();
+            tag
+        };
+        let work = insert_tag("work");
+        let work_a = insert_tag("work/a");
+        insert_tag("workshop");
+        let home_a = insert_tag("home/a");
+        let action = dummy_action();
+        storage.insert_action(&action).unwrap();
+        storage
+            .insert_action_tag(action.action_id(), work_a.tag_id())
+            .unwrap();
+        let project = dummy_project();
+        storage.insert_project(&project).unwrap();
+        for tag in [&work_a, &home_a] {
+            storage
+                .insert_project_tag(project.project_id(), tag.tag_id())
+                .unwrap();
+        }
+
+        let tagged = |path: &str| ActionFilter {
+            tag: Some(TagPath::new(path).unwrap()),
+            ..Default::default()
+        };
+        assert_eq!(
+            storage.list_actions(&tagged("work")).unwrap(),
+            vec![action.clone()]
+        );
+        assert!(
+            storage
+                .list_actions(&tagged("workshop"))
+                .unwrap()
+                .is_empty()
+        );
+
+        storage
+            .move_tags(work.tag_path(), &TagPath::new("home").unwrap())
+            .unwrap();
+
+        let paths: Vec<_> = storage
+            .list_tags()
+            .unwrap()
+            .iter()
+            .map(|tag| tag.tag_path().as_str().to_owned())
+            .collect();
+        assert_eq!(paths, vec!["home", "home/a", "workshop"]);
+        assert_eq!(
+            storage.get_tag(work.tag_id()).unwrap().tag_path().as_str(),
+            "home"
+        );
+        assert_eq!(
+            storage.list_action_tags(action.action_id()).unwrap(),
+            vec![home_a.clone()]
+        );
+        assert_eq!(
+            storage.list_project_tags(project.project_id()).unwrap(),
+            vec![home_a]
+        );
+        assert_eq!(storage.list_actions(&tagged("home")).unwrap(), vec![action]);
+        let filter = ProjectFilter {
+            tag: Some(TagPath::new("home").unwrap()),
+            ..Default::default()
+        };
+        assert_eq!(storage.list_projects(&filter).unwrap(), vec![project]);
+    }
+
+    #[test]
+    fn test_delete_context_in_use_fails() {
+        crate::test_utils::init_test_logging();
+
+        let mut storage = new_in_memory_storage().unwrap();
+        let _ = storage.run_migrations(&migration::builtin_migrations());
+        let context = dummy_context();
+        storage.insert_context(&context).unwrap();
+        let action = dummy_action();
+        storage.insert_action(&action).unwrap();
+        storage
+            .insert_action_context(action.action_id(), context.context_id())
+            .unwrap();
+
+        assert!(storage.delete_context(context.context_id()).is_err());
+        storage
+            .delete_action_context(action.action_id(), context.context_id())
+            .unwrap();
+        storage.delete_context(context.context_id()).unwrap();
+        assert!(storage.list_contexts().unwrap().is_empty());
+    }
+
+    #[test]
+    fn test_list_actions_filters_by_dates() {
+        crate::test_utils::init_test_logging();
+
+        let mut storage = new_in_memory_storage().unwrap();
+        let _ = storage.run_migrations(&migration::builtin_migrations());
+        let now = Utc::now();
+        let mut overdue = dummy_action();
+        overdue.set_action_due_date(Some(ActionDueDate::from_value(now - Duration::days(1))));
+        let mut due_soon = dummy_action();
+        due_soon.set_action_due_date(Some(ActionDueDate::from_value(now + Duration::days(2))));
+        let mut deferred = dummy_action();
+        deferred.set_action_defer_date(Some(ActionDeferDate::from_value(now + Duration::days(1))));
+        for action in [&overdue, &due_soon, &deferred] {
+            storage.insert_action(action).unwrap();
+        }
+
+        let available = ActionFilter {
+            available_at: Some(now),
+            ..Default::default()
+        };
+        assert_eq!(
+            storage.list_actions(&available).unwrap(),
+            vec![overdue.clone(), due_soon.clone()]
+        );
+
+        let overdue_filter = ActionFilter {
+            due_before: Some(now),
+            ..Default::default()
+        };
+        assert_eq!(
+            storage.list_actions(&overdue_filter).unwrap(),
+            vec![overdue.clone()]
+        );
+
+        let due_within_a_week = ActionFilter {
+            due_before: Some(now + Duration::days(7)),
+            order: ActionOrder::DueDate,
+            ..Default::default()
+        };
+        assert_eq!(
+            storage.list_actions(&due_within_a_week).unwrap(),
+            vec![overdue, due_soon]
+        );
+    }
+
     #[test]
     fn test_schema_version_for_new_databse_is_0() {
         crate::test_utils::init_test_logging();
@@ -79,4 +1074,143 @@ mod tests {
         let result = storage.run_migrations(&migration::builtin_migrations());
         assert!(result.is_ok())
     }
+
+    #[test]
+    fn test_search_index_follows_changes() {
+        crate::test_utils::init_test_logging();
+
+        let mut storage = new_in_memory_storage().unwrap();
+        let before_search = migration::MIGRATIONS[..15].to_vec();
+        storage
+            .run_migrations(&migration::test_migrations(before_search))
+            .unwrap();
+        let project = dummy_project();
+        storage.insert_project(&project).unwrap();
+        storage
+            .run_migrations(&migration::builtin_migrations())
+            .unwrap();
+        let mut action = dummy_action();
+        action.set_action_name(ActionName::new("Write report").unwrap());
+        action.set_action_notes(Some(ActionNotes::new("Ask Müller about the budget")));
+        storage.insert_action(&action).unwrap();
+
+        let found = |text: &str| {
+            storage
+                .search(text)
+                .unwrap()
+                .into_iter()
+                .map(|(item, _, _)| item)
+                .collect::<Vec<_>>()
+        };
+        let action_item = SearchItem::Action(action.action_id().clone());
+        assert_eq!(
+            found(project.project_name().as_str()),
+            vec![SearchItem::Project(project.project_id().clone())]
+        );
+        assert_eq!(
+            storage.search("muller rep").unwrap(),
+            vec![(
+                action_item.clone(),
+                String::from("Write <mark>report</mark>"),
+                Some(String::from("Ask <mark>Müller</mark> about the budget")),
+            )]
+        );
+        assert_eq!(found(r#"rep" OR -"#), Vec::new());
+        assert_eq!(found("  "), Vec::new());
+        assert_eq!(found("-"), Vec::new());
+
+        action.set_action_notes(None);
+        storage.update_action(&action).unwrap();
+        assert_eq!(found("muller"), Vec::new());
+        assert_eq!(found("report"), vec![action_item]);
+
+        storage.delete_action(action.action_id()).unwrap();
+        assert_eq!(found("report"), Vec::new());
+
+        // Items indexed before the rows were mapped to their items follow changes as well.
+        let mut project = project;
+        let project_item = SearchItem::Project(project.project_id().clone());
+        project.set_project_name(ProjectName::new("Plan offsite"));
+        storage.update_project(&project).unwrap();
+        assert_eq!(found("offsite"), vec![project_item]);
+        storage.delete_project(project.project_id()).unwrap();
+        assert_eq!(found("offsite"), Vec::new());
+    }
+
+    #[test]
+    fn test_history_is_bounded() {
+        crate::test_utils::init_test_logging();
+
+        let mut storage = new_in_memory_storage().unwrap();
+        storage
+            .run_migrations(&migration::builtin_migrations())
+            .unwrap();
+        for name in ["Health", "Finance", "Team"] {
+            storage.start_history_step().unwrap();
+            let area = Area::new(AreaId::new(), AreaName::new(name).unwrap());
+            storage.insert_area(&area).unwrap();
+            storage.finish_history_step(name, 2, 100).unwrap();
+        }
+
+        assert_eq!(
+            storage.undo_history_step().unwrap().as_deref(),
+            Some("Team")
+        );
+        assert_eq!(
+            storage.undo_history_step().unwrap().as_deref(),
+            Some("Finance")
+        );
+        assert_eq!(storage.undo_history_step().unwrap(), None);
+        let names: Vec<_> = storage
+            .list_areas()
+            .unwrap()
+            .iter()
+            .map(|area| area.area_name().as_str().to_owned())
+            .collect();
+        assert_eq!(names, vec!["Health"]);
+    }
+
+    #[test]
+    fn test_history_entries_are_bounded() {
+        crate::test_utils::init_test_logging();
+
+        /// Records the insertion of `count` areas as one history step.
+        fn insert_areas(storage: &impl Storage, operation: &str, count: usize) {
+            storage.start_history_step().unwrap();
+            for i in 0..count {
+                let name = AreaName::new(&format!("{operation} {i}")).unwrap();
+                storage
+                    .insert_area(&Area::new(AreaId::new(), name))
+                    .unwrap();
+            }
+            storage.finish_history_step(operation, 100, 5).unwrap();
+        }
+
+        let mut storage = new_in_memory_storage().unwrap();
+        storage
+            .run_migrations(&migration::builtin_migrations())
+            .unwrap();
+        insert_areas(&storage, "First", 2);
+        insert_areas(&storage, "Second", 2);
+        insert_areas(&storage, "Third", 3);
+
+        assert_eq!(
+            storage.undo_history_step().unwrap().as_deref(),
+            Some("Third")
+        );
+        assert_eq!(
+            storage.undo_history_step().unwrap().as_deref(),
+            Some("Second")
+        );
+        assert_eq!(storage.undo_history_step().unwrap(), None);
+        assert_eq!(storage.list_areas().unwrap().len(), 2);
+
+        // The newest step is kept, even though it exceeds the limit on its own.
+        insert_areas(&storage, "Import", 6);
+        assert_eq!(
+            storage.undo_history_step().unwrap().as_deref(),
+            Some("Import")
+        );
+        assert_eq!(storage.undo_history_step().unwrap(), None);
+    }
 }
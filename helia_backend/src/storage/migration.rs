@@ -95,7 +95,77 @@ macro_rules! include_migration {
 /// This static array holds all migrations defined in `migrations/` in version order.
 /// It is is used by the storage backend to determine which migrations need to be applied
 /// based on the current schema version of the database.
-pub static MIGRATIONS: &[Migration] = &[Migration {
-    version: 1,
-    sql: include_migration!("001_initial.sql"),
-}];
+pub static MIGRATIONS: &[Migration] = &[
+    Migration {
+        version: 1,
+        sql: include_migration!("001_initial.sql"),
+    },
+    Migration {
+        version: 2,
+        sql: include_migration!("002_action_completion.sql"),
+    },
+    Migration {
+        version: 3,
+        sql: include_migration!("003_projects.sql"),
+    },
+    Migration {
+        version: 4,
+        sql: include_migration!("004_inbox.sql"),
+    },
+    Migration {
+        version: 5,
+        sql: include_migration!("005_contexts.sql"),
+    },
+    Migration {
+        version: 6,
+        sql: include_migration!("006_action_dates.sql"),
+    },
+    Migration {
+        version: 7,
+        sql: include_migration!("007_action_notes.sql"),
+    },
+    Migration {
+        version: 8,
+        sql: include_migration!("008_action_recurrence.sql"),
+    },
+    Migration {
+        version: 9,
+        sql: include_migration!("009_action_delegation.sql"),
+    },
+    Migration {
+        version: 10,
+        sql: include_migration!("010_someday_maybe.sql"),
+    },
+    Migration {
+        version: 11,
+        sql: include_migration!("011_weekly_review.sql"),
+    },
+    Migration {
+        version: 12,
+        sql: include_migration!("012_project_ordering.sql"),
+    },
+    Migration {
+        version: 13,
+        sql: include_migration!("013_areas.sql"),
+    },
+    Migration {
+        version: 14,
+        sql: include_migration!("014_tags.sql"),
+    },
+    Migration {
+        version: 15,
+        sql: include_migration!("015_perspectives.sql"),
+    },
+    Migration {
+        version: 16,
+        sql: include_migration!("016_search.sql"),
+    },
+    Migration {
+        version: 17,
+        sql: include_migration!("017_history.sql"),
+    },
+    Migration {
+        version: 18,
+        sql: include_migration!("018_search_rowids.sql"),
+    },
+];
@@ -1,17 +1,546 @@
 //! SQLite implementation of the [Storage] trait.
 
-use rusqlite::Connection;
+use std::path::Path;
+
+use chrono::{DateTime, Utc};
+use rusqlite::{
+    CachedStatement, Connection, OptionalExtension, Row, ToSql, named_params, params_from_iter,
+    types::Type,
+};
 use tracing::{debug, error, info, instrument};
 
 use crate::{
-    model::action::Action,
+    core::{
+        requests::{ActionPredicate, ActionQuery, ActionSortKey, SortDirection},
+        responses::{SearchItem, SearchResult},
+    },
+    model::{
+        action::{
+            Action,
+            acion_create_date::ActionCreateDate,
+            action_complete_date::ActionCompleteDate,
+            action_defer_date::ActionDeferDate,
+            action_delegation::ActionDelegation,
+            action_due_date::ActionDueDate,
+            action_id::ActionId,
+            action_name::ActionName,
+            action_notes::ActionNotes,
+            action_recurrence::{ActionRecurrence, RecurrenceBase},
+        },
+        area::{Area, area_id::AreaId, area_name::AreaName},
+        context::{Context, context_id::ContextId, context_name::ContextName},
+        inbox_item::{
+            InboxItem, inbox_item_capture_date::InboxItemCaptureDate, inbox_item_id::InboxItemId,
+            inbox_item_text::InboxItemText,
+        },
+        perspective::{
+            Perspective, perspective_id::PerspectiveId, perspective_name::PerspectiveName,
+            perspective_query::PerspectiveQuery,
+        },
+        project::{
+            Project, project_create_date::ProjectCreateDate, project_id::ProjectId,
+            project_name::ProjectName, project_ordering::ProjectOrdering,
+            project_review_date::ProjectReviewDate, project_status::ProjectStatus,
+        },
+        reference_item::{
+            ReferenceItem, reference_item_create_date::ReferenceItemCreateDate,
+            reference_item_id::ReferenceItemId, reference_item_text::ReferenceItemText,
+        },
+        review::{Review, review_id::ReviewId},
+        tag::{Tag, tag_id::TagId, tag_path::TagPath},
+    },
     storage::{
+        ActionFilter, ActionOrder, AreaSummaryRow, DataSet, InboxItemTarget, ProjectFilter,
+        SearchRow, StalledProjectRow, Storage,
         migration::{Migration, MigrationSource},
         storage_error::StorageError,
-        Storage,
     },
 };
 
+/// The columns selected when reading actions, in the order expected by [action_from_row].
+const ACTION_COLUMNS: &str = "id, title, created_at, completed_at, project_id, someday, due_at, \
+     defer_until, notes, recurrence, recur_from_completion, delegated_to, delegated_at, \
+     follow_up_at, position, area_id";
+
+/// Maps a row selected with [ACTION_COLUMNS] to an [Action].
+fn action_from_row(row: &Row) -> rusqlite::Result<Action> {
+    let mut action = Action::new(
+        ActionId::from_uuid(row.get(0)?),
+        ActionName::from_stored(&row.get::<_, String>(1)?),
+        ActionCreateDate::from_value(row.get(2)?),
+    );
+    action.set_action_complete_date(
+        row.get::<_, Option<_>>(3)?
+            .map(ActionCompleteDate::from_value),
+    );
+    action.set_project_id(row.get::<_, Option<_>>(4)?.map(ProjectId::from_uuid));
+    action.set_someday(row.get(5)?);
+    action.set_action_due_date(row.get::<_, Option<_>>(6)?.map(ActionDueDate::from_value));
+    action.set_action_defer_date(row.get::<_, Option<_>>(7)?.map(ActionDeferDate::from_value));
+    action.set_action_notes(
+        row.get::<_, Option<String>>(8)?
+            .map(|notes| ActionNotes::new(&notes)),
+    );
+    if let Some(rrule) = row.get::<_, Option<String>>(9)? {
+        let base = match row.get(10)? {
+            true => RecurrenceBase::Completion,
+            false => RecurrenceBase::Schedule,
+        };
+        let recurrence = ActionRecurrence::from_rrule(&rrule, base).map_err(|err| {
+            rusqlite::Error::FromSqlConversionFailure(9, Type::Text, Box::new(err))
+        })?;
+        action.set_action_recurrence(Some(recurrence));
+    }
+    if let Some(delegate) = row.get::<_, Option<String>>(11)? {
+        action.set_action_delegation(Some(ActionDelegation::from_stored(
+            &delegate,
+            row.get(12)?,
+            row.get(13)?,
+        )));
+    }
+    action.set_position(row.get(14)?);
+    action.set_area_id(row.get::<_, Option<_>>(15)?.map(AreaId::from_uuid));
+    Ok(action)
+}
+
+/// Expands to the condition matching the tags at or below the path bound to `:tag`.
+///
+/// Compares prefixes with `substr` rather than `LIKE`, so paths need no escaping.
+macro_rules! tag_subtree {
+    () => {
+        "(tag.path = :tag OR substr(tag.path, 1, length(:tag) + 1) = :tag || '/')"
+    };
+}
+
+/// Expands to the condition matching the actions not blocked by an earlier open action of their
+/// sequential project.
+///
+/// Binds `:sequential` to [ProjectOrdering::Sequential].
+macro_rules! unblocked_action {
+    () => {
+        "NOT EXISTS (SELECT 1 FROM project \
+         WHERE project.id = action.project_id AND project.ordering = :sequential \
+         AND EXISTS (SELECT 1 FROM action AS earlier \
+             WHERE earlier.project_id = action.project_id \
+             AND earlier.completed_at IS NULL AND earlier.someday = 0 \
+             AND earlier.position < action.position))"
+    };
+}
+
+/// Named values to bind to a dynamically built statement.
+type NamedValues = Vec<(&'static str, Box<dyn ToSql>)>;
+
+/// Builds the statement selecting the actions matching `filter`, and the values to bind to it.
+///
+/// Only criteria that are set end up in the statement, so SQLite can use the matching indexes.
+/// Values are always bound as parameters, never interpolated.
+fn select_actions_sql(filter: &ActionFilter) -> (String, NamedValues) {
+    let mut clauses: Vec<&str> = Vec::new();
+    let mut values: NamedValues = Vec::new();
+    match filter.completed {
+        Some(true) => clauses.push("completed_at IS NOT NULL"),
+        Some(false) => clauses.push("completed_at IS NULL"),
+        None => {}
+    }
+    if let Some(project_id) = &filter.project_id {
+        clauses.push("project_id = :project_id");
+        values.push((":project_id", Box::new(*project_id.uuid())));
+    }
+    if let Some(context_id) = &filter.context_id {
+        clauses.push(
+            "EXISTS (SELECT 1 FROM action_context \
+             WHERE action_context.action_id = action.id \
+             AND action_context.context_id = :context_id)",
+        );
+        values.push((":context_id", Box::new(*context_id.uuid())));
+    }
+    if let Some(tag) = &filter.tag {
+        clauses.push(concat!(
+            "EXISTS (SELECT 1 FROM action_tag JOIN tag ON tag.id = action_tag.tag_id \
+             WHERE action_tag.action_id = action.id AND ",
+            tag_subtree!(),
+            ")"
+        ));
+        values.push((":tag", Box::new(tag.as_str().to_owned())));
+    }
+    if let Some(available_at) = filter.available_at {
+        clauses.push("(defer_until IS NULL OR defer_until <= :available_at)");
+        values.push((":available_at", Box::new(available_at)));
+    }
+    if let Some(due_from) = filter.due_from {
+        clauses.push("due_at >= :due_from");
+        values.push((":due_from", Box::new(due_from)));
+    }
+    if let Some(due_before) = filter.due_before {
+        clauses.push("due_at < :due_before");
+        values.push((":due_before", Box::new(due_before)));
+    }
+    match filter.delegated {
+        Some(true) => clauses.push("delegated_to IS NOT NULL"),
+        Some(false) => clauses.push("delegated_to IS NULL"),
+        None => {}
+    }
+    match filter.someday {
+        Some(true) => clauses.push(
+            "(someday = 1 OR EXISTS (SELECT 1 FROM project \
+             WHERE project.id = action.project_id AND project.someday = 1))",
+        ),
+        Some(false) => clauses.push(
+            "someday = 0 AND NOT EXISTS (SELECT 1 FROM project \
+             WHERE project.id = action.project_id AND project.someday = 1)",
+        ),
+        None => {}
+    }
+    if filter.unblocked {
+        clauses.push(unblocked_action!());
+        values.push((
+            ":sequential",
+            Box::new(ProjectOrdering::Sequential.as_str()),
+        ));
+    }
+
+    let where_clause = if clauses.is_empty() {
+        String::new()
+    } else {
+        format!("WHERE {}", clauses.join(" AND "))
+    };
+    let order_clause = match filter.order {
+        ActionOrder::CreateDate => "created_at, rowid",
+        ActionOrder::DueDate => "due_at, created_at, rowid",
+        ActionOrder::FollowUp => "follow_up_at NULLS LAST, created_at, rowid",
+        ActionOrder::Position => "position NULLS LAST, created_at, rowid",
+    };
+    let sql = format!("SELECT {ACTION_COLUMNS} FROM action {where_clause} ORDER BY {order_clause}");
+    (sql, values)
+}
+
+/// Values to bind, in order, to the numbered parameters (`?1`, `?2`, ...) of a dynamically built
+/// statement.
+type PositionalValues = Vec<Box<dyn ToSql>>;
+
+/// Appends `value` to `values` and returns the numbered parameter it is bound to.
+fn bind(values: &mut PositionalValues, value: impl ToSql + 'static) -> String {
+    values.push(Box::new(value));
+    format!("?{}", values.len())
+}
+
+/// The area of an action, which is its project's area for actions of a project.
+const ACTION_AREA: &str = "COALESCE((SELECT project.area_id FROM project \
+     WHERE project.id = action.project_id), action.area_id)";
+
+/// Builds the statement selecting the page of actions matching `query`, and the values to bind
+/// to it.
+///
+/// Every value of the query is bound to a numbered parameter, never interpolated.
+fn select_query_sql(query: &ActionQuery) -> (String, PositionalValues) {
+    let mut values = PositionalValues::new();
+    let where_clause = match &query.predicate {
+        Some(predicate) => format!("WHERE {}", predicate_sql(predicate, &mut values)),
+        None => String::new(),
+    };
+    let mut order_terms: Vec<String> = query
+        .sort
+        .iter()
+        .map(|(key, direction)| {
+            let column = match key {
+                ActionSortKey::CreatedAt => "action.created_at",
+                ActionSortKey::CompletedAt => "action.completed_at",
+                ActionSortKey::DeferUntil => "action.defer_until",
+                ActionSortKey::DueAt => "action.due_at",
+                ActionSortKey::Name => "action.title COLLATE NOCASE",
+                ActionSortKey::Position => "action.position",
+            };
+            let direction = match direction {
+                SortDirection::Ascending => "ASC",
+                SortDirection::Descending => "DESC",
+            };
+            format!("{column} {direction} NULLS LAST")
+        })
+        .collect();
+    order_terms.push(String::from("action.created_at, action.rowid"));
+
+    let mut sql = format!(
+        "SELECT {ACTION_COLUMNS} FROM action {where_clause} ORDER BY {}",
+        order_terms.join(", ")
+    );
+    if query.limit.is_some() || query.offset > 0 {
+        // A negative limit means no limit to SQLite.
+        let limit = bind(&mut values, query.limit.map_or(-1, i64::from));
+        let offset = bind(&mut values, query.offset);
+        sql.push_str(&format!(" LIMIT {limit} OFFSET {offset}"));
+    }
+    (sql, values)
+}
+
+/// Compiles `predicate` to a condition on the `action` table, binding its values to `values`.
+fn predicate_sql(predicate: &ActionPredicate, values: &mut PositionalValues) -> String {
+    match predicate {
+        ActionPredicate::Open => String::from("action.completed_at IS NULL"),
+        ActionPredicate::Completed => String::from("action.completed_at IS NOT NULL"),
+        ActionPredicate::Someday => String::from(
+            "(action.someday = 1 OR EXISTS (SELECT 1 FROM project \
+             WHERE project.id = action.project_id AND project.someday = 1))",
+        ),
+        ActionPredicate::Delegated => String::from("action.delegated_to IS NOT NULL"),
+        ActionPredicate::AvailableAt(moment) => format!(
+            "(action.defer_until IS NULL OR action.defer_until <= {})",
+            bind(values, *moment)
+        ),
+        ActionPredicate::DueBefore(moment) => format!("action.due_at < {}", bind(values, *moment)),
+        ActionPredicate::DueFrom(moment) => format!("action.due_at >= {}", bind(values, *moment)),
+        ActionPredicate::NameContains(text) => {
+            let pattern = text
+                .replace('\\', "\\\\")
+                .replace('%', "\\%")
+                .replace('_', "\\_");
+            format!(
+                "action.title LIKE {} ESCAPE '\\'",
+                bind(values, format!("%{pattern}%"))
+            )
+        }
+        ActionPredicate::Project(project_id) => {
+            format!("action.project_id = {}", bind(values, *project_id.uuid()))
+        }
+        ActionPredicate::ProjectNamed(name) => format!(
+            "EXISTS (SELECT 1 FROM project \
+             WHERE project.id = action.project_id AND project.title = {})",
+            bind(values, name.clone())
+        ),
+        ActionPredicate::Context(context_id) => format!(
+            "EXISTS (SELECT 1 FROM action_context \
+             WHERE action_context.action_id = action.id AND action_context.context_id = {})",
+            bind(values, *context_id.uuid())
+        ),
+        ActionPredicate::ContextNamed(name) => format!(
+            "EXISTS (SELECT 1 FROM action_context \
+             JOIN context ON context.id = action_context.context_id \
+             WHERE action_context.action_id = action.id AND context.name = {})",
+            bind(values, name.clone())
+        ),
+        ActionPredicate::Area(area_id) => {
+            format!("{ACTION_AREA} = {}", bind(values, *area_id.uuid()))
+        }
+        ActionPredicate::AreaNamed(name) => format!(
+            "EXISTS (SELECT 1 FROM area WHERE area.id = {ACTION_AREA} AND area.name = {})",
+            bind(values, name.clone())
+        ),
+        ActionPredicate::Tag(tag_path) => {
+            let tag = bind(values, tag_path.as_str().to_owned());
+            format!(
+                "EXISTS (SELECT 1 FROM action_tag JOIN tag ON tag.id = action_tag.tag_id \
+                 WHERE action_tag.action_id = action.id AND {})",
+                tag_subtree!().replace(":tag", &tag)
+            )
+        }
+        ActionPredicate::And(predicates) if predicates.is_empty() => String::from("1"),
+        ActionPredicate::And(predicates) => {
+            let conditions: Vec<_> = predicates
+                .iter()
+                .map(|predicate| predicate_sql(predicate, values))
+                .collect();
+            format!("({})", conditions.join(" AND "))
+        }
+        ActionPredicate::Or(predicates) if predicates.is_empty() => String::from("0"),
+        ActionPredicate::Or(predicates) => {
+            let conditions: Vec<_> = predicates
+                .iter()
+                .map(|predicate| predicate_sql(predicate, values))
+                .collect();
+            format!("({})", conditions.join(" OR "))
+        }
+        ActionPredicate::Not(predicate) => format!("NOT ({})", predicate_sql(predicate, values)),
+    }
+}
+
+/// Executes an insert or update statement, binding every field of `action` to its named
+/// parameter (`:id`, `:title`, ...).
+fn execute_with_action(stmt: &mut CachedStatement, action: &Action) -> rusqlite::Result<usize> {
+    stmt.execute(named_params! {
+        ":id": action.action_id().uuid(),
+        ":title": action.action_name().as_str(),
+        ":created_at": action.action_create_date().value(),
+        ":completed_at": action.action_complete_date().map(|date| date.value()),
+        ":project_id": action.project_id().map(|id| id.uuid()),
+        ":someday": action.is_someday(),
+        ":due_at": action.action_due_date().map(|date| date.value()),
+        ":defer_until": action.action_defer_date().map(|date| date.value()),
+        ":notes": action.action_notes().map(|notes| notes.as_str()),
+        ":recurrence": action.action_recurrence().map(|recurrence| recurrence.to_rrule()),
+        ":recur_from_completion": action
+            .action_recurrence()
+            .is_some_and(|recurrence| recurrence.base() == RecurrenceBase::Completion),
+        ":delegated_to": action.action_delegation().map(|delegation| delegation.delegate()),
+        ":delegated_at": action.action_delegation().map(|delegation| delegation.delegated_at()),
+        ":follow_up_at": action
+            .action_delegation()
+            .and_then(|delegation| delegation.follow_up_at()),
+        ":position": action.position(),
+        ":area_id": action.area_id().map(|id| id.uuid()),
+    })
+}
+
+/// The columns selected when reading projects, in the order expected by [project_from_row].
+const PROJECT_COLUMNS: &str =
+    "id, title, created_at, status, someday, last_reviewed_at, ordering, area_id";
+
+/// Maps a row selected with [PROJECT_COLUMNS] to a [Project].
+fn project_from_row(row: &Row) -> rusqlite::Result<Project> {
+    let status = row.get::<_, String>(3)?;
+    let status = status
+        .parse()
+        .map_err(|err| rusqlite::Error::FromSqlConversionFailure(3, Type::Text, Box::new(err)))?;
+    let mut project = Project::new(
+        ProjectId::from_uuid(row.get(0)?),
+        ProjectName::new(&row.get::<_, String>(1)?),
+        ProjectCreateDate::from_value(row.get(2)?),
+        status,
+    );
+    project.set_someday(row.get(4)?);
+    project.set_project_review_date(
+        row.get::<_, Option<_>>(5)?
+            .map(ProjectReviewDate::from_value),
+    );
+    let ordering = row.get::<_, String>(6)?;
+    let ordering = ordering
+        .parse()
+        .map_err(|err| rusqlite::Error::FromSqlConversionFailure(6, Type::Text, Box::new(err)))?;
+    project.set_project_ordering(ordering);
+    project.set_area_id(row.get::<_, Option<_>>(7)?.map(AreaId::from_uuid));
+    Ok(project)
+}
+
+/// Executes an insert or update statement, binding every field of `project` to its named
+/// parameter (`:id`, `:title`, ...).
+fn execute_with_project(stmt: &mut CachedStatement, project: &Project) -> rusqlite::Result<usize> {
+    stmt.execute(named_params! {
+        ":id": project.project_id().uuid(),
+        ":title": project.project_name().as_str(),
+        ":created_at": project.project_create_date().value(),
+        ":status": project.project_status().as_str(),
+        ":someday": project.is_someday(),
+        ":last_reviewed_at": project.project_review_date().map(|date| date.value()),
+        ":ordering": project.project_ordering().as_str(),
+        ":area_id": project.area_id().map(|id| id.uuid()),
+    })
+}
+
+/// Condition matching the active, committed projects without a next action.
+///
+/// Next actions are open, committed, not delegated, available and not blocked, like the ones
+/// listed by [HeliaCore::list_next_actions](crate::core::HeliaCore::list_next_actions).
+/// Binds `:active` to [ProjectStatus::Active], `:now` to the current moment and `:sequential`
+/// to [ProjectOrdering::Sequential].
+const STALLED_PROJECT: &str = concat!(
+    "project.status = :active AND project.someday = 0 \
+     AND NOT EXISTS (SELECT 1 FROM action \
+         WHERE action.project_id = project.id \
+         AND action.completed_at IS NULL \
+         AND action.someday = 0 \
+         AND action.delegated_to IS NULL \
+         AND (action.defer_until IS NULL OR action.defer_until <= :now) \
+         AND ",
+    unblocked_action!(),
+    ")"
+);
+
+/// The columns selected when reading tags, in the order expected by [tag_from_row].
+const TAG_COLUMNS: &str = "tag.id, tag.path";
+
+/// Maps a row selected with [TAG_COLUMNS] to a [Tag].
+fn tag_from_row(row: &Row) -> rusqlite::Result<Tag> {
+    Ok(Tag::new(
+        TagId::from_uuid(row.get(0)?),
+        TagPath::from_stored(&row.get::<_, String>(1)?),
+    ))
+}
+
+/// The columns selected when reading inbox items, in the order expected by [inbox_item_from_row].
+const INBOX_ITEM_COLUMNS: &str = "id, text, captured_at";
+
+/// Maps a row selected with [INBOX_ITEM_COLUMNS] to an [InboxItem].
+fn inbox_item_from_row(row: &Row) -> rusqlite::Result<InboxItem> {
+    Ok(InboxItem::new(
+        InboxItemId::from_uuid(row.get(0)?),
+        InboxItemText::from_stored(&row.get::<_, String>(1)?),
+        InboxItemCaptureDate::from_value(row.get(2)?),
+    ))
+}
+
+/// The columns selected when reading reference items, in the order expected by
+/// [reference_item_from_row].
+const REFERENCE_ITEM_COLUMNS: &str = "id, text, created_at";
+
+/// Maps a row selected with [REFERENCE_ITEM_COLUMNS] to a [ReferenceItem].
+fn reference_item_from_row(row: &Row) -> rusqlite::Result<ReferenceItem> {
+    Ok(ReferenceItem::new(
+        ReferenceItemId::from_uuid(row.get(0)?),
+        ReferenceItemText::new(&row.get::<_, String>(1)?),
+        ReferenceItemCreateDate::from_value(row.get(2)?),
+    ))
+}
+
+/// The columns selected when reading contexts, in the order expected by [context_from_row].
+const CONTEXT_COLUMNS: &str = "id, name";
+
+/// Maps a row selected with [CONTEXT_COLUMNS] to a [Context].
+fn context_from_row(row: &Row) -> rusqlite::Result<Context> {
+    Ok(Context::new(
+        ContextId::from_uuid(row.get(0)?),
+        ContextName::new(&row.get::<_, String>(1)?),
+    ))
+}
+
+/// The columns selected when reading areas, in the order expected by [area_from_row].
+const AREA_COLUMNS: &str = "id, name";
+
+/// Maps a row selected with [AREA_COLUMNS] to an [Area].
+fn area_from_row(row: &Row) -> rusqlite::Result<Area> {
+    Ok(Area::new(
+        AreaId::from_uuid(row.get(0)?),
+        AreaName::from_stored(&row.get::<_, String>(1)?),
+    ))
+}
+
+/// The columns selected when reading perspectives, in the order expected by
+/// [perspective_from_row].
+const PERSPECTIVE_COLUMNS: &str = "id, name, query";
+
+/// Maps a row selected with [PERSPECTIVE_COLUMNS] to a [Perspective].
+fn perspective_from_row(row: &Row) -> rusqlite::Result<Perspective> {
+    Ok(Perspective::new(
+        PerspectiveId::from_uuid(row.get(0)?),
+        PerspectiveName::from_stored(&row.get::<_, String>(1)?),
+        PerspectiveQuery::from_stored(&row.get::<_, String>(2)?),
+    ))
+}
+
+/// Turns free text into an FTS5 query matching every word of it as a prefix.
+///
+/// Each word is quoted, so FTS5 operators and special characters in `text` are taken literally.
+fn fts_query(text: &str) -> String {
+    text.split_whitespace()
+        .map(|word| format!("\"{}\"*", word.replace('"', "\"\"")))
+        .collect::<Vec<_>>()
+        .join(" ")
+}
+
+/// Maps a row of the search index to a [SearchRow].
+fn search_row_from_row(row: &Row) -> rusqlite::Result<SearchRow> {
+    let item = match row.get_ref(0)?.as_str()? {
+        "action" => SearchItem::Action(ActionId::from_uuid(row.get(1)?)),
+        "project" => SearchItem::Project(ProjectId::from_uuid(row.get(1)?)),
+        "reference_item" => SearchItem::ReferenceItem(ReferenceItemId::from_uuid(row.get(1)?)),
+        kind => {
+            return Err(rusqlite::Error::FromSqlConversionFailure(
+                0,
+                Type::Text,
+                format!("unknown search item kind '{kind}'").into(),
+            ));
+        }
+    };
+    Ok((item, row.get(2)?, row.get(3)?))
+}
+
 /// The SQLite implementation used for production.
 pub struct SqliteStorage {
     conn: Connection,
@@ -19,25 +548,21 @@ pub struct SqliteStorage {
 
 impl SqliteStorage {
     /// Creates a new [SqliteStorage] instance with persistence.
-    pub fn new_persistence(db_path: &str) -> Result<Self, StorageError> {
-        debug!(
-            db_path,
-            "Trying to open database connection with persistence."
-        );
+    pub fn new_persistence(db_path: &Path) -> Result<Self, StorageError> {
+        let path = db_path.display();
+        debug!(db_path = %path, "Trying to open database connection with persistence.");
         let conn = Connection::open(db_path);
         let conn = match conn {
             Ok(sqtlite_storage) => sqtlite_storage,
             Err(err) => {
-                error!(db_path, error = %err, "Failed to establish database connection.");
-                return Result::Err(StorageError::ConnectionError);
+                error!(db_path = %path, error = %err, "Failed to establish database connection.");
+                return Result::Err(StorageError::ConnectionError { source: err });
             }
         };
 
-        info!(db_path, "Database connection established successfully.");
+        info!(db_path = %path, "Database connection established successfully.");
 
-        let sqlite_storage = SqliteStorage { conn };
-
-        Ok(sqlite_storage)
+        Self::from_connection(conn)
     }
 
     /// Creates a new in-memory [SqliteStorage].
@@ -46,17 +571,207 @@ impl SqliteStorage {
         let conn = Connection::open_in_memory();
         let conn = match conn {
             Ok(sqtlite_storage) => sqtlite_storage,
-            Err(_sqlite_error) => {
-                error!("Failed to establish database connection.");
-                return Result::Err(StorageError::ConnectionError);
+            Err(err) => {
+                error!(error = %err, "Failed to establish database connection.");
+                return Result::Err(StorageError::ConnectionError { source: err });
             }
         };
 
         info!("Database connection established successfully.");
 
-        let sqlite_storage = SqliteStorage { conn };
+        Self::from_connection(conn)
+    }
+
+    /// Configures an established connection and wraps it in a [SqliteStorage].
+    fn from_connection(conn: Connection) -> Result<Self, StorageError> {
+        // SQLite does not enforce foreign keys unless asked to, once per connection.
+        if let Err(err) = conn.pragma_update(None, "foreign_keys", true) {
+            error!(error = %err, "Failed to enable foreign key enforcement.");
+            return Err(StorageError::ConnectionError { source: err });
+        }
+
+        Ok(SqliteStorage { conn })
+    }
+
+    /// Prepares a cached sql statement.
+    fn prepare(&self, sql: &str) -> Result<CachedStatement<'_>, StorageError> {
+        self.conn.prepare_cached(sql).map_err(|err| {
+            error!(error = %err, sql, "Failed to prepare sql statement.");
+            StorageError::PrepareStatementFailed { source: err }
+        })
+    }
+
+    /// Replays the statements of the last step of the history that is not undone (`false`), or
+    /// of the step that was undone last (`true`), in one transaction.
+    ///
+    /// The replay logs the statements reverting it, they replace those of the step.
+    /// Returns the operation of the step, or `None` if there is no such step.
+    fn replay_history_step(&self, undone: bool) -> Result<Option<String>, StorageError> {
+        debug!(undone, "Preparing replay history step sql statements.");
+
+        let tx = self.conn.unchecked_transaction().map_err(|err| {
+            error!(error = %err, "Failed to initiate the transaction.");
+            StorageError::TransactionInitFailed { source: err }
+        })?;
+
+        // Statements are replayed one row at a time, a row may reference a row that is only
+        // restored after it.
+        if let Err(err) = self.conn.pragma_update(None, "defer_foreign_keys", true) {
+            error!(error = %err, "Failed to defer foreign key enforcement.");
+            return Err(StorageError::UpdateFailed { source: err });
+        }
+        self.start_history_step()?;
+
+        let order = if undone { "ASC" } else { "DESC" };
+        let mut stmt = self.prepare(&format!(
+            "SELECT id, operation FROM history_step WHERE undone = ?1 ORDER BY id {order} LIMIT 1"
+        ))?;
+        let step = match stmt
+            .query_row([undone], |row| Ok((row.get::<_, i64>(0)?, row.get(1)?)))
+            .optional()
+        {
+            Ok(Some(step)) => step,
+            Ok(None) => {
+                debug!(undone, "No history step to replay.");
+                return Ok(None);
+            }
+            Err(err) => {
+                error!(error = %err, "Failed to fetch history step.");
+                return Err(StorageError::QueryFailed { source: err });
+            }
+        };
+        let (step_id, operation) = step;
+
+        let mut stmt = self
+            .prepare("SELECT statement FROM history_entry WHERE step_id = ?1 ORDER BY id DESC")?;
+        let statements = stmt
+            .query_map([step_id], |row| row.get::<_, String>(0))
+            .and_then(|rows| rows.collect::<Result<Vec<_>, _>>())
+            .map_err(|err| {
+                error!(error = %err, "Failed to list history entries.");
+                StorageError::QueryFailed { source: err }
+            })?;
+
+        let mut stmt = self.prepare("DELETE FROM history_entry WHERE step_id = ?1")?;
+        if let Err(err) = stmt.execute([step_id]) {
+            error!(error = %err, "Failed to delete history entries.");
+            return Err(StorageError::DeleteFailed { source: err });
+        }
+
+        for statement in &statements {
+            if let Err(err) = self.conn.execute(statement, []) {
+                error!(error = %err, statement, "Failed to replay history entry.");
+                return Err(StorageError::UpdateFailed { source: err });
+            }
+        }
+
+        let mut stmt =
+            self.prepare("UPDATE history_entry SET step_id = ?1 WHERE step_id IS NULL")?;
+        if let Err(err) = stmt.execute([step_id]) {
+            error!(error = %err, "Failed to group history entries.");
+            return Err(StorageError::UpdateFailed { source: err });
+        }
+
+        let mut stmt = self.prepare("UPDATE history_step SET undone = ?2 WHERE id = ?1")?;
+        if let Err(err) = stmt.execute((step_id, !undone)) {
+            error!(error = %err, "Failed to update history step.");
+            return Err(StorageError::UpdateFailed { source: err });
+        }
+
+        if let Err(err) = tx.commit() {
+            error!(error = %err, "Failed to commit transaction.");
+            return Err(StorageError::TransactionCommitFailed { source: err });
+        }
+
+        info!(operation, undone, "History step replayed.");
+        Ok(Some(operation))
+    }
+
+    /// Inserts `review` together with its completed steps.
+    fn insert_review_rows(&self, review: &Review) -> Result<(), StorageError> {
+        let mut stmt = self.prepare(
+            "INSERT INTO review (id, started_at, completed_at) \
+             VALUES (:id, :started_at, :completed_at)",
+        )?;
+        if let Err(err) = stmt.execute(named_params! {
+            ":id": review.review_id().uuid(),
+            ":started_at": review.started_at(),
+            ":completed_at": review.completed_at(),
+        }) {
+            error!(error = %err, "Failed to insert review.");
+            return Err(StorageError::InsertFailed { source: err });
+        }
+        self.insert_review_steps(review)
+    }
+
+    /// Inserts the completed steps of `review`.
+    fn insert_review_steps(&self, review: &Review) -> Result<(), StorageError> {
+        let mut stmt = self.prepare(
+            "INSERT INTO review_step (review_id, step, completed_at) VALUES (?1, ?2, ?3)",
+        )?;
+        for (step, completed_at) in review.completed_steps() {
+            if let Err(err) = stmt.execute((review.review_id().uuid(), step.as_str(), completed_at))
+            {
+                error!(error = %err, "Failed to insert review step.");
+                return Err(StorageError::InsertFailed { source: err });
+            }
+        }
+        Ok(())
+    }
+
+    /// Fetches the first review matching `condition`, together with its completed steps.
+    ///
+    /// Returns [`StorageError::NotFound`] if no review matches.
+    fn get_review_where(&self, condition: &str) -> Result<Review, StorageError> {
+        let mut stmt = self.prepare(&format!(
+            "SELECT id, started_at FROM review WHERE {condition} LIMIT 1"
+        ))?;
+        match stmt
+            .query_row([], |row| {
+                Ok(Review::new(ReviewId::from_uuid(row.get(0)?), row.get(1)?))
+            })
+            .optional()
+        {
+            Ok(Some(review)) => self.with_review_steps(review),
+            Ok(None) => {
+                debug!("Review not found.");
+                Err(StorageError::NotFound)
+            }
+            Err(err) => {
+                error!(error = %err, "Failed to fetch review.");
+                Err(StorageError::QueryFailed { source: err })
+            }
+        }
+    }
+
+    /// Adds the completed steps stored for `review` to it.
+    fn with_review_steps(&self, review: Review) -> Result<Review, StorageError> {
+        let mut stmt = self.prepare(
+            "SELECT step, completed_at FROM review_step WHERE review_id = ?1 \
+             ORDER BY completed_at, rowid",
+        )?;
+        let steps = stmt
+            .query_map([review.review_id().uuid()], |row| {
+                let step = row.get::<_, String>(0)?.parse().map_err(|err| {
+                    rusqlite::Error::FromSqlConversionFailure(0, Type::Text, Box::new(err))
+                })?;
+                Ok((step, row.get(1)?))
+            })
+            .and_then(|rows| rows.collect::<Result<Vec<_>, _>>());
 
-        Ok(sqlite_storage)
+        match steps {
+            Ok(steps) => {
+                let mut review = review;
+                for (step, completed_at) in steps {
+                    review.complete_step(step, completed_at);
+                }
+                Ok(review)
+            }
+            Err(err) => {
+                error!(error = %err, "Failed to list review steps.");
+                Err(StorageError::QueryFailed { source: err })
+            }
+        }
     }
 }
 
@@ -70,7 +785,7 @@ impl Storage for SqliteStorage {
 
         match result {
             Ok(version) => Ok(version as u32),
-            Err(_error) => Err(StorageError::QueryFailed),
+            Err(err) => Err(StorageError::QueryFailed { source: err }),
         }
     }
 
@@ -101,7 +816,7 @@ impl Storage for SqliteStorage {
                 error = %err,
                 "Failed to initiate the transaction."
             );
-            StorageError::TransactionInitFailed
+            StorageError::TransactionInitFailed { source: err }
         })?;
 
         for migration in &pending_migrations {
@@ -116,6 +831,7 @@ impl Storage for SqliteStorage {
                 );
                 return Err(StorageError::MigrationFailed {
                     version: migration.version,
+                    source: err,
                 });
             }
 
@@ -130,13 +846,14 @@ impl Storage for SqliteStorage {
                 );
                 return Err(StorageError::MigrationFailed {
                     version: migration.version,
+                    source: err,
                 });
             }
         }
 
         if let Err(err) = tx.commit() {
             error!(error = %err, "Failed to commit transaction.");
-            return Err(StorageError::TransactionCommitFailed);
+            return Err(StorageError::TransactionCommitFailed { source: err });
         }
 
         // Safe: cannot be `None`, see the `ìs_empty` check above
@@ -149,28 +866,1801 @@ impl Storage for SqliteStorage {
     fn insert_action(&self, action: &Action) -> Result<(), StorageError> {
         debug!(%action, "Preparing insert action sql statement.");
 
-        let stmt_result = self
-            .conn
-            .prepare_cached("INSERT INTO action (id, title, created_at) VALUES (?1, ?2, ?3)");
+        let mut stmt = self.prepare(
+            "INSERT INTO action \
+             (id, title, created_at, completed_at, project_id, someday, due_at, defer_until, \
+                 notes, recurrence, recur_from_completion, delegated_to, delegated_at, \
+                 follow_up_at, position, area_id) \
+             VALUES (:id, :title, :created_at, :completed_at, :project_id, :someday, :due_at, \
+                 :defer_until, :notes, :recurrence, :recur_from_completion, :delegated_to, \
+                 :delegated_at, :follow_up_at, :position, :area_id)",
+        )?;
 
-        let mut stmt = match stmt_result {
-            Ok(stmt) => stmt,
+        match execute_with_action(&mut stmt, action) {
             Err(err) => {
-                error!(error = %err, "Failed to prepare sql statement.");
-                return Err(StorageError::PrepareStatementFailed);
+                error!(err = % err, "Failed to insert action.");
+                Err(StorageError::InsertFailed { source: err })
             }
-        };
+            Ok(_num_rows_updated) => Ok(()),
+        }
+    }
 
-        match stmt.execute((
-            &action.action_id().uuid(),
-            &action.action_name().as_str(),
-            &action.action_create_date().value(),
+    fn get_action(&self, action_id: &ActionId) -> Result<Action, StorageError> {
+        debug!(%action_id, "Preparing get action sql statement.");
+
+        let mut stmt = self.prepare(&format!(
+            "SELECT {ACTION_COLUMNS} FROM action WHERE id = ?1"
+        ))?;
+
+        match stmt
+            .query_row([action_id.uuid()], action_from_row)
+            .optional()
+        {
+            Ok(Some(action)) => Ok(action),
+            Ok(None) => {
+                debug!(%action_id, "Action not found.");
+                Err(StorageError::NotFound)
+            }
+            Err(err) => {
+                error!(error = %err, "Failed to fetch action.");
+                Err(StorageError::QueryFailed { source: err })
+            }
+        }
+    }
+
+    fn list_actions(&self, filter: &ActionFilter) -> Result<Vec<Action>, StorageError> {
+        debug!(?filter, "Preparing list actions sql statement.");
+
+        let (sql, values) = select_actions_sql(filter);
+        let mut stmt = self.prepare(&sql)?;
+
+        let params: Vec<(&str, &dyn ToSql)> = values
+            .iter()
+            .map(|(name, value)| (*name, value.as_ref()))
+            .collect();
+        let result = stmt
+            .query_map(params.as_slice(), action_from_row)
+            .and_then(|rows| rows.collect::<Result<Vec<Action>, _>>());
+
+        match result {
+            Ok(actions) => Ok(actions),
+            Err(err) => {
+                error!(error = %err, "Failed to list actions.");
+                Err(StorageError::QueryFailed { source: err })
+            }
+        }
+    }
+
+    fn query_actions(&self, query: &ActionQuery) -> Result<Vec<Action>, StorageError> {
+        debug!(?query, "Preparing query actions sql statement.");
+
+        let (sql, values) = select_query_sql(query);
+        let mut stmt = self.prepare(&sql)?;
+
+        let result = stmt
+            .query_map(params_from_iter(values.iter()), action_from_row)
+            .and_then(|rows| rows.collect::<Result<Vec<Action>, _>>());
+
+        match result {
+            Ok(actions) => Ok(actions),
+            Err(err) => {
+                error!(error = %err, "Failed to query actions.");
+                Err(StorageError::QueryFailed { source: err })
+            }
+        }
+    }
+
+    fn update_action(&self, action: &Action) -> Result<(), StorageError> {
+        debug!(%action, "Preparing update action sql statement.");
+
+        let mut stmt = self.prepare(
+            "UPDATE action \
+             SET title = :title, created_at = :created_at, completed_at = :completed_at, \
+                 project_id = :project_id, someday = :someday, due_at = :due_at, \
+                 defer_until = :defer_until, notes = :notes, recurrence = :recurrence, \
+                 recur_from_completion = :recur_from_completion, delegated_to = :delegated_to, \
+                 delegated_at = :delegated_at, follow_up_at = :follow_up_at, \
+                 position = :position, area_id = :area_id \
+             WHERE id = :id",
+        )?;
+
+        match execute_with_action(&mut stmt, action) {
+            Err(err) => {
+                error!(error = %err, "Failed to update action.");
+                Err(StorageError::UpdateFailed { source: err })
+            }
+            Ok(0) => {
+                debug!(action_id = %action.action_id(), "Action not found.");
+                Err(StorageError::NotFound)
+            }
+            Ok(_num_rows_updated) => Ok(()),
+        }
+    }
+
+    #[instrument(skip(self, completed, next_occurrence))]
+    fn complete_recurring_action(
+        &self,
+        completed: &Action,
+        next_occurrence: &Action,
+    ) -> Result<(), StorageError> {
+        let tx = self.conn.unchecked_transaction().map_err(|err| {
+            error!(error = %err, "Failed to initiate the transaction.");
+            StorageError::TransactionInitFailed { source: err }
+        })?;
+
+        self.update_action(completed)?;
+        self.insert_action(next_occurrence)?;
+
+        let mut stmt = self.prepare(
+            "INSERT INTO action_context (action_id, context_id) \
+             SELECT ?1, context_id FROM action_context WHERE action_id = ?2",
+        )?;
+        if let Err(err) = stmt.execute((
+            next_occurrence.action_id().uuid(),
+            completed.action_id().uuid(),
         )) {
+            error!(error = %err, "Failed to copy the contexts of the action.");
+            return Err(StorageError::InsertFailed { source: err });
+        }
+
+        let mut stmt = self.prepare(
+            "INSERT INTO action_tag (action_id, tag_id) \
+             SELECT ?1, tag_id FROM action_tag WHERE action_id = ?2",
+        )?;
+        if let Err(err) = stmt.execute((
+            next_occurrence.action_id().uuid(),
+            completed.action_id().uuid(),
+        )) {
+            error!(error = %err, "Failed to copy the tags of the action.");
+            return Err(StorageError::InsertFailed { source: err });
+        }
+
+        if let Err(err) = tx.commit() {
+            error!(error = %err, "Failed to commit transaction.");
+            return Err(StorageError::TransactionCommitFailed { source: err });
+        }
+
+        info!("Recurring action completed.");
+        Ok(())
+    }
+
+    fn next_action_position(&self, project_id: &ProjectId) -> Result<u32, StorageError> {
+        debug!(%project_id, "Preparing next action position sql statement.");
+
+        let mut stmt = self
+            .prepare("SELECT COALESCE(MAX(position) + 1, 0) FROM action WHERE project_id = ?1")?;
+
+        match stmt.query_row([project_id.uuid()], |row| row.get(0)) {
+            Ok(position) => Ok(position),
             Err(err) => {
-                error!(err = % err, "Failed to insert action.");
-                Err(StorageError::InsertFailed)
+                error!(error = %err, "Failed to fetch the next action position.");
+                Err(StorageError::QueryFailed { source: err })
+            }
+        }
+    }
+
+    fn set_action_positions(
+        &self,
+        project_id: &ProjectId,
+        action_ids: &[ActionId],
+    ) -> Result<(), StorageError> {
+        let tx = self.conn.unchecked_transaction().map_err(|err| {
+            error!(error = %err, "Failed to initiate the transaction.");
+            StorageError::TransactionInitFailed { source: err }
+        })?;
+
+        let mut stmt =
+            self.prepare("UPDATE action SET position = ?1 WHERE id = ?2 AND project_id = ?3")?;
+        for (position, action_id) in action_ids.iter().enumerate() {
+            match stmt.execute((position as u32, action_id.uuid(), project_id.uuid())) {
+                Err(err) => {
+                    error!(error = %err, "Failed to update action position.");
+                    return Err(StorageError::UpdateFailed { source: err });
+                }
+                Ok(0) => {
+                    debug!(%action_id, %project_id, "Action not found in project.");
+                    return Err(StorageError::NotFound);
+                }
+                Ok(_num_rows_updated) => {}
+            }
+        }
+
+        if let Err(err) = tx.commit() {
+            error!(error = %err, "Failed to commit transaction.");
+            return Err(StorageError::TransactionCommitFailed { source: err });
+        }
+
+        info!("Action positions updated.");
+        Ok(())
+    }
+
+    fn delete_action(&self, action_id: &ActionId) -> Result<(), StorageError> {
+        debug!(%action_id, "Preparing delete action sql statement.");
+
+        let mut stmt = self.prepare("DELETE FROM action WHERE id = ?1")?;
+
+        match stmt.execute([action_id.uuid()]) {
+            Err(err) => {
+                error!(error = %err, "Failed to delete action.");
+                Err(StorageError::DeleteFailed { source: err })
+            }
+            Ok(0) => {
+                debug!(%action_id, "Action not found.");
+                Err(StorageError::NotFound)
+            }
+            Ok(_num_rows_deleted) => Ok(()),
+        }
+    }
+
+    fn insert_project(&self, project: &Project) -> Result<(), StorageError> {
+        debug!(%project, "Preparing insert project sql statement.");
+
+        let mut stmt = self.prepare(
+            "INSERT INTO project \
+             (id, title, created_at, status, someday, last_reviewed_at, ordering, area_id) \
+             VALUES (:id, :title, :created_at, :status, :someday, :last_reviewed_at, \
+                 :ordering, :area_id)",
+        )?;
+
+        match execute_with_project(&mut stmt, project) {
+            Err(err) => {
+                error!(error = %err, "Failed to insert project.");
+                Err(StorageError::InsertFailed { source: err })
             }
             Ok(_num_rows_updated) => Ok(()),
         }
     }
+
+    fn get_project(&self, project_id: &ProjectId) -> Result<Project, StorageError> {
+        debug!(%project_id, "Preparing get project sql statement.");
+
+        let mut stmt = self.prepare(&format!(
+            "SELECT {PROJECT_COLUMNS} FROM project WHERE id = ?1"
+        ))?;
+
+        match stmt
+            .query_row([project_id.uuid()], project_from_row)
+            .optional()
+        {
+            Ok(Some(project)) => Ok(project),
+            Ok(None) => {
+                debug!(%project_id, "Project not found.");
+                Err(StorageError::NotFound)
+            }
+            Err(err) => {
+                error!(error = %err, "Failed to fetch project.");
+                Err(StorageError::QueryFailed { source: err })
+            }
+        }
+    }
+
+    fn list_projects(&self, filter: &ProjectFilter) -> Result<Vec<Project>, StorageError> {
+        debug!(?filter, "Preparing list projects sql statement.");
+
+        let mut clauses: Vec<&str> = Vec::new();
+        let mut values: NamedValues = Vec::new();
+        match filter.someday {
+            Some(true) => clauses.push("someday = 1"),
+            Some(false) => clauses.push("someday = 0"),
+            None => {}
+        }
+        if let Some(tag) = &filter.tag {
+            clauses.push(concat!(
+                "EXISTS (SELECT 1 FROM project_tag JOIN tag ON tag.id = project_tag.tag_id \
+                 WHERE project_tag.project_id = project.id AND ",
+                tag_subtree!(),
+                ")"
+            ));
+            values.push((":tag", Box::new(tag.as_str().to_owned())));
+        }
+        let where_clause = if clauses.is_empty() {
+            String::new()
+        } else {
+            format!("WHERE {}", clauses.join(" AND "))
+        };
+        let mut stmt = self.prepare(&format!(
+            "SELECT {PROJECT_COLUMNS} FROM project {where_clause} ORDER BY created_at, rowid"
+        ))?;
+
+        let params: Vec<(&str, &dyn ToSql)> = values
+            .iter()
+            .map(|(name, value)| (*name, value.as_ref()))
+            .collect();
+        let result = stmt
+            .query_map(params.as_slice(), project_from_row)
+            .and_then(|rows| rows.collect::<Result<Vec<Project>, _>>());
+
+        match result {
+            Ok(projects) => Ok(projects),
+            Err(err) => {
+                error!(error = %err, "Failed to list projects.");
+                Err(StorageError::QueryFailed { source: err })
+            }
+        }
+    }
+
+    fn list_stalled_projects(
+        &self,
+        now: DateTime<Utc>,
+    ) -> Result<Vec<StalledProjectRow>, StorageError> {
+        debug!(%now, "Preparing list stalled projects sql statement.");
+
+        let mut stmt = self.prepare(&format!(
+            "SELECT {PROJECT_COLUMNS}, \
+                 (SELECT MAX(action.completed_at) FROM action \
+                  WHERE action.project_id = project.id) AS last_completed_at \
+             FROM project WHERE {STALLED_PROJECT} \
+             ORDER BY last_completed_at, created_at, rowid"
+        ))?;
+
+        let result = stmt
+            .query_map(
+                named_params! {
+                    ":active": ProjectStatus::Active.as_str(),
+                    ":now": now,
+                    ":sequential": ProjectOrdering::Sequential.as_str(),
+                },
+                |row| Ok((project_from_row(row)?, row.get(8)?)),
+            )
+            .and_then(|rows| rows.collect::<Result<Vec<_>, _>>());
+
+        match result {
+            Ok(projects) => Ok(projects),
+            Err(err) => {
+                error!(error = %err, "Failed to list stalled projects.");
+                Err(StorageError::QueryFailed { source: err })
+            }
+        }
+    }
+
+    fn update_project(&self, project: &Project) -> Result<(), StorageError> {
+        debug!(%project, "Preparing update project sql statement.");
+
+        let mut stmt = self.prepare(
+            "UPDATE project \
+             SET title = :title, created_at = :created_at, status = :status, \
+                 someday = :someday, last_reviewed_at = :last_reviewed_at, \
+                 ordering = :ordering, area_id = :area_id \
+             WHERE id = :id",
+        )?;
+
+        match execute_with_project(&mut stmt, project) {
+            Err(err) => {
+                error!(error = %err, "Failed to update project.");
+                Err(StorageError::UpdateFailed { source: err })
+            }
+            Ok(0) => {
+                debug!(project_id = %project.project_id(), "Project not found.");
+                Err(StorageError::NotFound)
+            }
+            Ok(_num_rows_updated) => Ok(()),
+        }
+    }
+
+    fn delete_project(&self, project_id: &ProjectId) -> Result<(), StorageError> {
+        debug!(%project_id, "Preparing delete project sql statement.");
+
+        let mut stmt = self.prepare("DELETE FROM project WHERE id = ?1")?;
+
+        match stmt.execute([project_id.uuid()]) {
+            Err(err) => {
+                error!(error = %err, "Failed to delete project.");
+                Err(StorageError::DeleteFailed { source: err })
+            }
+            Ok(0) => {
+                debug!(%project_id, "Project not found.");
+                Err(StorageError::NotFound)
+            }
+            Ok(_num_rows_deleted) => Ok(()),
+        }
+    }
+
+    fn insert_inbox_item(&self, inbox_item: &InboxItem) -> Result<(), StorageError> {
+        debug!(%inbox_item, "Preparing insert inbox item sql statement.");
+
+        let mut stmt =
+            self.prepare("INSERT INTO inbox_item (id, text, captured_at) VALUES (?1, ?2, ?3)")?;
+
+        match stmt.execute((
+            inbox_item.inbox_item_id().uuid(),
+            inbox_item.inbox_item_text().as_str(),
+            inbox_item.inbox_item_capture_date().value(),
+        )) {
+            Err(err) => {
+                error!(error = %err, "Failed to insert inbox item.");
+                Err(StorageError::InsertFailed { source: err })
+            }
+            Ok(_num_rows_updated) => Ok(()),
+        }
+    }
+
+    fn get_inbox_item(&self, inbox_item_id: &InboxItemId) -> Result<InboxItem, StorageError> {
+        debug!(%inbox_item_id, "Preparing get inbox item sql statement.");
+
+        let mut stmt = self.prepare(&format!(
+            "SELECT {INBOX_ITEM_COLUMNS} FROM inbox_item WHERE id = ?1"
+        ))?;
+
+        match stmt
+            .query_row([inbox_item_id.uuid()], inbox_item_from_row)
+            .optional()
+        {
+            Ok(Some(inbox_item)) => Ok(inbox_item),
+            Ok(None) => {
+                debug!(%inbox_item_id, "Inbox item not found.");
+                Err(StorageError::NotFound)
+            }
+            Err(err) => {
+                error!(error = %err, "Failed to fetch inbox item.");
+                Err(StorageError::QueryFailed { source: err })
+            }
+        }
+    }
+
+    fn list_inbox_items(&self) -> Result<Vec<InboxItem>, StorageError> {
+        debug!("Preparing list inbox items sql statement.");
+
+        let mut stmt = self.prepare(&format!(
+            "SELECT {INBOX_ITEM_COLUMNS} FROM inbox_item ORDER BY captured_at, rowid"
+        ))?;
+
+        let result = stmt
+            .query_map([], inbox_item_from_row)
+            .and_then(|rows| rows.collect::<Result<Vec<InboxItem>, _>>());
+
+        match result {
+            Ok(inbox_items) => Ok(inbox_items),
+            Err(err) => {
+                error!(error = %err, "Failed to list inbox items.");
+                Err(StorageError::QueryFailed { source: err })
+            }
+        }
+    }
+
+    #[instrument(skip(self, target))]
+    fn process_inbox_item(
+        &self,
+        inbox_item_id: &InboxItemId,
+        target: &InboxItemTarget,
+    ) -> Result<(), StorageError> {
+        let tx = self.conn.unchecked_transaction().map_err(|err| {
+            error!(error = %err, "Failed to initiate the transaction.");
+            StorageError::TransactionInitFailed { source: err }
+        })?;
+
+        let mut stmt = self.prepare("DELETE FROM inbox_item WHERE id = ?1")?;
+        match stmt.execute([inbox_item_id.uuid()]) {
+            Err(err) => {
+                error!(error = %err, "Failed to delete inbox item.");
+                return Err(StorageError::DeleteFailed { source: err });
+            }
+            Ok(0) => {
+                debug!("Inbox item not found.");
+                return Err(StorageError::NotFound);
+            }
+            Ok(_num_rows_deleted) => {}
+        }
+
+        match target {
+            InboxItemTarget::Action(action) => self.insert_action(action)?,
+            InboxItemTarget::Project(project) => self.insert_project(project)?,
+            InboxItemTarget::Reference(reference_item) => {
+                self.insert_reference_item(reference_item)?
+            }
+            InboxItemTarget::Trash => {}
+        }
+
+        if let Err(err) = tx.commit() {
+            error!(error = %err, "Failed to commit transaction.");
+            return Err(StorageError::TransactionCommitFailed { source: err });
+        }
+
+        info!("Inbox item processed.");
+        Ok(())
+    }
+
+    fn insert_reference_item(&self, reference_item: &ReferenceItem) -> Result<(), StorageError> {
+        debug!(%reference_item, "Preparing insert reference item sql statement.");
+
+        let mut stmt =
+            self.prepare("INSERT INTO reference_item (id, text, created_at) VALUES (?1, ?2, ?3)")?;
+
+        match stmt.execute((
+            reference_item.reference_item_id().uuid(),
+            reference_item.reference_item_text().as_str(),
+            reference_item.reference_item_create_date().value(),
+        )) {
+            Err(err) => {
+                error!(error = %err, "Failed to insert reference item.");
+                Err(StorageError::InsertFailed { source: err })
+            }
+            Ok(_num_rows_updated) => Ok(()),
+        }
+    }
+
+    fn list_reference_items(&self) -> Result<Vec<ReferenceItem>, StorageError> {
+        debug!("Preparing list reference items sql statement.");
+
+        let mut stmt = self.prepare(&format!(
+            "SELECT {REFERENCE_ITEM_COLUMNS} FROM reference_item ORDER BY created_at, rowid"
+        ))?;
+
+        let result = stmt
+            .query_map([], reference_item_from_row)
+            .and_then(|rows| rows.collect::<Result<Vec<ReferenceItem>, _>>());
+
+        match result {
+            Ok(reference_items) => Ok(reference_items),
+            Err(err) => {
+                error!(error = %err, "Failed to list reference items.");
+                Err(StorageError::QueryFailed { source: err })
+            }
+        }
+    }
+
+    fn insert_context(&self, context: &Context) -> Result<(), StorageError> {
+        debug!(%context, "Preparing insert context sql statement.");
+
+        let mut stmt = self.prepare("INSERT INTO context (id, name) VALUES (?1, ?2)")?;
+
+        match stmt.execute((context.context_id().uuid(), context.context_name().as_str())) {
+            Err(err) => {
+                error!(error = %err, "Failed to insert context.");
+                Err(StorageError::InsertFailed { source: err })
+            }
+            Ok(_num_rows_updated) => Ok(()),
+        }
+    }
+
+    fn get_context(&self, context_id: &ContextId) -> Result<Context, StorageError> {
+        debug!(%context_id, "Preparing get context sql statement.");
+
+        let mut stmt = self.prepare(&format!(
+            "SELECT {CONTEXT_COLUMNS} FROM context WHERE id = ?1"
+        ))?;
+
+        match stmt
+            .query_row([context_id.uuid()], context_from_row)
+            .optional()
+        {
+            Ok(Some(context)) => Ok(context),
+            Ok(None) => {
+                debug!(%context_id, "Context not found.");
+                Err(StorageError::NotFound)
+            }
+            Err(err) => {
+                error!(error = %err, "Failed to fetch context.");
+                Err(StorageError::QueryFailed { source: err })
+            }
+        }
+    }
+
+    fn list_contexts(&self) -> Result<Vec<Context>, StorageError> {
+        debug!("Preparing list contexts sql statement.");
+
+        let mut stmt = self.prepare(&format!(
+            "SELECT {CONTEXT_COLUMNS} FROM context ORDER BY name"
+        ))?;
+
+        let result = stmt
+            .query_map([], context_from_row)
+            .and_then(|rows| rows.collect::<Result<Vec<Context>, _>>());
+
+        match result {
+            Ok(contexts) => Ok(contexts),
+            Err(err) => {
+                error!(error = %err, "Failed to list contexts.");
+                Err(StorageError::QueryFailed { source: err })
+            }
+        }
+    }
+
+    fn update_context(&self, context: &Context) -> Result<(), StorageError> {
+        debug!(%context, "Preparing update context sql statement.");
+
+        let mut stmt = self.prepare("UPDATE context SET name = ?2 WHERE id = ?1")?;
+
+        match stmt.execute((context.context_id().uuid(), context.context_name().as_str())) {
+            Err(err) => {
+                error!(error = %err, "Failed to update context.");
+                Err(StorageError::UpdateFailed { source: err })
+            }
+            Ok(0) => {
+                debug!(context_id = %context.context_id(), "Context not found.");
+                Err(StorageError::NotFound)
+            }
+            Ok(_num_rows_updated) => Ok(()),
+        }
+    }
+
+    fn delete_context(&self, context_id: &ContextId) -> Result<(), StorageError> {
+        debug!(%context_id, "Preparing delete context sql statement.");
+
+        let mut stmt = self.prepare("DELETE FROM context WHERE id = ?1")?;
+
+        match stmt.execute([context_id.uuid()]) {
+            Err(err) => {
+                error!(error = %err, "Failed to delete context.");
+                Err(StorageError::DeleteFailed { source: err })
+            }
+            Ok(0) => {
+                debug!(%context_id, "Context not found.");
+                Err(StorageError::NotFound)
+            }
+            Ok(_num_rows_deleted) => Ok(()),
+        }
+    }
+
+    fn insert_area(&self, area: &Area) -> Result<(), StorageError> {
+        debug!(%area, "Preparing insert area sql statement.");
+
+        let mut stmt = self.prepare("INSERT INTO area (id, name) VALUES (?1, ?2)")?;
+
+        match stmt.execute((area.area_id().uuid(), area.area_name().as_str())) {
+            Err(err) => {
+                error!(error = %err, "Failed to insert area.");
+                Err(StorageError::InsertFailed { source: err })
+            }
+            Ok(_num_rows_updated) => Ok(()),
+        }
+    }
+
+    fn get_area(&self, area_id: &AreaId) -> Result<Area, StorageError> {
+        debug!(%area_id, "Preparing get area sql statement.");
+
+        let mut stmt = self.prepare(&format!("SELECT {AREA_COLUMNS} FROM area WHERE id = ?1"))?;
+
+        match stmt.query_row([area_id.uuid()], area_from_row).optional() {
+            Ok(Some(area)) => Ok(area),
+            Ok(None) => {
+                debug!(%area_id, "Area not found.");
+                Err(StorageError::NotFound)
+            }
+            Err(err) => {
+                error!(error = %err, "Failed to fetch area.");
+                Err(StorageError::QueryFailed { source: err })
+            }
+        }
+    }
+
+    fn list_areas(&self) -> Result<Vec<Area>, StorageError> {
+        debug!("Preparing list areas sql statement.");
+
+        let mut stmt = self.prepare(&format!("SELECT {AREA_COLUMNS} FROM area ORDER BY name"))?;
+
+        let result = stmt
+            .query_map([], area_from_row)
+            .and_then(|rows| rows.collect::<Result<Vec<Area>, _>>());
+
+        match result {
+            Ok(areas) => Ok(areas),
+            Err(err) => {
+                error!(error = %err, "Failed to list areas.");
+                Err(StorageError::QueryFailed { source: err })
+            }
+        }
+    }
+
+    fn list_area_summaries(&self, now: DateTime<Utc>) -> Result<Vec<AreaSummaryRow>, StorageError> {
+        debug!(%now, "Preparing list area summaries sql statement.");
+
+        // An action of a project belongs to the project's area.
+        let mut stmt = self.prepare(&format!(
+            "SELECT {AREA_COLUMNS}, \
+                 (SELECT COUNT(*) FROM action \
+                  LEFT JOIN project ON project.id = action.project_id \
+                  WHERE action.completed_at IS NULL AND action.someday = 0 \
+                  AND COALESCE(project.someday, 0) = 0 \
+                  AND COALESCE(project.area_id, action.area_id) = area.id), \
+                 (SELECT COUNT(*) FROM project \
+                  WHERE project.area_id = area.id AND {STALLED_PROJECT}) \
+             FROM area ORDER BY name"
+        ))?;
+
+        let result = stmt
+            .query_map(
+                named_params! {
+                    ":active": ProjectStatus::Active.as_str(),
+                    ":now": now,
+                    ":sequential": ProjectOrdering::Sequential.as_str(),
+                },
+                |row| Ok((area_from_row(row)?, row.get(2)?, row.get(3)?)),
+            )
+            .and_then(|rows| rows.collect::<Result<Vec<_>, _>>());
+
+        match result {
+            Ok(areas) => Ok(areas),
+            Err(err) => {
+                error!(error = %err, "Failed to list area summaries.");
+                Err(StorageError::QueryFailed { source: err })
+            }
+        }
+    }
+
+    fn update_area(&self, area: &Area) -> Result<(), StorageError> {
+        debug!(%area, "Preparing update area sql statement.");
+
+        let mut stmt = self.prepare("UPDATE area SET name = ?2 WHERE id = ?1")?;
+
+        match stmt.execute((area.area_id().uuid(), area.area_name().as_str())) {
+            Err(err) => {
+                error!(error = %err, "Failed to update area.");
+                Err(StorageError::UpdateFailed { source: err })
+            }
+            Ok(0) => {
+                debug!(area_id = %area.area_id(), "Area not found.");
+                Err(StorageError::NotFound)
+            }
+            Ok(_num_rows_updated) => Ok(()),
+        }
+    }
+
+    fn delete_area(&self, area_id: &AreaId) -> Result<(), StorageError> {
+        debug!(%area_id, "Preparing delete area sql statement.");
+
+        let mut stmt = self.prepare("DELETE FROM area WHERE id = ?1")?;
+
+        match stmt.execute([area_id.uuid()]) {
+            Err(err) => {
+                error!(error = %err, "Failed to delete area.");
+                Err(StorageError::DeleteFailed { source: err })
+            }
+            Ok(0) => {
+                debug!(%area_id, "Area not found.");
+                Err(StorageError::NotFound)
+            }
+            Ok(_num_rows_deleted) => Ok(()),
+        }
+    }
+
+    fn insert_tag(&self, tag: &Tag) -> Result<(), StorageError> {
+        debug!(%tag, "Preparing insert tag sql statement.");
+
+        let mut stmt = self.prepare("INSERT INTO tag (id, path) VALUES (?1, ?2)")?;
+
+        match stmt.execute((tag.tag_id().uuid(), tag.tag_path().as_str())) {
+            Err(err) => {
+                error!(error = %err, "Failed to insert tag.");
+                Err(StorageError::InsertFailed { source: err })
+            }
+            Ok(_num_rows_updated) => Ok(()),
+        }
+    }
+
+    fn get_tag(&self, tag_id: &TagId) -> Result<Tag, StorageError> {
+        debug!(%tag_id, "Preparing get tag sql statement.");
+
+        let mut stmt = self.prepare(&format!("SELECT {TAG_COLUMNS} FROM tag WHERE id = ?1"))?;
+
+        match stmt.query_row([tag_id.uuid()], tag_from_row).optional() {
+            Ok(Some(tag)) => Ok(tag),
+            Ok(None) => {
+                debug!(%tag_id, "Tag not found.");
+                Err(StorageError::NotFound)
+            }
+            Err(err) => {
+                error!(error = %err, "Failed to fetch tag.");
+                Err(StorageError::QueryFailed { source: err })
+            }
+        }
+    }
+
+    fn get_tag_by_path(&self, tag_path: &TagPath) -> Result<Tag, StorageError> {
+        debug!(%tag_path, "Preparing get tag by path sql statement.");
+
+        let mut stmt = self.prepare(&format!("SELECT {TAG_COLUMNS} FROM tag WHERE path = ?1"))?;
+
+        match stmt.query_row([tag_path.as_str()], tag_from_row).optional() {
+            Ok(Some(tag)) => Ok(tag),
+            Ok(None) => {
+                debug!(%tag_path, "Tag not found.");
+                Err(StorageError::NotFound)
+            }
+            Err(err) => {
+                error!(error = %err, "Failed to fetch tag.");
+                Err(StorageError::QueryFailed { source: err })
+            }
+        }
+    }
+
+    fn list_tags(&self) -> Result<Vec<Tag>, StorageError> {
+        debug!("Preparing list tags sql statement.");
+
+        let mut stmt = self.prepare(&format!("SELECT {TAG_COLUMNS} FROM tag ORDER BY path"))?;
+
+        let result = stmt
+            .query_map([], tag_from_row)
+            .and_then(|rows| rows.collect::<Result<Vec<Tag>, _>>());
+
+        match result {
+            Ok(tags) => Ok(tags),
+            Err(err) => {
+                error!(error = %err, "Failed to list tags.");
+                Err(StorageError::QueryFailed { source: err })
+            }
+        }
+    }
+
+    fn move_tags(&self, from: &TagPath, to: &TagPath) -> Result<(), StorageError> {
+        debug!(%from, %to, "Preparing move tags sql statements.");
+
+        let tx = self.conn.unchecked_transaction().map_err(|err| {
+            error!(error = %err, "Failed to initiate the transaction.");
+            StorageError::TransactionInitFailed { source: err }
+        })?;
+
+        // Every moved tag is planned against the tags outside the moved subtree before anything
+        // changes, as moved tags may take over the paths of other moved tags.
+        let mut stmt = self.prepare(concat!(
+            "SELECT id, path FROM tag WHERE ",
+            tag_subtree!(),
+            " ORDER BY length(path)"
+        ))?;
+        let moved = stmt
+            .query_map(named_params! { ":tag": from.as_str() }, |row| {
+                let path: String = row.get(1)?;
+                Ok((
+                    TagId::from_uuid(row.get(0)?),
+                    format!("{}{}", to.as_str(), &path[from.as_str().len()..]),
+                ))
+            })
+            .and_then(|rows| rows.collect::<Result<Vec<_>, _>>())
+            .map_err(|err| {
+                error!(error = %err, "Failed to fetch the moved tags.");
+                StorageError::QueryFailed { source: err }
+            })?;
+        let mut stmt = self.prepare(concat!(
+            "SELECT id FROM tag WHERE path = :path AND NOT ",
+            tag_subtree!()
+        ))?;
+        let mut plan = Vec::with_capacity(moved.len());
+        for (tag_id, path) in moved {
+            let target = stmt
+                .query_row(
+                    named_params! { ":path": path, ":tag": from.as_str() },
+                    |row| Ok(TagId::from_uuid(row.get(0)?)),
+                )
+                .optional()
+                .map_err(|err| {
+                    error!(error = %err, "Failed to fetch the tag merged into.");
+                    StorageError::QueryFailed { source: err }
+                })?;
+            plan.push((tag_id, path, target));
+        }
+
+        // A moved tag whose new path is taken has its links copied to the tag at that path and
+        // is deleted. The others are renamed, shallowest first, so that a tag taking over the
+        // path of another moved tag only does so once that tag moved on.
+        let link_tables = [("action_tag", "action_id"), ("project_tag", "project_id")];
+        for (tag_id, _, target) in &plan {
+            let Some(target) = target else {
+                continue;
+            };
+            for (table, item_column) in link_tables {
+                let mut stmt = self.prepare(&format!(
+                    "INSERT OR IGNORE INTO {table} ({item_column}, tag_id) \
+                     SELECT {item_column}, ?2 FROM {table} WHERE tag_id = ?1"
+                ))?;
+                if let Err(err) = stmt.execute((tag_id.uuid(), target.uuid())) {
+                    error!(error = %err, "Failed to copy the links of merged tags.");
+                    return Err(StorageError::InsertFailed { source: err });
+                }
+            }
+            let mut stmt = self.prepare("DELETE FROM tag WHERE id = ?1")?;
+            if let Err(err) = stmt.execute([tag_id.uuid()]) {
+                error!(error = %err, "Failed to delete merged tags.");
+                return Err(StorageError::DeleteFailed { source: err });
+            }
+        }
+        for (tag_id, path, target) in &plan {
+            if target.is_some() {
+                continue;
+            }
+            let mut stmt = self.prepare("UPDATE tag SET path = ?2 WHERE id = ?1")?;
+            if let Err(err) = stmt.execute((tag_id.uuid(), path)) {
+                error!(error = %err, "Failed to rename tags.");
+                return Err(StorageError::UpdateFailed { source: err });
+            }
+        }
+
+        if let Err(err) = tx.commit() {
+            error!(error = %err, "Failed to commit transaction.");
+            return Err(StorageError::TransactionCommitFailed { source: err });
+        }
+
+        info!("Tags moved.");
+        Ok(())
+    }
+
+    fn delete_tag(&self, tag_id: &TagId) -> Result<(), StorageError> {
+        debug!(%tag_id, "Preparing delete tag sql statement.");
+
+        let mut stmt = self.prepare("DELETE FROM tag WHERE id = ?1")?;
+
+        match stmt.execute([tag_id.uuid()]) {
+            Err(err) => {
+                error!(error = %err, "Failed to delete tag.");
+                Err(StorageError::DeleteFailed { source: err })
+            }
+            Ok(0) => {
+                debug!(%tag_id, "Tag not found.");
+                Err(StorageError::NotFound)
+            }
+            Ok(_num_rows_deleted) => Ok(()),
+        }
+    }
+
+    fn insert_perspective(&self, perspective: &Perspective) -> Result<(), StorageError> {
+        debug!(%perspective, "Preparing insert perspective sql statement.");
+
+        let mut stmt =
+            self.prepare("INSERT INTO perspective (id, name, query) VALUES (?1, ?2, ?3)")?;
+
+        match stmt.execute((
+            perspective.perspective_id().uuid(),
+            perspective.perspective_name().as_str(),
+            perspective.perspective_query().as_str(),
+        )) {
+            Err(err) => {
+                error!(error = %err, "Failed to insert perspective.");
+                Err(StorageError::InsertFailed { source: err })
+            }
+            Ok(_num_rows_updated) => Ok(()),
+        }
+    }
+
+    fn get_perspective(&self, perspective_id: &PerspectiveId) -> Result<Perspective, StorageError> {
+        debug!(%perspective_id, "Preparing get perspective sql statement.");
+
+        let mut stmt = self.prepare(&format!(
+            "SELECT {PERSPECTIVE_COLUMNS} FROM perspective WHERE id = ?1"
+        ))?;
+
+        match stmt
+            .query_row([perspective_id.uuid()], perspective_from_row)
+            .optional()
+        {
+            Ok(Some(perspective)) => Ok(perspective),
+            Ok(None) => {
+                debug!(%perspective_id, "Perspective not found.");
+                Err(StorageError::NotFound)
+            }
+            Err(err) => {
+                error!(error = %err, "Failed to fetch perspective.");
+                Err(StorageError::QueryFailed { source: err })
+            }
+        }
+    }
+
+    fn list_perspectives(&self) -> Result<Vec<Perspective>, StorageError> {
+        debug!("Preparing list perspectives sql statement.");
+
+        let mut stmt = self.prepare(&format!(
+            "SELECT {PERSPECTIVE_COLUMNS} FROM perspective ORDER BY name"
+        ))?;
+
+        let result = stmt
+            .query_map([], perspective_from_row)
+            .and_then(|rows| rows.collect::<Result<Vec<Perspective>, _>>());
+
+        match result {
+            Ok(perspectives) => Ok(perspectives),
+            Err(err) => {
+                error!(error = %err, "Failed to list perspectives.");
+                Err(StorageError::QueryFailed { source: err })
+            }
+        }
+    }
+
+    fn update_perspective(&self, perspective: &Perspective) -> Result<(), StorageError> {
+        debug!(%perspective, "Preparing update perspective sql statement.");
+
+        let mut stmt =
+            self.prepare("UPDATE perspective SET name = ?2, query = ?3 WHERE id = ?1")?;
+
+        match stmt.execute((
+            perspective.perspective_id().uuid(),
+            perspective.perspective_name().as_str(),
+            perspective.perspective_query().as_str(),
+        )) {
+            Err(err) => {
+                error!(error = %err, "Failed to update perspective.");
+                Err(StorageError::UpdateFailed { source: err })
+            }
+            Ok(0) => {
+                debug!(perspective_id = %perspective.perspective_id(), "Perspective not found.");
+                Err(StorageError::NotFound)
+            }
+            Ok(_num_rows_updated) => Ok(()),
+        }
+    }
+
+    fn delete_perspective(&self, perspective_id: &PerspectiveId) -> Result<(), StorageError> {
+        debug!(%perspective_id, "Preparing delete perspective sql statement.");
+
+        let mut stmt = self.prepare("DELETE FROM perspective WHERE id = ?1")?;
+
+        match stmt.execute([perspective_id.uuid()]) {
+            Err(err) => {
+                error!(error = %err, "Failed to delete perspective.");
+                Err(StorageError::DeleteFailed { source: err })
+            }
+            Ok(0) => {
+                debug!(%perspective_id, "Perspective not found.");
+                Err(StorageError::NotFound)
+            }
+            Ok(_num_rows_deleted) => Ok(()),
+        }
+    }
+
+    fn insert_action_context(
+        &self,
+        action_id: &ActionId,
+        context_id: &ContextId,
+    ) -> Result<(), StorageError> {
+        debug!(%action_id, %context_id, "Preparing insert action context sql statement.");
+
+        let mut stmt = self.prepare(
+            "INSERT OR IGNORE INTO action_context (action_id, context_id) VALUES (?1, ?2)",
+        )?;
+
+        match stmt.execute((action_id.uuid(), context_id.uuid())) {
+            Err(err) => {
+                error!(error = %err, "Failed to link action to context.");
+                Err(StorageError::InsertFailed { source: err })
+            }
+            Ok(_num_rows_updated) => Ok(()),
+        }
+    }
+
+    fn delete_action_context(
+        &self,
+        action_id: &ActionId,
+        context_id: &ContextId,
+    ) -> Result<(), StorageError> {
+        debug!(%action_id, %context_id, "Preparing delete action context sql statement.");
+
+        let mut stmt =
+            self.prepare("DELETE FROM action_context WHERE action_id = ?1 AND context_id = ?2")?;
+
+        match stmt.execute((action_id.uuid(), context_id.uuid())) {
+            Err(err) => {
+                error!(error = %err, "Failed to unlink action from context.");
+                Err(StorageError::DeleteFailed { source: err })
+            }
+            Ok(0) => {
+                debug!(%action_id, %context_id, "Action is not linked to context.");
+                Err(StorageError::NotFound)
+            }
+            Ok(_num_rows_deleted) => Ok(()),
+        }
+    }
+
+    fn list_action_contexts(&self, action_id: &ActionId) -> Result<Vec<Context>, StorageError> {
+        debug!(%action_id, "Preparing list action contexts sql statement.");
+
+        let mut stmt = self.prepare(
+            "SELECT context.id, context.name FROM context \
+             JOIN action_context ON action_context.context_id = context.id \
+             WHERE action_context.action_id = ?1 \
+             ORDER BY context.name",
+        )?;
+
+        let result = stmt
+            .query_map([action_id.uuid()], context_from_row)
+            .and_then(|rows| rows.collect::<Result<Vec<Context>, _>>());
+
+        match result {
+            Ok(contexts) => Ok(contexts),
+            Err(err) => {
+                error!(error = %err, "Failed to list action contexts.");
+                Err(StorageError::QueryFailed { source: err })
+            }
+        }
+    }
+
+    fn insert_action_tag(&self, action_id: &ActionId, tag_id: &TagId) -> Result<(), StorageError> {
+        debug!(%action_id, %tag_id, "Preparing insert action tag sql statement.");
+
+        let mut stmt =
+            self.prepare("INSERT OR IGNORE INTO action_tag (action_id, tag_id) VALUES (?1, ?2)")?;
+
+        match stmt.execute((action_id.uuid(), tag_id.uuid())) {
+            Err(err) => {
+                error!(error = %err, "Failed to link action to tag.");
+                Err(StorageError::InsertFailed { source: err })
+            }
+            Ok(_num_rows_updated) => Ok(()),
+        }
+    }
+
+    fn delete_action_tag(&self, action_id: &ActionId, tag_id: &TagId) -> Result<(), StorageError> {
+        debug!(%action_id, %tag_id, "Preparing delete action tag sql statement.");
+
+        let mut stmt =
+            self.prepare("DELETE FROM action_tag WHERE action_id = ?1 AND tag_id = ?2")?;
+
+        match stmt.execute((action_id.uuid(), tag_id.uuid())) {
+            Err(err) => {
+                error!(error = %err, "Failed to unlink action from tag.");
+                Err(StorageError::DeleteFailed { source: err })
+            }
+            Ok(0) => {
+                debug!(%action_id, %tag_id, "Action is not linked to tag.");
+                Err(StorageError::NotFound)
+            }
+            Ok(_num_rows_deleted) => Ok(()),
+        }
+    }
+
+    fn list_action_tags(&self, action_id: &ActionId) -> Result<Vec<Tag>, StorageError> {
+        debug!(%action_id, "Preparing list action tags sql statement.");
+
+        let mut stmt = self.prepare(&format!(
+            "SELECT {TAG_COLUMNS} FROM tag \
+             JOIN action_tag ON action_tag.tag_id = tag.id \
+             WHERE action_tag.action_id = ?1 \
+             ORDER BY tag.path"
+        ))?;
+
+        let result = stmt
+            .query_map([action_id.uuid()], tag_from_row)
+            .and_then(|rows| rows.collect::<Result<Vec<Tag>, _>>());
+
+        match result {
+            Ok(tags) => Ok(tags),
+            Err(err) => {
+                error!(error = %err, "Failed to list action tags.");
+                Err(StorageError::QueryFailed { source: err })
+            }
+        }
+    }
+
+    fn insert_project_tag(
+        &self,
+        project_id: &ProjectId,
+        tag_id: &TagId,
+    ) -> Result<(), StorageError> {
+        debug!(%project_id, %tag_id, "Preparing insert project tag sql statement.");
+
+        let mut stmt =
+            self.prepare("INSERT OR IGNORE INTO project_tag (project_id, tag_id) VALUES (?1, ?2)")?;
+
+        match stmt.execute((project_id.uuid(), tag_id.uuid())) {
+            Err(err) => {
+                error!(error = %err, "Failed to link project to tag.");
+                Err(StorageError::InsertFailed { source: err })
+            }
+            Ok(_num_rows_updated) => Ok(()),
+        }
+    }
+
+    fn delete_project_tag(
+        &self,
+        project_id: &ProjectId,
+        tag_id: &TagId,
+    ) -> Result<(), StorageError> {
+        debug!(%project_id, %tag_id, "Preparing delete project tag sql statement.");
+
+        let mut stmt =
+            self.prepare("DELETE FROM project_tag WHERE project_id = ?1 AND tag_id = ?2")?;
+
+        match stmt.execute((project_id.uuid(), tag_id.uuid())) {
+            Err(err) => {
+                error!(error = %err, "Failed to unlink project from tag.");
+                Err(StorageError::DeleteFailed { source: err })
+            }
+            Ok(0) => {
+                debug!(%project_id, %tag_id, "Project is not linked to tag.");
+                Err(StorageError::NotFound)
+            }
+            Ok(_num_rows_deleted) => Ok(()),
+        }
+    }
+
+    fn list_project_tags(&self, project_id: &ProjectId) -> Result<Vec<Tag>, StorageError> {
+        debug!(%project_id, "Preparing list project tags sql statement.");
+
+        let mut stmt = self.prepare(&format!(
+            "SELECT {TAG_COLUMNS} FROM tag \
+             JOIN project_tag ON project_tag.tag_id = tag.id \
+             WHERE project_tag.project_id = ?1 \
+             ORDER BY tag.path"
+        ))?;
+
+        let result = stmt
+            .query_map([project_id.uuid()], tag_from_row)
+            .and_then(|rows| rows.collect::<Result<Vec<Tag>, _>>());
+
+        match result {
+            Ok(tags) => Ok(tags),
+            Err(err) => {
+                error!(error = %err, "Failed to list project tags.");
+                Err(StorageError::QueryFailed { source: err })
+            }
+        }
+    }
+
+    fn insert_review(&self, review: &Review) -> Result<(), StorageError> {
+        debug!(%review, "Preparing insert review sql statement.");
+
+        let tx = self.conn.unchecked_transaction().map_err(|err| {
+            error!(error = %err, "Failed to initiate the transaction.");
+            StorageError::TransactionInitFailed { source: err }
+        })?;
+
+        self.insert_review_rows(review)?;
+
+        if let Err(err) = tx.commit() {
+            error!(error = %err, "Failed to commit transaction.");
+            return Err(StorageError::TransactionCommitFailed { source: err });
+        }
+
+        Ok(())
+    }
+
+    fn get_review_in_progress(&self) -> Result<Review, StorageError> {
+        debug!("Preparing get review in progress sql statement.");
+
+        self.get_review_where("completed_at IS NULL")
+    }
+
+    fn list_reviews(&self) -> Result<Vec<Review>, StorageError> {
+        debug!("Preparing list reviews sql statement.");
+
+        let mut stmt =
+            self.prepare("SELECT id, started_at FROM review ORDER BY started_at, rowid")?;
+
+        let result = stmt
+            .query_map([], |row| {
+                Ok(Review::new(ReviewId::from_uuid(row.get(0)?), row.get(1)?))
+            })
+            .and_then(|rows| rows.collect::<Result<Vec<Review>, _>>());
+
+        match result {
+            Ok(reviews) => reviews
+                .into_iter()
+                .map(|review| self.with_review_steps(review))
+                .collect(),
+            Err(err) => {
+                error!(error = %err, "Failed to list reviews.");
+                Err(StorageError::QueryFailed { source: err })
+            }
+        }
+    }
+
+    fn get_last_completed_review(&self) -> Result<Review, StorageError> {
+        debug!("Preparing get last completed review sql statement.");
+
+        self.get_review_where("completed_at IS NOT NULL ORDER BY completed_at DESC, rowid DESC")
+    }
+
+    fn update_review(&self, review: &Review) -> Result<(), StorageError> {
+        debug!(%review, "Preparing update review sql statement.");
+
+        let tx = self.conn.unchecked_transaction().map_err(|err| {
+            error!(error = %err, "Failed to initiate the transaction.");
+            StorageError::TransactionInitFailed { source: err }
+        })?;
+
+        let mut stmt = self.prepare(
+            "UPDATE review SET started_at = :started_at, completed_at = :completed_at \
+             WHERE id = :id",
+        )?;
+        match stmt.execute(named_params! {
+            ":id": review.review_id().uuid(),
+            ":started_at": review.started_at(),
+            ":completed_at": review.completed_at(),
+        }) {
+            Err(err) => {
+                error!(error = %err, "Failed to update review.");
+                return Err(StorageError::UpdateFailed { source: err });
+            }
+            Ok(0) => {
+                debug!(review_id = %review.review_id(), "Review not found.");
+                return Err(StorageError::NotFound);
+            }
+            Ok(_num_rows_updated) => {}
+        }
+
+        let mut stmt = self.prepare("DELETE FROM review_step WHERE review_id = ?1")?;
+        if let Err(err) = stmt.execute([review.review_id().uuid()]) {
+            error!(error = %err, "Failed to delete review steps.");
+            return Err(StorageError::DeleteFailed { source: err });
+        }
+        self.insert_review_steps(review)?;
+
+        if let Err(err) = tx.commit() {
+            error!(error = %err, "Failed to commit transaction.");
+            return Err(StorageError::TransactionCommitFailed { source: err });
+        }
+
+        Ok(())
+    }
+
+    fn search(&self, text: &str) -> Result<Vec<SearchRow>, StorageError> {
+        debug!(text, "Preparing search sql statement.");
+
+        let query = fts_query(text);
+        if query.is_empty() {
+            return Ok(Vec::new());
+        }
+
+        // Matches in names weigh ten times as much as matches in notes.
+        let mut stmt = self.prepare(
+            "SELECT kind, item_id, highlight(search_index, 0, :start, :end), \
+                 NULLIF(snippet(search_index, 1, :start, :end, '…', 16), '') \
+             FROM search_index WHERE search_index MATCH :query \
+             ORDER BY bm25(search_index, 10.0, 1.0)",
+        )?;
+
+        let result = stmt
+            .query_map(
+                named_params! {
+                    ":query": query,
+                    ":start": SearchResult::HIGHLIGHT_START,
+                    ":end": SearchResult::HIGHLIGHT_END,
+                },
+                search_row_from_row,
+            )
+            .and_then(|rows| rows.collect::<Result<Vec<_>, _>>());
+
+        match result {
+            Ok(rows) => Ok(rows),
+            Err(err) => {
+                error!(error = %err, "Failed to search.");
+                Err(StorageError::QueryFailed { source: err })
+            }
+        }
+    }
+
+    fn start_history_step(&self) -> Result<(), StorageError> {
+        debug!("Preparing start history step sql statement.");
+
+        let mut stmt = self.prepare("DELETE FROM history_entry WHERE step_id IS NULL")?;
+
+        match stmt.execute([]) {
+            Err(err) => {
+                error!(error = %err, "Failed to discard open history entries.");
+                Err(StorageError::DeleteFailed { source: err })
+            }
+            Ok(_num_rows_deleted) => Ok(()),
+        }
+    }
+
+    fn finish_history_step(
+        &self,
+        operation: &str,
+        max_steps: usize,
+        max_entries: usize,
+    ) -> Result<(), StorageError> {
+        debug!(
+            operation,
+            max_steps, max_entries, "Preparing finish history step sql statements."
+        );
+
+        let tx = self.conn.unchecked_transaction().map_err(|err| {
+            error!(error = %err, "Failed to initiate the transaction.");
+            StorageError::TransactionInitFailed { source: err }
+        })?;
+
+        let mut stmt =
+            self.prepare("SELECT EXISTS (SELECT 1 FROM history_entry WHERE step_id IS NULL)")?;
+        match stmt.query_row([], |row| row.get::<_, bool>(0)) {
+            Ok(true) => {}
+            Ok(false) => {
+                debug!(operation, "Nothing changed, no history step recorded.");
+                return Ok(());
+            }
+            Err(err) => {
+                error!(error = %err, "Failed to check for open history entries.");
+                return Err(StorageError::QueryFailed { source: err });
+            }
+        }
+
+        // Undone steps can't be redone on top of the new changes.
+        let mut stmt = self.prepare("DELETE FROM history_step WHERE undone = 1")?;
+        if let Err(err) = stmt.execute([]) {
+            error!(error = %err, "Failed to delete undone history steps.");
+            return Err(StorageError::DeleteFailed { source: err });
+        }
+
+        let mut stmt = self.prepare("INSERT INTO history_step (operation) VALUES (?1)")?;
+        if let Err(err) = stmt.execute([operation]) {
+            error!(error = %err, "Failed to insert history step.");
+            return Err(StorageError::InsertFailed { source: err });
+        }
+        let step_id = self.conn.last_insert_rowid();
+
+        let mut stmt =
+            self.prepare("UPDATE history_entry SET step_id = ?1 WHERE step_id IS NULL")?;
+        if let Err(err) = stmt.execute([step_id]) {
+            error!(error = %err, "Failed to group history entries.");
+            return Err(StorageError::UpdateFailed { source: err });
+        }
+
+        let mut stmt = self.prepare(
+            "DELETE FROM history_step WHERE id NOT IN \
+             (SELECT id FROM history_step ORDER BY id DESC LIMIT ?1)",
+        )?;
+        if let Err(err) = stmt.execute([max_steps as i64]) {
+            error!(error = %err, "Failed to delete old history steps.");
+            return Err(StorageError::DeleteFailed { source: err });
+        }
+
+        // Counts the entries of each step together with those of all newer steps.
+        let mut stmt = self.prepare(
+            "WITH totals AS ( \
+                 SELECT step_id, SUM(COUNT(*)) OVER (ORDER BY step_id DESC) AS total \
+                 FROM history_entry GROUP BY step_id \
+             ) \
+             DELETE FROM history_step WHERE id != ?1 AND id IN \
+             (SELECT step_id FROM totals WHERE total > ?2)",
+        )?;
+        if let Err(err) = stmt.execute([step_id, max_entries as i64]) {
+            error!(error = %err, "Failed to delete history steps beyond the entry limit.");
+            return Err(StorageError::DeleteFailed { source: err });
+        }
+
+        if let Err(err) = tx.commit() {
+            error!(error = %err, "Failed to commit transaction.");
+            return Err(StorageError::TransactionCommitFailed { source: err });
+        }
+
+        Ok(())
+    }
+
+    fn undo_history_step(&self) -> Result<Option<String>, StorageError> {
+        self.replay_history_step(false)
+    }
+
+    fn redo_history_step(&self) -> Result<Option<String>, StorageError> {
+        self.replay_history_step(true)
+    }
+
+    fn export_data(&self) -> Result<DataSet, StorageError> {
+        let tx = self.conn.unchecked_transaction().map_err(|err| {
+            error!(error = %err, "Failed to initiate the transaction.");
+            StorageError::TransactionInitFailed { source: err }
+        })?;
+
+        let mut stmt =
+            self.prepare("SELECT action_id, context_id FROM action_context ORDER BY rowid")?;
+        let action_contexts = stmt
+            .query_map([], |row| {
+                Ok((
+                    ActionId::from_uuid(row.get(0)?),
+                    ContextId::from_uuid(row.get(1)?),
+                ))
+            })
+            .and_then(|rows| rows.collect::<Result<Vec<_>, _>>())
+            .map_err(|err| {
+                error!(error = %err, "Failed to list action contexts.");
+                StorageError::QueryFailed { source: err }
+            })?;
+
+        let mut stmt = self.prepare("SELECT action_id, tag_id FROM action_tag ORDER BY rowid")?;
+        let action_tags = stmt
+            .query_map([], |row| {
+                Ok((
+                    ActionId::from_uuid(row.get(0)?),
+                    TagId::from_uuid(row.get(1)?),
+                ))
+            })
+            .and_then(|rows| rows.collect::<Result<Vec<_>, _>>())
+            .map_err(|err| {
+                error!(error = %err, "Failed to list action tags.");
+                StorageError::QueryFailed { source: err }
+            })?;
+
+        let mut stmt = self.prepare("SELECT project_id, tag_id FROM project_tag ORDER BY rowid")?;
+        let project_tags = stmt
+            .query_map([], |row| {
+                Ok((
+                    ProjectId::from_uuid(row.get(0)?),
+                    TagId::from_uuid(row.get(1)?),
+                ))
+            })
+            .and_then(|rows| rows.collect::<Result<Vec<_>, _>>())
+            .map_err(|err| {
+                error!(error = %err, "Failed to list project tags.");
+                StorageError::QueryFailed { source: err }
+            })?;
+
+        let data = DataSet {
+            areas: self.list_areas()?,
+            actions: self.list_actions(&ActionFilter::default())?,
+            projects: self.list_projects(&ProjectFilter::default())?,
+            contexts: self.list_contexts()?,
+            action_contexts,
+            tags: self.list_tags()?,
+            action_tags,
+            project_tags,
+            perspectives: self.list_perspectives()?,
+            reviews: self.list_reviews()?,
+            inbox_items: self.list_inbox_items()?,
+            reference_items: self.list_reference_items()?,
+        };
+
+        if let Err(err) = tx.commit() {
+            error!(error = %err, "Failed to commit transaction.");
+            return Err(StorageError::TransactionCommitFailed { source: err });
+        }
+
+        Ok(data)
+    }
+
+    fn import_data(&self, data: &DataSet) -> Result<(), StorageError> {
+        let tx = self.conn.unchecked_transaction().map_err(|err| {
+            error!(error = %err, "Failed to initiate the transaction.");
+            StorageError::TransactionInitFailed { source: err }
+        })?;
+
+        // Areas first, projects and actions reference them.
+        for area in &data.areas {
+            self.insert_area(area)?;
+        }
+        // Projects and contexts next, actions reference them.
+        for project in &data.projects {
+            self.insert_project(project)?;
+        }
+        for context in &data.contexts {
+            self.insert_context(context)?;
+        }
+        for action in &data.actions {
+            self.insert_action(action)?;
+        }
+        for (action_id, context_id) in &data.action_contexts {
+            self.insert_action_context(action_id, context_id)?;
+        }
+        for tag in &data.tags {
+            self.insert_tag(tag)?;
+        }
+        for (action_id, tag_id) in &data.action_tags {
+            self.insert_action_tag(action_id, tag_id)?;
+        }
+        for (project_id, tag_id) in &data.project_tags {
+            self.insert_project_tag(project_id, tag_id)?;
+        }
+        for perspective in &data.perspectives {
+            self.insert_perspective(perspective)?;
+        }
+        for review in &data.reviews {
+            self.insert_review_rows(review)?;
+        }
+        for inbox_item in &data.inbox_items {
+            self.insert_inbox_item(inbox_item)?;
+        }
+        for reference_item in &data.reference_items {
+            self.insert_reference_item(reference_item)?;
+        }
+
+        if let Err(err) = tx.commit() {
+            error!(error = %err, "Failed to commit transaction.");
+            return Err(StorageError::TransactionCommitFailed { source: err });
+        }
+
+        info!("Data imported.");
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::storage::migration;
+
+    /// Returns the query plan SQLite chooses for listing actions with the given filter.
+    fn query_plan(storage: &SqliteStorage, filter: &ActionFilter) -> String {
+        let (sql, values) = select_actions_sql(filter);
+        let params: Vec<(&str, &dyn ToSql)> = values
+            .iter()
+            .map(|(name, value)| (*name, value.as_ref()))
+            .collect();
+        let mut stmt = storage
+            .conn
+            .prepare(&format!("EXPLAIN QUERY PLAN {sql}"))
+            .unwrap();
+        let rows = stmt
+            .query_map(params.as_slice(), |row| row.get::<_, String>(3))
+            .unwrap();
+        rows.map(Result::unwrap).collect::<Vec<_>>().join("\n")
+    }
+
+    #[test]
+    fn test_due_date_queries_use_index() {
+        crate::test_utils::init_test_logging();
+
+        let mut storage = SqliteStorage::new_in_memory().unwrap();
+        storage
+            .run_migrations(&migration::builtin_migrations())
+            .unwrap();
+        let filter = ActionFilter {
+            completed: Some(false),
+            due_before: Some(chrono::Utc::now()),
+            order: ActionOrder::DueDate,
+            ..Default::default()
+        };
+        let plan = query_plan(&storage, &filter);
+        assert!(plan.contains("idx_action_open_due_at"), "{plan}");
+    }
+
+    #[test]
+    fn test_waiting_for_puts_follow_ups_first() {
+        crate::test_utils::init_test_logging();
+
+        let mut storage = SqliteStorage::new_in_memory().unwrap();
+        storage
+            .run_migrations(&migration::builtin_migrations())
+            .unwrap();
+        let now = chrono::Utc::now();
+        let delegated = |name: &str, follow_up_at| {
+            let mut action = crate::model::action::test_utils::dummy_action();
+            action.set_action_name(ActionName::new(name).unwrap());
+            action.set_action_delegation(Some(
+                ActionDelegation::new("Alice", now, follow_up_at).unwrap(),
+            ));
+            action
+        };
+        let without_follow_up = delegated("without", None);
+        let later = delegated("later", Some(now + chrono::Duration::days(3)));
+        let sooner = delegated("sooner", Some(now - chrono::Duration::days(1)));
+        let not_delegated = crate::model::action::test_utils::dummy_action();
+        for action in [&without_follow_up, &later, &sooner, &not_delegated] {
+            storage.insert_action(action).unwrap();
+        }
+
+        let waiting_for = storage
+            .list_actions(&ActionFilter {
+                completed: Some(false),
+                delegated: Some(true),
+                order: ActionOrder::FollowUp,
+                ..Default::default()
+            })
+            .unwrap();
+        assert_eq!(waiting_for, vec![sooner, later, without_follow_up]);
+    }
+
+    #[test]
+    fn test_project_actions_get_positions_and_lose_them_with_the_project() {
+        crate::test_utils::init_test_logging();
+
+        let mut storage = SqliteStorage::new_in_memory().unwrap();
+        storage
+            .run_migrations(&migration::test_migrations(
+                migration::MIGRATIONS[..11].to_vec(),
+            ))
+            .unwrap();
+        let project_id = ProjectId::new();
+        storage
+            .conn
+            .execute(
+                "INSERT INTO project (id, title, created_at, status) \
+                 VALUES (?1, 'Move', '2024-01-01T00:00:00Z', 'active')",
+                [project_id.uuid()],
+            )
+            .unwrap();
+        for (title, created_at) in [("second", "2024-01-03"), ("first", "2024-01-02")] {
+            storage
+                .conn
+                .execute(
+                    "INSERT INTO action (id, title, created_at, project_id) \
+                     VALUES (?1, ?2, ?3, ?4)",
+                    (
+                        ActionId::new().uuid(),
+                        title,
+                        format!("{created_at}T00:00:00Z"),
+                        project_id.uuid(),
+                    ),
+                )
+                .unwrap();
+        }
+        storage
+            .run_migrations(&migration::builtin_migrations())
+            .unwrap();
+
+        let filter = ActionFilter {
+            project_id: Some(project_id.clone()),
+            order: ActionOrder::Position,
+            ..Default::default()
+        };
+        let actions = storage.list_actions(&filter).unwrap();
+        let positions: Vec<_> = actions
+            .iter()
+            .map(|action| (action.action_name().as_str(), action.position()))
+            .collect();
+        assert_eq!(positions, vec![("first", Some(0)), ("second", Some(1))]);
+        assert_eq!(storage.next_action_position(&project_id).unwrap(), 2);
+
+        storage.delete_project(&project_id).unwrap();
+        let orphan = storage.get_action(actions[0].action_id()).unwrap();
+        assert_eq!(orphan.project_id(), None);
+        assert_eq!(orphan.position(), None);
+    }
+
+    /// Returns the SQL of the trigger with the given name.
+    fn trigger_sql(storage: &SqliteStorage, name: &str) -> String {
+        storage
+            .conn
+            .query_row(
+                "SELECT sql FROM sqlite_master WHERE type = 'trigger' AND name = ?1",
+                [name],
+                |row| row.get(0),
+            )
+            .unwrap_or_else(|err| panic!("trigger {name} is missing: {err}"))
+    }
+
+    /// Returns the columns the trigger SQL reads from the `old` row, in order of appearance.
+    fn old_columns(sql: &str) -> Vec<String> {
+        sql.split("old.")
+            .skip(1)
+            .map(|rest| {
+                rest.chars()
+                    .take_while(|c| c.is_ascii_alphanumeric() || *c == '_')
+                    .collect()
+            })
+            .collect()
+    }
+
+    #[test]
+    fn test_history_triggers_cover_all_columns() {
+        crate::test_utils::init_test_logging();
+
+        let mut storage = SqliteStorage::new_in_memory().unwrap();
+        storage
+            .run_migrations(&migration::builtin_migrations())
+            .unwrap();
+        // The link tables only consist of their key, so their rows are never updated.
+        let tables = [
+            ("area", true),
+            ("project", true),
+            ("action", true),
+            ("context", true),
+            ("action_context", false),
+            ("tag", true),
+            ("action_tag", false),
+            ("project_tag", false),
+            ("perspective", true),
+            ("inbox_item", true),
+            ("reference_item", true),
+            ("review", true),
+            ("review_step", true),
+        ];
+        for (table, updatable) in tables {
+            let mut stmt = storage
+                .conn
+                .prepare(&format!("PRAGMA table_info({table})"))
+                .unwrap();
+            let columns: Vec<String> = stmt
+                .query_map([], |row| row.get(1))
+                .unwrap()
+                .map(Result::unwrap)
+                .collect();
+
+            // The inverse of a delete restores every column.
+            let sql = trigger_sql(&storage, &format!("history_{table}_delete"));
+            let prefix = format!("INSERT INTO {table} (");
+            let start = sql.find(&prefix).unwrap() + prefix.len();
+            let end = start + sql[start..].find(") VALUES (").unwrap();
+            let listed: Vec<String> = sql[start..end]
+                .replace(['\'', '|'], "")
+                .split(',')
+                .map(|column| column.trim().to_owned())
+                .collect();
+            assert_eq!(listed, columns, "history_{table}_delete");
+            assert_eq!(old_columns(&sql), columns, "history_{table}_delete");
+
+            // The inverse of an update restores every column, or identifies the row by it.
+            if updatable {
+                let sql = trigger_sql(&storage, &format!("history_{table}_update"));
+                let mut restored = old_columns(&sql);
+                restored.sort();
+                let mut expected = columns.clone();
+                expected.sort();
+                assert_eq!(restored, expected, "history_{table}_update");
+            }
+
+            trigger_sql(&storage, &format!("history_{table}_insert"));
+        }
+    }
 }
@@ -1,28 +1,156 @@
 //! Various error definitions for all things related to database operations.
 
+use rusqlite::ErrorCode;
 use thiserror::Error;
 
 /// Encodes an error that happened at the persistence layer.
+///
+/// Except for [StorageError::NotFound], every variant carries the [rusqlite::Error] that caused
+/// it as its source. Use [StorageError::kind] to find out what went wrong.
 #[derive(Error, Debug)]
 pub enum StorageError {
     #[error("Could not connect to the database.")]
-    ConnectionError,
+    ConnectionError { source: rusqlite::Error },
+
+    #[error("Delete operation failed.")]
+    DeleteFailed { source: rusqlite::Error },
 
     #[error("Insert operation failed.")]
-    InsertFailed,
+    InsertFailed { source: rusqlite::Error },
 
     #[error("Migration to schema version {version} failed.")]
-    MigrationFailed { version: u32 },
+    MigrationFailed {
+        version: u32,
+        source: rusqlite::Error,
+    },
+
+    #[error("The requested record does not exist.")]
+    NotFound,
 
     #[error("Failed to prepare sql statement.")]
-    PrepareStatementFailed,
+    PrepareStatementFailed { source: rusqlite::Error },
 
     #[error("Query execution failed.")]
-    QueryFailed,
+    QueryFailed { source: rusqlite::Error },
 
     #[error("Failed to commit transaction.")]
-    TransactionCommitFailed,
+    TransactionCommitFailed { source: rusqlite::Error },
 
     #[error("Failed to begin transaction.")]
-    TransactionInitFailed,
+    TransactionInitFailed { source: rusqlite::Error },
+
+    #[error("Update operation failed.")]
+    UpdateFailed { source: rusqlite::Error },
+}
+
+/// A coarse classification of [StorageError]s, for callers that want to react to the cause
+/// of an error rather than to the operation that failed.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum StorageErrorKind {
+    /// A constraint like a unique or foreign key constraint was violated.
+    Constraint,
+    /// The database is busy or locked by another connection. Retrying later may succeed.
+    Busy,
+    /// The database file is corrupt or not a database at all.
+    Corrupt,
+    /// The requested record does not exist.
+    NotFound,
+    /// Reading or writing the database file failed, e.g. because the disk is full or the file
+    /// can't be opened.
+    Io,
+    /// Anything else, e.g. a faulty sql statement.
+    Other,
+}
+
+impl StorageError {
+    /// Classifies this error by its underlying cause.
+    pub fn kind(&self) -> StorageErrorKind {
+        match self {
+            StorageError::NotFound => StorageErrorKind::NotFound,
+            StorageError::ConnectionError { source }
+            | StorageError::DeleteFailed { source }
+            | StorageError::InsertFailed { source }
+            | StorageError::MigrationFailed { source, .. }
+            | StorageError::PrepareStatementFailed { source }
+            | StorageError::QueryFailed { source }
+            | StorageError::TransactionCommitFailed { source }
+            | StorageError::TransactionInitFailed { source }
+            | StorageError::UpdateFailed { source } => classify(source),
+        }
+    }
+}
+
+/// Maps a [rusqlite::Error] to its [StorageErrorKind].
+fn classify(err: &rusqlite::Error) -> StorageErrorKind {
+    match err {
+        rusqlite::Error::QueryReturnedNoRows => StorageErrorKind::NotFound,
+        rusqlite::Error::SqliteFailure(err, _) => match err.code {
+            ErrorCode::ConstraintViolation => StorageErrorKind::Constraint,
+            ErrorCode::DatabaseBusy | ErrorCode::DatabaseLocked => StorageErrorKind::Busy,
+            ErrorCode::DatabaseCorrupt | ErrorCode::NotADatabase => StorageErrorKind::Corrupt,
+            ErrorCode::SystemIoFailure
+            | ErrorCode::DiskFull
+            | ErrorCode::CannotOpen
+            | ErrorCode::ReadOnly
+            | ErrorCode::PermissionDenied
+            | ErrorCode::FileLockingProtocolFailed => StorageErrorKind::Io,
+            _ => StorageErrorKind::Other,
+        },
+        _ => StorageErrorKind::Other,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::error::Error;
+
+    use rusqlite::Connection;
+
+    use super::*;
+
+    #[test]
+    fn test_constraint_violation_is_classified() {
+        let conn = Connection::open_in_memory().unwrap();
+        conn.execute_batch("CREATE TABLE t (id INTEGER PRIMARY KEY); INSERT INTO t VALUES (1);")
+            .unwrap();
+        let source = conn.execute("INSERT INTO t VALUES (1)", []).unwrap_err();
+
+        let err = StorageError::InsertFailed { source };
+        assert_eq!(err.kind(), StorageErrorKind::Constraint);
+        assert!(err.source().is_some());
+    }
+
+    #[test]
+    fn test_busy_and_corrupt_are_classified() {
+        let failure = |code| {
+            rusqlite::Error::SqliteFailure(
+                rusqlite::ffi::Error {
+                    code,
+                    extended_code: 0,
+                },
+                None,
+            )
+        };
+
+        let err = StorageError::TransactionInitFailed {
+            source: failure(ErrorCode::DatabaseBusy),
+        };
+        assert_eq!(err.kind(), StorageErrorKind::Busy);
+
+        let err = StorageError::QueryFailed {
+            source: failure(ErrorCode::NotADatabase),
+        };
+        assert_eq!(err.kind(), StorageErrorKind::Corrupt);
+
+        let err = StorageError::ConnectionError {
+            source: failure(ErrorCode::DiskFull),
+        };
+        assert_eq!(err.kind(), StorageErrorKind::Io);
+    }
+
+    #[test]
+    fn test_not_found_has_no_source() {
+        assert_eq!(StorageError::NotFound.kind(), StorageErrorKind::NotFound);
+        assert!(StorageError::NotFound.source().is_none());
+    }
 }
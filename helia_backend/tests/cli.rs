@@ -0,0 +1,89 @@
+//! Integration tests for the `helia` binary.
+
+use std::{
+    path::{Path, PathBuf},
+    process::{Command, Output},
+};
+
+/// A database file in a fresh temporary directory, removed again on drop.
+struct TempDb {
+    dir: PathBuf,
+}
+
+impl TempDb {
+    fn new() -> Self {
+        let dir = std::env::temp_dir().join(format!("helia-cli-{}", uuid::Uuid::new_v4()));
+        Self { dir }
+    }
+
+    fn path(&self) -> PathBuf {
+        self.dir.join("helia.db")
+    }
+}
+
+impl Drop for TempDb {
+    fn drop(&mut self) {
+        let _ = std::fs::remove_dir_all(&self.dir);
+    }
+}
+
+fn helia(db: &Path, args: &[&str]) -> Output {
+    Command::new(env!("CARGO_BIN_EXE_helia"))
+        .arg("--db")
+        .arg(db)
+        .args(args)
+        .output()
+        .unwrap()
+}
+
+fn stdout(output: &Output) -> String {
+    String::from_utf8(output.stdout.clone()).unwrap()
+}
+
+#[test]
+fn test_action_lifecycle() {
+    let db = TempDb::new();
+    assert!(helia(&db.path(), &["migrate"]).status.success());
+
+    let added = helia(&db.path(), &["--output", "json", "add", "Water plants"]);
+    assert!(added.status.success());
+    let action: serde_json::Value = serde_json::from_str(&stdout(&added)).unwrap();
+    let id = action["id"].as_str().unwrap().to_owned();
+
+    let listed = helia(&db.path(), &["list"]);
+    assert!(stdout(&listed).contains("[ ] Water plants"));
+
+    assert!(helia(&db.path(), &["complete", &id]).status.success());
+    let listed = helia(&db.path(), &["--output", "json", "list"]);
+    assert_eq!(stdout(&listed).trim(), "[]");
+    let listed = helia(&db.path(), &["list", "--status", "completed"]);
+    assert!(stdout(&listed).contains("[x] Water plants"));
+
+    assert!(helia(&db.path(), &["delete", &id]).status.success());
+    let listed = helia(&db.path(), &["list", "--status", "all"]);
+    assert!(stdout(&listed).is_empty());
+}
+
+#[test]
+fn test_errors_map_to_exit_codes() {
+    let db = TempDb::new();
+    assert!(helia(&db.path(), &["migrate"]).status.success());
+
+    let missing = uuid::Uuid::new_v4().to_string();
+    let output = helia(&db.path(), &["complete", &missing]);
+    assert_eq!(output.status.code(), Some(3));
+    assert!(String::from_utf8_lossy(&output.stderr).contains("does not exist"));
+
+    let output = helia(&db.path(), &["complete", "not-a-uuid"]);
+    assert_eq!(output.status.code(), Some(2));
+}
+
+#[test]
+fn test_version_reports_schema_version() {
+    let db = TempDb::new();
+    assert!(helia(&db.path(), &["migrate"]).status.success());
+
+    let output = helia(&db.path(), &["--output", "json", "version"]);
+    let version: serde_json::Value = serde_json::from_str(&stdout(&output)).unwrap();
+    assert!(version["schema_version"].as_u64().unwrap() > 0);
+}